@@ -0,0 +1,179 @@
+//! Times the article listings over a seeded database of 100k articles, against the
+//! correlated subqueries they ran before the counters and the pre-aggregated joins.
+//!
+//! `cargo bench --bench article_listing --features ssr`
+
+use realworld_app_leptos_axum_sqlite::{config, database, models};
+use std::time::{Duration, Instant};
+
+const ARTICLES: i64 = 100_000;
+const USERS: i64 = 1_000;
+const TAGS: i64 = 50;
+const RUNS: u32 = 20;
+const AMOUNT: i64 = 10;
+
+/// The home page query before the counters, one set of subqueries per article.
+const CORRELATED: &str = "
+SELECT
+    a.slug,
+    a.title,
+    a.description,
+    a.created_at,
+    CAST(a.created_at AS TEXT) as cursor_created_at,
+    (SELECT COUNT(*) FROM FavArticles WHERE article=a.slug) as favorites_count,
+    (SELECT COUNT(*) FROM comments WHERE article=a.slug) as comments_count,
+    u.username, u.image,
+    EXISTS(SELECT 1 FROM FavArticles WHERE article=a.slug and username=$5) as fav,
+    EXISTS(SELECT 1 FROM Follows WHERE follower=$5 and influencer=u.username) as following,
+    EXISTS(SELECT 1 FROM Bookmarks WHERE article=a.slug and username=$5) as bookmarked,
+    EXISTS(SELECT 1 FROM Bookmarks WHERE article=a.slug and username=$5 and read_at IS NOT NULL) as bookmark_read,
+    (SELECT json_group_array(username) FROM (
+        SELECT username FROM ArticleAuthors
+        WHERE article=a.slug and role='editor' and accepted_at IS NOT NULL
+        ORDER BY accepted_at
+    )) as co_authors,
+    s.id as series_id,
+    s.title as series_title,
+    sa.position as series_position,
+    (SELECT string_agg(tag, ' ') FROM ArticleTags WHERE article = a.slug) as tag_list
+FROM Articles as a
+    JOIN Users as u ON a.author = u.username
+    LEFT JOIN SeriesArticles as sa ON sa.article = a.slug
+    LEFT JOIN Series as s ON s.id = sa.series
+WHERE
+    CASE WHEN $3!='' THEN a.slug in (SELECT distinct article FROM ArticleTags WHERE tag=$3)
+    ELSE 1=1
+    END
+    AND
+    CASE WHEN $4 THEN
+        u.username in (SELECT influencer FROM Follows WHERE follower=$5)
+        OR ($6 AND a.slug in (
+            SELECT at.article FROM ArticleTags as at
+                JOIN TagFollows as tf ON tf.tag = at.tag
+            WHERE tf.username=$5
+        ))
+    ELSE 1=1
+    END
+    AND ($7 IS NULL OR (a.created_at, a.slug) < ($7, $8))
+    AND ($9 IS NULL OR (a.created_at, a.slug) > ($9, $10))
+ORDER BY
+    CASE WHEN $9 IS NULL THEN a.created_at END desc,
+    CASE WHEN $9 IS NULL THEN a.slug END desc,
+    a.created_at, a.slug
+LIMIT $1 OFFSET $2";
+
+async fn seed(db: &sqlx::SqlitePool) -> Result<(), sqlx::Error> {
+    let statements: [(&str, &[i64]); 6] = [
+        ("INSERT INTO Users(username, email, password)
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < $1)
+SELECT 'bench' || i, 'bench' || i || '@example.com', '' FROM n", &[USERS]),
+        ("INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at)
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < $2)
+SELECT
+    'bench-' || i,
+    'bench' || (i % $1 + 1),
+    'Article ' || i,
+    'The description of article ' || i,
+    'The body of article ' || i,
+    datetime('2025-01-01', '+' || i || ' minutes'),
+    datetime('2025-01-01', '+' || i || ' minutes')
+FROM n", &[USERS, ARTICLES]),
+        ("INSERT INTO ArticleTags(article, tag)
+SELECT slug, 'tag' || ((rowid + k) % $1) FROM Articles, (SELECT 0 as k UNION ALL SELECT 7 UNION ALL SELECT 19)
+WHERE slug LIKE 'bench-%'", &[TAGS]),
+        ("INSERT OR IGNORE INTO FavArticles(article, username)
+SELECT slug, 'bench' || ((rowid * k) % $1 + 1) FROM Articles, (SELECT 1 as k UNION ALL SELECT 3 UNION ALL SELECT 11)
+WHERE slug LIKE 'bench-%'", &[USERS]),
+        ("INSERT INTO Comments(article, username, body)
+SELECT slug, 'bench' || ((rowid + k) % $1 + 1), 'A comment' FROM Articles, (SELECT 1 as k UNION ALL SELECT 2)
+WHERE slug LIKE 'bench-%'", &[USERS]),
+        ("INSERT OR IGNORE INTO Follows(follower, influencer)
+SELECT 'bench1', username FROM Users WHERE username LIKE 'bench%' LIMIT 100", &[]),
+    ];
+    for (statement, args) in statements {
+        let mut query = sqlx::query(statement);
+        for arg in args {
+            query = query.bind(*arg);
+        }
+        query.execute(db).await?;
+    }
+    Ok(())
+}
+
+async fn time<F, Fut>(mut run: F) -> Duration
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    // The first run warms the page cache
+    run().await;
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        run().await;
+    }
+    start.elapsed() / RUNS
+}
+
+async fn bench() {
+    let file = std::env::temp_dir().join(format!("article_listing_{}.db", std::process::id()));
+    std::env::set_var(
+        "DATABASE_URL",
+        format!("sqlite://{}?mode=rwc", file.display()),
+    );
+    std::env::set_var("JWT_SECRET", "bench");
+    config::init_config(([127, 0, 0, 1], 3000).into()).expect("configuration loaded");
+    database::init_db().await.expect("database initialized");
+    let db = database::get_db();
+    seed(db).await.expect("seeded");
+    println!("seeded {ARTICLES} articles");
+
+    let cases = [
+        ("first page", 0, ""),
+        ("page 500", 500 * AMOUNT, ""),
+        ("tag page", 0, "tag7"),
+    ];
+    for (name, offset, tag) in cases {
+        let before = time(|| async move {
+            sqlx::query(CORRELATED)
+                .bind(AMOUNT + 1)
+                .bind(offset)
+                .bind(tag)
+                .bind(false)
+                .bind(Option::<String>::None)
+                .bind(false)
+                .bind(Option::<String>::None)
+                .bind(Option::<String>::None)
+                .bind(Option::<String>::None)
+                .bind(Option::<String>::None)
+                .fetch_all(db)
+                .await
+                .expect("correlated listing");
+        })
+        .await;
+        let after = time(|| async move {
+            models::ArticleQuery::new()
+                .tag(tag.to_string())
+                .fetch_page(models::PageStart::Offset(offset), AMOUNT)
+                .await
+                .expect("home page listing");
+        })
+        .await;
+        println!(
+            "{name:<12} correlated {before:>10.2?}   aggregated {after:>10.2?}   {:.1}x",
+            before.as_secs_f64() / after.as_secs_f64()
+        );
+    }
+
+    db.close().await;
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{suffix}", file.display()));
+    }
+}
+
+fn main() {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("tokio runtime")
+        .block_on(bench());
+}
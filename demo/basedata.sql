@@ -1,8 +1,10 @@
-INSERT INTO Users VALUES('user1','user1@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$AnaB4r1/j5mQ66KMDGlvtQ$25FF8k1gramInrICz3HbH/YAhxUUonMFxaAS/nqHL7Y',NULL,NULL);
-INSERT INTO Users VALUES('user2','user2@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$N+kvivxKNpp2C0oYN/JcLg$5Lzz0rte6s5goYaod50p6WWfh3vmIYlZ/jf4wcutRg4',NULL,NULL);
-INSERT INTO Users VALUES('user3','user3@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$sK3ANCyW4++XATmkePUgjw$lOZu7Z9P9MKLonq6f0FJdk0B1WuajHsxNsVapILgNGg',NULL,NULL);
-INSERT INTO Users VALUES('user4','user4@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$nHzPjIItOYUns/ljri/kyA$htS2eJAomY5h4/0aE5r4ByrBxOa6a7MClaW/qlFvlM0',NULL,NULL);
-INSERT INTO Users VALUES('user5','user5@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$DSyiHjOeOoz1hDfDl6UcJg$CttwVp/CVQS1DJSzf2WEA9mrPWLATRMeEWNItIyaIQk',NULL,NULL);
+-- The demo content loaded by `seed --demo`: user1 to user5, their password is their username.
+
+INSERT INTO Users(username, email, password, bio, image) VALUES('user1','user1@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$AnaB4r1/j5mQ66KMDGlvtQ$25FF8k1gramInrICz3HbH/YAhxUUonMFxaAS/nqHL7Y',NULL,NULL);
+INSERT INTO Users(username, email, password, bio, image) VALUES('user2','user2@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$N+kvivxKNpp2C0oYN/JcLg$5Lzz0rte6s5goYaod50p6WWfh3vmIYlZ/jf4wcutRg4',NULL,NULL);
+INSERT INTO Users(username, email, password, bio, image) VALUES('user3','user3@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$sK3ANCyW4++XATmkePUgjw$lOZu7Z9P9MKLonq6f0FJdk0B1WuajHsxNsVapILgNGg',NULL,NULL);
+INSERT INTO Users(username, email, password, bio, image) VALUES('user4','user4@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$nHzPjIItOYUns/ljri/kyA$htS2eJAomY5h4/0aE5r4ByrBxOa6a7MClaW/qlFvlM0',NULL,NULL);
+INSERT INTO Users(username, email, password, bio, image) VALUES('user5','user5@abcd.co','$argon2id$v=19$m=19456,t=2,p=1$DSyiHjOeOoz1hDfDl6UcJg$CttwVp/CVQS1DJSzf2WEA9mrPWLATRMeEWNItIyaIQk',NULL,NULL);
 
 -- SQL INSERT Queries for Articles, ArticleTags, FavArticles, and Comments
 
@@ -15,441 +17,439 @@ INSERT INTO Users VALUES('user5','user5@abcd.co','$argon2id$v=19$m=19456,t=2,p=1
 -- Dates are set to random days in June 2025.
 
 -- Article 1: user1 - AI Ethics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000001','user1','The Ethical Quandaries of AI Development','Exploring the moral and societal challenges posed by advanced artificial intelligence.','As artificial intelligence continues its rapid advancement, the ethical implications of its development and deployment are becoming increasingly critical. Questions surrounding algorithmic bias, privacy, accountability, and the potential for autonomous decision-making without human oversight demand careful consideration. For instance, AI systems trained on biased datasets can perpetuate and even amplify existing societal inequalities, leading to unfair outcomes in areas like hiring, lending, or criminal justice. Ensuring transparency in AI models, often referred to as "explainable AI," is vital so that decisions made by these systems can be understood and audited. Furthermore, the concept of AI accountability raises complex legal and moral dilemmas: who is responsible when an autonomous AI system causes harm? The development of ethical AI guidelines and regulations, alongside interdisciplinary collaboration between technologists, ethicists, policymakers, and civil society, is essential to navigate these challenges responsibly and ensure that AI serves humanity''s best interests. This proactive approach is crucial to build public trust and prevent unforeseen negative consequences as AI becomes more integrated into our daily lives and critical infrastructure. The goal is not to stifle innovation, but to guide it towards beneficial and equitable outcomes for all.','2025-06-05 09:15:22','2025-06-05 09:15:22');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000001','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000001','Ethics');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000001','grand');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000001','user1','The Ethical Quandaries of AI Development','Exploring the moral and societal challenges posed by advanced artificial intelligence.','As artificial intelligence continues its rapid advancement, the ethical implications of its development and deployment are becoming increasingly critical. Questions surrounding algorithmic bias, privacy, accountability, and the potential for autonomous decision-making without human oversight demand careful consideration. For instance, AI systems trained on biased datasets can perpetuate and even amplify existing societal inequalities, leading to unfair outcomes in areas like hiring, lending, or criminal justice. Ensuring transparency in AI models, often referred to as "explainable AI," is vital so that decisions made by these systems can be understood and audited. Furthermore, the concept of AI accountability raises complex legal and moral dilemmas: who is responsible when an autonomous AI system causes harm? The development of ethical AI guidelines and regulations, alongside interdisciplinary collaboration between technologists, ethicists, policymakers, and civil society, is essential to navigate these challenges responsibly and ensure that AI serves humanity''s best interests. This proactive approach is crucial to build public trust and prevent unforeseen negative consequences as AI becomes more integrated into our daily lives and critical infrastructure. The goal is not to stifle innovation, but to guide it towards beneficial and equitable outcomes for all.','2025-06-05 09:15:22','2025-06-05 09:15:22');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000001','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000001','ethics');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000001','grand');
 
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000001','user3');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000001','user3');
 
-INSERT INTO Comments VALUES(1,'01980000-0000-0000-0000-000000000001','user2','Great points on bias, very relevant!','2025-06-05 10:00:00');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000001','user2','Great points on bias, very relevant!','2025-06-05 10:00:00');
 
 -- Article 2: user2 - Quantum Computing
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000002','user2','Quantum Computing: Beyond Classical Limits','An overview of quantum computing and its potential to solve previously intractable problems.','Quantum computing leverages principles of quantum mechanics, such as superposition and entanglement, to perform computations in fundamentally new ways. Unlike classical bits that are either 0 or 1, quantum bits (qubits) can exist in multiple states simultaneously, allowing for exponentially more complex calculations. This technology holds immense potential for breaking current encryption methods, accelerating drug discovery, optimizing complex logistics, and developing new materials. While still in its early stages, quantum computers promise to revolutionize fields currently limited by the computational power of even the most powerful classical supercomputers, opening doors to unprecedented scientific and technological advancements. The engineering challenges are significant, requiring ultra-cold temperatures and isolation from environmental noise to maintain quantum coherence. Despite these hurdles, ongoing research and investment from governments and private companies indicate a strong belief in its transformative power. Understanding its basic principles is becoming increasingly important for anyone interested in the future of computation.','2025-06-07 14:30:10','2025-06-07 14:30:10');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000002','Quantum_Computing');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000002','Technology');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000002','user1');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000002','user4');
-INSERT INTO Comments VALUES(2,'01980000-0000-0000-0000-000000000002','user5','Fascinating! When do you think we will see practical applications?','2025-06-07 15:00:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000002','user2','Quantum Computing: Beyond Classical Limits','An overview of quantum computing and its potential to solve previously intractable problems.','Quantum computing leverages principles of quantum mechanics, such as superposition and entanglement, to perform computations in fundamentally new ways. Unlike classical bits that are either 0 or 1, quantum bits (qubits) can exist in multiple states simultaneously, allowing for exponentially more complex calculations. This technology holds immense potential for breaking current encryption methods, accelerating drug discovery, optimizing complex logistics, and developing new materials. While still in its early stages, quantum computers promise to revolutionize fields currently limited by the computational power of even the most powerful classical supercomputers, opening doors to unprecedented scientific and technological advancements. The engineering challenges are significant, requiring ultra-cold temperatures and isolation from environmental noise to maintain quantum coherence. Despite these hurdles, ongoing research and investment from governments and private companies indicate a strong belief in its transformative power. Understanding its basic principles is becoming increasingly important for anyone interested in the future of computation.','2025-06-07 14:30:10','2025-06-07 14:30:10');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000002','quantum_computing');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000002','technology');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000002','user1');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000002','user4');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000002','user5','Fascinating! When do you think we will see practical applications?','2025-06-07 15:00:00');
 
 -- Article 3: user3 - CRISPR Technology
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000003','user3','CRISPR: Revolutionizing Gene Editing','How CRISPR technology is transforming biology and medicine.','CRISPR-Cas9 is a revolutionary gene-editing tool derived from a bacterial defense system. It allows scientists to precisely cut and edit DNA sequences, effectively correcting genetic mutations, inserting new genes, or deactivating existing ones. This technology holds immense promise for treating genetic diseases like cystic fibrosis and sickle cell anemia, and for developing new therapies for cancer and infectious diseases. Beyond human health, CRISPR is being applied in agriculture to create more resilient crops and in biotechnology for industrial applications. While its potential is vast, ethical considerations regarding germline editing and unintended consequences remain central to ongoing research and public debate. The precision and relative ease of use of CRISPR compared to previous gene-editing methods have accelerated biological research across numerous fields, offering unprecedented opportunities to understand and manipulate life at its most fundamental level. Continued responsible development and societal dialogue are key to unlocking its full beneficial potential.','2025-06-09 10:45:30','2025-06-09 10:45:30');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000003','Biotechnology');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000003','CRISPR');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000003','Medicine');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000003','user2');
-INSERT INTO Comments VALUES(3,'01980000-0000-0000-0000-000000000003','user1','The ethical debate around CRISPR is so important.','2025-06-09 11:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000003','user3','CRISPR: Revolutionizing Gene Editing','How CRISPR technology is transforming biology and medicine.','CRISPR-Cas9 is a revolutionary gene-editing tool derived from a bacterial defense system. It allows scientists to precisely cut and edit DNA sequences, effectively correcting genetic mutations, inserting new genes, or deactivating existing ones. This technology holds immense promise for treating genetic diseases like cystic fibrosis and sickle cell anemia, and for developing new therapies for cancer and infectious diseases. Beyond human health, CRISPR is being applied in agriculture to create more resilient crops and in biotechnology for industrial applications. While its potential is vast, ethical considerations regarding germline editing and unintended consequences remain central to ongoing research and public debate. The precision and relative ease of use of CRISPR compared to previous gene-editing methods have accelerated biological research across numerous fields, offering unprecedented opportunities to understand and manipulate life at its most fundamental level. Continued responsible development and societal dialogue are key to unlocking its full beneficial potential.','2025-06-09 10:45:30','2025-06-09 10:45:30');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000003','biotechnology');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000003','crispr');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000003','medicine');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000003','user2');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000003','user1','The ethical debate around CRISPR is so important.','2025-06-09 11:30:00');
 
 -- Article 4: user4 - Space Exploration
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000004','user4','Mars Colonization: The Next Giant Leap for Humanity','Examining the challenges and opportunities of establishing a human presence on Mars.','The dream of colonizing Mars has captivated scientists and futurists for decades, representing the next frontier in human space exploration. Establishing a sustainable human presence on the Red Planet presents immense engineering, biological, and psychological challenges. These include developing advanced life support systems, radiation shielding, self-sustaining habitats, and efficient transportation. However, the potential rewards are equally vast: expanding humanity''s reach, unlocking new scientific discoveries, and providing a "backup" for civilization in case of catastrophic events on Earth. Private companies alongside national space agencies are actively pursuing this goal, developing reusable rockets and innovative technologies. While still decades away, each successful mission to Mars, whether robotic or crewed, brings us closer to understanding how to live and thrive on another planet, paving the way for humanity to become a multi-planetary species.','2025-06-11 08:00:00','2025-06-11 08:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000004','Space');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000004','Colonization');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000004','user5');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000004','user4','Mars Colonization: The Next Giant Leap for Humanity','Examining the challenges and opportunities of establishing a human presence on Mars.','The dream of colonizing Mars has captivated scientists and futurists for decades, representing the next frontier in human space exploration. Establishing a sustainable human presence on the Red Planet presents immense engineering, biological, and psychological challenges. These include developing advanced life support systems, radiation shielding, self-sustaining habitats, and efficient transportation. However, the potential rewards are equally vast: expanding humanity''s reach, unlocking new scientific discoveries, and providing a "backup" for civilization in case of catastrophic events on Earth. Private companies alongside national space agencies are actively pursuing this goal, developing reusable rockets and innovative technologies. While still decades away, each successful mission to Mars, whether robotic or crewed, brings us closer to understanding how to live and thrive on another planet, paving the way for humanity to become a multi-planetary species.','2025-06-11 08:00:00','2025-06-11 08:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000004','space');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000004','colonization');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000004','user5');
 
 -- Article 5: user5 - Renewable Energy
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000005','user5','The Rise of Green Hydrogen: A Game Changer for Decarbonization','How green hydrogen production is poised to revolutionize energy storage and industry.','Green hydrogen, produced by electrolyzing water using renewable electricity, is emerging as a critical component in global decarbonization efforts. Unlike grey or blue hydrogen, its production emits no greenhouse gases, making it a truly clean fuel. It holds immense potential for sectors difficult to electrify, such as heavy industry (steel, chemicals), long-haul transport (shipping, aviation), and seasonal energy storage. Challenges remain in scaling up electrolysis technology, reducing production costs, and building the necessary infrastructure for transport and storage. However, significant investments and policy support worldwide indicate a strong commitment to making green hydrogen a viable and widespread energy carrier, accelerating the transition away from fossil fuels and helping to achieve net-zero emissions targets. Its versatility makes it a powerful tool in the fight against climate change.','2025-06-13 11:00:00','2025-06-13 11:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000005','Energy');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000005','Sustainability');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000005','Hydrogen');
-INSERT INTO Comments VALUES(4,'01980000-0000-0000-0000-000000000005','user3','Very informative! What are the biggest hurdles to widespread adoption?','2025-06-13 11:45:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000005','user5','The Rise of Green Hydrogen: A Game Changer for Decarbonization','How green hydrogen production is poised to revolutionize energy storage and industry.','Green hydrogen, produced by electrolyzing water using renewable electricity, is emerging as a critical component in global decarbonization efforts. Unlike grey or blue hydrogen, its production emits no greenhouse gases, making it a truly clean fuel. It holds immense potential for sectors difficult to electrify, such as heavy industry (steel, chemicals), long-haul transport (shipping, aviation), and seasonal energy storage. Challenges remain in scaling up electrolysis technology, reducing production costs, and building the necessary infrastructure for transport and storage. However, significant investments and policy support worldwide indicate a strong commitment to making green hydrogen a viable and widespread energy carrier, accelerating the transition away from fossil fuels and helping to achieve net-zero emissions targets. Its versatility makes it a powerful tool in the fight against climate change.','2025-06-13 11:00:00','2025-06-13 11:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000005','energy');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000005','sustainability');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000005','hydrogen');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000005','user3','Very informative! What are the biggest hurdles to widespread adoption?','2025-06-13 11:45:00');
 
 -- Article 6: user1 - Brain-Computer Interfaces
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000006','user1','Brain-Computer Interfaces: Merging Mind and Machine','Exploring the cutting-edge technology that connects the human brain directly to computers.','Brain-Computer Interfaces (BCIs) are revolutionary technologies that create a direct communication pathway between the brain and an external device. These systems can interpret brain signals and translate them into commands, offering unprecedented possibilities for individuals with severe motor impairments to control prosthetic limbs, communicate, or interact with computers using only their thoughts. Beyond medical applications, BCIs are also being explored for enhancing human capabilities, from improving focus to enabling direct mental control of complex machinery. Ethical considerations, such as privacy of thought, data security, and potential misuse, are paramount as this technology advances. While still in early development, BCIs hold the promise of fundamentally altering how humans interact with technology and even redefine what it means to be human, opening new frontiers in neuroscience and human-computer interaction.','2025-06-15 10:20:00','2025-06-15 10:20:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000006','Neuroscience');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000006','BCI');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000006','user2');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000006','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000006','user1','Brain-Computer Interfaces: Merging Mind and Machine','Exploring the cutting-edge technology that connects the human brain directly to computers.','Brain-Computer Interfaces (BCIs) are revolutionary technologies that create a direct communication pathway between the brain and an external device. These systems can interpret brain signals and translate them into commands, offering unprecedented possibilities for individuals with severe motor impairments to control prosthetic limbs, communicate, or interact with computers using only their thoughts. Beyond medical applications, BCIs are also being explored for enhancing human capabilities, from improving focus to enabling direct mental control of complex machinery. Ethical considerations, such as privacy of thought, data security, and potential misuse, are paramount as this technology advances. While still in early development, BCIs hold the promise of fundamentally altering how humans interact with technology and even redefine what it means to be human, opening new frontiers in neuroscience and human-computer interaction.','2025-06-15 10:20:00','2025-06-15 10:20:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000006','neuroscience');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000006','bci');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000006','user2');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000006','user4');
 
 -- Article 7: user2 - Cybersecurity
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000007','user2','The Evolving Landscape of Cybersecurity Threats','Understanding the latest cyber threats and strategies for digital defense.','The digital world is constantly evolving, and with it, the landscape of cybersecurity threats. From sophisticated ransomware attacks and state-sponsored espionage to pervasive phishing scams and supply chain vulnerabilities, protecting digital assets has never been more critical. Modern cybersecurity strategies go beyond traditional firewalls and antivirus software, embracing multi-layered approaches that include zero-trust architectures, advanced threat intelligence, AI-driven anomaly detection, and robust incident response plans. The human element remains the weakest link, emphasizing the importance of continuous security awareness training for all users. As more devices connect to the internet (IoT) and cloud computing becomes ubiquitous, the attack surface expands, demanding proactive and adaptive defense mechanisms. Staying informed about emerging threats and implementing best practices are essential for individuals and organizations alike to safeguard their data and maintain digital resilience in an increasingly interconnected world.','2025-06-17 13:05:00','2025-06-17 13:05:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000007','Cybersecurity');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000007','Security');
-INSERT INTO Comments VALUES(5,'01980000-0000-0000-0000-000000000007','user1','Zero-trust is definitely the way forward.','2025-06-17 13:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000007','user2','The Evolving Landscape of Cybersecurity Threats','Understanding the latest cyber threats and strategies for digital defense.','The digital world is constantly evolving, and with it, the landscape of cybersecurity threats. From sophisticated ransomware attacks and state-sponsored espionage to pervasive phishing scams and supply chain vulnerabilities, protecting digital assets has never been more critical. Modern cybersecurity strategies go beyond traditional firewalls and antivirus software, embracing multi-layered approaches that include zero-trust architectures, advanced threat intelligence, AI-driven anomaly detection, and robust incident response plans. The human element remains the weakest link, emphasizing the importance of continuous security awareness training for all users. As more devices connect to the internet (IoT) and cloud computing becomes ubiquitous, the attack surface expands, demanding proactive and adaptive defense mechanisms. Staying informed about emerging threats and implementing best practices are essential for individuals and organizations alike to safeguard their data and maintain digital resilience in an increasingly interconnected world.','2025-06-17 13:05:00','2025-06-17 13:05:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000007','cybersecurity');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000007','security');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000007','user1','Zero-trust is definitely the way forward.','2025-06-17 13:30:00');
 
 -- Article 8: user3 - Sustainable Materials
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000008','user3','Innovations in Sustainable Materials: Building a Greener Future','How new materials are reducing environmental impact in various industries.','The development of sustainable materials is crucial for addressing global environmental challenges, from climate change to resource depletion. Researchers are innovating across various sectors to create materials with reduced carbon footprints, enhanced recyclability, and lower toxicity. Examples include biodegradable plastics derived from plant starches, self-healing concrete that extends infrastructure lifespan, and mycelium-based composites grown from fungi as alternatives to traditional packaging and building materials. These innovations aim to minimize waste, conserve natural resources, and reduce energy consumption throughout a product''s lifecycle. Beyond their environmental benefits, sustainable materials often offer unique properties and performance advantages, driving a paradigm shift in manufacturing and construction. Investing in and adopting these materials is essential for transitioning to a circular economy and building a truly greener, more resilient future for generations to come.','2025-06-19 09:50:00','2025-06-19 09:50:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000008','Sustainability');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000008','Materials_Science');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000008','user5');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000008','user2');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000008','user3','Innovations in Sustainable Materials: Building a Greener Future','How new materials are reducing environmental impact in various industries.','The development of sustainable materials is crucial for addressing global environmental challenges, from climate change to resource depletion. Researchers are innovating across various sectors to create materials with reduced carbon footprints, enhanced recyclability, and lower toxicity. Examples include biodegradable plastics derived from plant starches, self-healing concrete that extends infrastructure lifespan, and mycelium-based composites grown from fungi as alternatives to traditional packaging and building materials. These innovations aim to minimize waste, conserve natural resources, and reduce energy consumption throughout a product''s lifecycle. Beyond their environmental benefits, sustainable materials often offer unique properties and performance advantages, driving a paradigm shift in manufacturing and construction. Investing in and adopting these materials is essential for transitioning to a circular economy and building a truly greener, more resilient future for generations to come.','2025-06-19 09:50:00','2025-06-19 09:50:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000008','sustainability');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000008','materials_science');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000008','user5');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000008','user2');
 
 -- Article 9: user4 - AI in Healthcare
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000009','user4','AI in Healthcare: Transforming Diagnostics and Treatment','The impact of artificial intelligence on medical advancements and patient care.','Artificial intelligence is rapidly revolutionizing healthcare, offering unprecedented capabilities in diagnostics, personalized treatment, and drug discovery. AI-powered tools can analyze vast amounts of medical data, including patient records, imaging scans, and genomic information, to assist clinicians in making more accurate and timely diagnoses. Machine learning algorithms are being used to identify patterns in disease progression, predict patient outcomes, and even design novel drug compounds, significantly accelerating the research and development process. Furthermore, AI-driven virtual assistants and remote monitoring systems are enhancing patient engagement and providing more accessible care. While the integration of AI into healthcare presents challenges, such as data privacy, regulatory hurdles, and the need for robust validation, its potential to improve patient outcomes, reduce costs, and streamline healthcare operations is immense. AI is not replacing human doctors but rather augmenting their capabilities, leading to a more efficient, precise, and patient-centric healthcare system.','2025-06-21 14:00:00','2025-06-21 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000009','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000009','Healthcare');
-INSERT INTO Comments VALUES(6,'01980000-0000-0000-0000-000000000009','user3','Exciting potential, but data security is key.','2025-06-21 14:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000009','user4','AI in Healthcare: Transforming Diagnostics and Treatment','The impact of artificial intelligence on medical advancements and patient care.','Artificial intelligence is rapidly revolutionizing healthcare, offering unprecedented capabilities in diagnostics, personalized treatment, and drug discovery. AI-powered tools can analyze vast amounts of medical data, including patient records, imaging scans, and genomic information, to assist clinicians in making more accurate and timely diagnoses. Machine learning algorithms are being used to identify patterns in disease progression, predict patient outcomes, and even design novel drug compounds, significantly accelerating the research and development process. Furthermore, AI-driven virtual assistants and remote monitoring systems are enhancing patient engagement and providing more accessible care. While the integration of AI into healthcare presents challenges, such as data privacy, regulatory hurdles, and the need for robust validation, its potential to improve patient outcomes, reduce costs, and streamline healthcare operations is immense. AI is not replacing human doctors but rather augmenting their capabilities, leading to a more efficient, precise, and patient-centric healthcare system.','2025-06-21 14:00:00','2025-06-21 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000009','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000009','healthcare');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000009','user3','Exciting potential, but data security is key.','2025-06-21 14:30:00');
 
 -- Article 10: user5 - 5G Technology
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000010','user5','The Promise of 5G: Enabling the Next Generation of Connectivity','How 5G technology is paving the way for new applications and services.','5G, the fifth generation of cellular technology, promises to revolutionize connectivity with its unprecedented speed, ultra-low latency, and massive capacity. Beyond faster mobile internet, 5G is designed to enable a new era of interconnected devices and services, from smart cities and autonomous vehicles to advanced augmented reality and remote surgery. Its high bandwidth supports real-time data processing for IoT devices, while its low latency is critical for mission-critical applications where immediate response is required. The deployment of 5G networks involves significant infrastructure investment and addresses challenges like spectrum allocation and security. However, its transformative potential for various industries, including manufacturing, healthcare, and entertainment, is immense. 5G is not just an incremental upgrade; it''s a foundational technology that will unlock innovation and drive economic growth, fundamentally changing how we live, work, and interact with the digital world.','2025-06-23 10:10:00','2025-06-23 10:10:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000010','5G');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000010','Connectivity');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000010','user1');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000010','user5','The Promise of 5G: Enabling the Next Generation of Connectivity','How 5G technology is paving the way for new applications and services.','5G, the fifth generation of cellular technology, promises to revolutionize connectivity with its unprecedented speed, ultra-low latency, and massive capacity. Beyond faster mobile internet, 5G is designed to enable a new era of interconnected devices and services, from smart cities and autonomous vehicles to advanced augmented reality and remote surgery. Its high bandwidth supports real-time data processing for IoT devices, while its low latency is critical for mission-critical applications where immediate response is required. The deployment of 5G networks involves significant infrastructure investment and addresses challenges like spectrum allocation and security. However, its transformative potential for various industries, including manufacturing, healthcare, and entertainment, is immense. 5G is not just an incremental upgrade; it''s a foundational technology that will unlock innovation and drive economic growth, fundamentally changing how we live, work, and interact with the digital world.','2025-06-23 10:10:00','2025-06-23 10:10:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000010','5g');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000010','connectivity');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000010','user1');
 
 -- Article 11: user1 - Metaverse
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000011','user1','The Metaverse: A New Digital Frontier','Exploring the concepts, technologies, and potential of the immersive metaverse.','The metaverse represents a persistent, interconnected virtual world where users can interact with each other, digital objects, and AI-driven entities in real-time. It''s envisioned as the next evolution of the internet, moving beyond flat web pages to immersive 3D experiences. Key technologies underpinning the metaverse include virtual reality (VR), augmented reality (AR), blockchain for digital ownership (NFTs), and advanced networking (like 5G). Its potential applications span across gaming, social interaction, education, commerce, and even remote work, offering new avenues for creativity and economic activity. Challenges include interoperability between different platforms, robust security and privacy protocols, and the development of powerful hardware. While the full realization of the metaverse is still years away, early iterations are already emerging, hinting at a future where our digital and physical lives become increasingly intertwined, creating new forms of social and economic engagement.','2025-06-01 16:00:00','2025-06-01 16:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000011','Metaverse');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000011','VR');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000011','AR');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000011','user3');
-INSERT INTO Comments VALUES(7,'01980000-0000-0000-0000-000000000011','user4','Interoperability is definitely the biggest hurdle.','2025-06-01 16:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000011','user1','The Metaverse: A New Digital Frontier','Exploring the concepts, technologies, and potential of the immersive metaverse.','The metaverse represents a persistent, interconnected virtual world where users can interact with each other, digital objects, and AI-driven entities in real-time. It''s envisioned as the next evolution of the internet, moving beyond flat web pages to immersive 3D experiences. Key technologies underpinning the metaverse include virtual reality (VR), augmented reality (AR), blockchain for digital ownership (NFTs), and advanced networking (like 5G). Its potential applications span across gaming, social interaction, education, commerce, and even remote work, offering new avenues for creativity and economic activity. Challenges include interoperability between different platforms, robust security and privacy protocols, and the development of powerful hardware. While the full realization of the metaverse is still years away, early iterations are already emerging, hinting at a future where our digital and physical lives become increasingly intertwined, creating new forms of social and economic engagement.','2025-06-01 16:00:00','2025-06-01 16:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000011','metaverse');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000011','vr');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000011','ar');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000011','user3');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000011','user4','Interoperability is definitely the biggest hurdle.','2025-06-01 16:30:00');
 
 -- Article 12: user2 - Gene Therapy
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000012','user2','Gene Therapy: Hope for Genetic Diseases','The advancements and challenges in using gene therapy to treat inherited disorders.','Gene therapy is a groundbreaking medical approach that aims to treat or prevent disease by correcting underlying genetic problems. It involves introducing, removing, or modifying genetic material (DNA or RNA) into a patient''s cells to restore normal function or combat disease. Early successes have been seen in treating rare genetic disorders like spinal muscular atrophy and certain forms of inherited blindness. The technology typically uses modified viruses as vectors to deliver the therapeutic genes into target cells. Challenges include ensuring precise gene delivery, avoiding off-target effects, and managing immune responses. Despite these complexities, ongoing research is rapidly expanding the range of diseases that gene therapy can address, including cancer and infectious diseases. It represents a significant paradigm shift in medicine, moving from symptom management to addressing the root genetic causes of illness, offering new hope for millions affected by previously untreatable conditions.','2025-06-03 09:00:00','2025-06-03 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000012','Gene_Therapy');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000012','Medicine');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000012','user5');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000012','user2','Gene Therapy: Hope for Genetic Diseases','The advancements and challenges in using gene therapy to treat inherited disorders.','Gene therapy is a groundbreaking medical approach that aims to treat or prevent disease by correcting underlying genetic problems. It involves introducing, removing, or modifying genetic material (DNA or RNA) into a patient''s cells to restore normal function or combat disease. Early successes have been seen in treating rare genetic disorders like spinal muscular atrophy and certain forms of inherited blindness. The technology typically uses modified viruses as vectors to deliver the therapeutic genes into target cells. Challenges include ensuring precise gene delivery, avoiding off-target effects, and managing immune responses. Despite these complexities, ongoing research is rapidly expanding the range of diseases that gene therapy can address, including cancer and infectious diseases. It represents a significant paradigm shift in medicine, moving from symptom management to addressing the root genetic causes of illness, offering new hope for millions affected by previously untreatable conditions.','2025-06-03 09:00:00','2025-06-03 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000012','gene_therapy');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000012','medicine');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000012','user5');
 
 -- Article 13: user3 - Edge Computing
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000013','user3','Edge Computing: Bringing Computation Closer to Data','How edge computing is transforming data processing for IoT and real-time applications.','Edge computing is a distributed computing paradigm that brings computation and data storage closer to the sources of data, rather than relying solely on a centralized cloud or data center. This approach is particularly beneficial for Internet of Things (IoT) devices, autonomous vehicles, and real-time applications where low latency and immediate processing are critical. By processing data at the "edge" of the network, bandwidth consumption is reduced, response times are improved, and data privacy can be enhanced. Edge computing complements cloud computing, with the edge handling immediate, localized processing and the cloud managing long-term storage, complex analytics, and broader insights. Challenges include managing distributed infrastructure, ensuring security across numerous endpoints, and standardizing protocols. However, its ability to enable truly responsive and intelligent systems makes edge computing a foundational technology for the next wave of digital transformation across industries from manufacturing to smart cities.','2025-06-06 14:00:00','2025-06-06 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000013','Edge_Computing');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000013','IoT');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000013','user1');
-INSERT INTO Comments VALUES(8,'01980000-0000-0000-0000-000000000013','user2','Latency reduction is huge for industrial IoT.','2025-06-06 14:45:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000013','user3','Edge Computing: Bringing Computation Closer to Data','How edge computing is transforming data processing for IoT and real-time applications.','Edge computing is a distributed computing paradigm that brings computation and data storage closer to the sources of data, rather than relying solely on a centralized cloud or data center. This approach is particularly beneficial for Internet of Things (IoT) devices, autonomous vehicles, and real-time applications where low latency and immediate processing are critical. By processing data at the "edge" of the network, bandwidth consumption is reduced, response times are improved, and data privacy can be enhanced. Edge computing complements cloud computing, with the edge handling immediate, localized processing and the cloud managing long-term storage, complex analytics, and broader insights. Challenges include managing distributed infrastructure, ensuring security across numerous endpoints, and standardizing protocols. However, its ability to enable truly responsive and intelligent systems makes edge computing a foundational technology for the next wave of digital transformation across industries from manufacturing to smart cities.','2025-06-06 14:00:00','2025-06-06 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000013','edge_computing');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000013','iot');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000013','user1');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000013','user2','Latency reduction is huge for industrial IoT.','2025-06-06 14:45:00');
 
 -- Article 14: user4 - Personalized Medicine
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000014','user4','Personalized Medicine: Tailoring Healthcare to the Individual','How genomics and data are enabling customized medical treatments.','Personalized medicine, also known as precision medicine, is a revolutionary approach to healthcare that tailors medical decisions, treatments, practices, or products to the individual patient. It moves beyond a "one-size-fits-all" model by leveraging an individual''s unique genetic makeup, environment, and lifestyle to optimize prevention, diagnosis, and treatment strategies. Advances in genomics, bioinformatics, and artificial intelligence are driving this field, allowing for a deeper understanding of disease mechanisms at a molecular level. This enables the development of targeted therapies that are more effective and have fewer side effects for specific patient subgroups. While challenges remain in data integration, privacy, and cost, personalized medicine holds immense promise for transforming healthcare, leading to more precise diagnoses, better treatment outcomes, and a more proactive approach to health management, ultimately improving the quality of life for patients worldwide.','2025-06-08 11:30:00','2025-06-08 11:30:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000014','Medicine');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000014','Genomics');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000014','user3');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000014','user1');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000014','user4','Personalized Medicine: Tailoring Healthcare to the Individual','How genomics and data are enabling customized medical treatments.','Personalized medicine, also known as precision medicine, is a revolutionary approach to healthcare that tailors medical decisions, treatments, practices, or products to the individual patient. It moves beyond a "one-size-fits-all" model by leveraging an individual''s unique genetic makeup, environment, and lifestyle to optimize prevention, diagnosis, and treatment strategies. Advances in genomics, bioinformatics, and artificial intelligence are driving this field, allowing for a deeper understanding of disease mechanisms at a molecular level. This enables the development of targeted therapies that are more effective and have fewer side effects for specific patient subgroups. While challenges remain in data integration, privacy, and cost, personalized medicine holds immense promise for transforming healthcare, leading to more precise diagnoses, better treatment outcomes, and a more proactive approach to health management, ultimately improving the quality of life for patients worldwide.','2025-06-08 11:30:00','2025-06-08 11:30:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000014','medicine');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000014','genomics');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000014','user3');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000014','user1');
 
 -- Article 15: user5 - Robotics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000015','user5','The Evolution of Robotics: From Industrial Arms to Humanoid Companions','Tracing the advancements and future of intelligent machines.','Robotics has evolved dramatically from its early days of rigid industrial arms performing repetitive tasks to sophisticated, intelligent machines capable of complex interactions. Modern robotics integrates advancements in artificial intelligence, machine learning, computer vision, and advanced sensors, enabling robots to perceive their environment, learn from experience, and adapt to changing conditions. This has opened up new applications in fields like healthcare (surgical robots, assistive devices), logistics (autonomous warehouses), exploration (Mars rovers), and even personal assistance (humanoid robots). Challenges include ensuring safety in human-robot interaction, improving dexterity and adaptability, and navigating ethical considerations. As robots become more autonomous and integrated into daily life, their impact on society, economy, and human labor will continue to grow, pushing the boundaries of automation and redefining the relationship between humans and machines.','2025-06-10 15:45:00','2025-06-10 15:45:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000015','Robotics');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000015','Automation');
-INSERT INTO Comments VALUES(9,'01980000-0000-0000-0000-000000000015','user4','I wonder how long until we have true household robots.','2025-06-10 16:00:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000015','user5','The Evolution of Robotics: From Industrial Arms to Humanoid Companions','Tracing the advancements and future of intelligent machines.','Robotics has evolved dramatically from its early days of rigid industrial arms performing repetitive tasks to sophisticated, intelligent machines capable of complex interactions. Modern robotics integrates advancements in artificial intelligence, machine learning, computer vision, and advanced sensors, enabling robots to perceive their environment, learn from experience, and adapt to changing conditions. This has opened up new applications in fields like healthcare (surgical robots, assistive devices), logistics (autonomous warehouses), exploration (Mars rovers), and even personal assistance (humanoid robots). Challenges include ensuring safety in human-robot interaction, improving dexterity and adaptability, and navigating ethical considerations. As robots become more autonomous and integrated into daily life, their impact on society, economy, and human labor will continue to grow, pushing the boundaries of automation and redefining the relationship between humans and machines.','2025-06-10 15:45:00','2025-06-10 15:45:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000015','robotics');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000015','automation');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000015','user4','I wonder how long until we have true household robots.','2025-06-10 16:00:00');
 
 -- Article 16: user1 - Climate Modeling
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000016','user1','Climate Modeling: Predicting Earth''s Future','How supercomputers simulate climate change and project future scenarios.','Climate modeling uses complex mathematical equations and powerful supercomputers to simulate Earth''s climate system, helping scientists understand past climate changes and project future scenarios. These models incorporate vast amounts of data on atmospheric composition, ocean currents, ice sheets, and land surface processes. They are essential tools for predicting the impacts of greenhouse gas emissions on global temperatures, sea levels, and extreme weather events. While models have inherent uncertainties, they have consistently shown a clear human influence on recent warming trends. Continuous improvements in computational power and scientific understanding lead to more refined and accurate models, providing crucial information for policymakers to develop strategies for climate change mitigation and adaptation. Understanding these models is key to comprehending the urgency and scale of the climate crisis facing our planet.','2025-06-12 07:30:00','2025-06-12 07:30:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000016','Climate_Change');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000016','Science');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000016','user1','Climate Modeling: Predicting Earth''s Future','How supercomputers simulate climate change and project future scenarios.','Climate modeling uses complex mathematical equations and powerful supercomputers to simulate Earth''s climate system, helping scientists understand past climate changes and project future scenarios. These models incorporate vast amounts of data on atmospheric composition, ocean currents, ice sheets, and land surface processes. They are essential tools for predicting the impacts of greenhouse gas emissions on global temperatures, sea levels, and extreme weather events. While models have inherent uncertainties, they have consistently shown a clear human influence on recent warming trends. Continuous improvements in computational power and scientific understanding lead to more refined and accurate models, providing crucial information for policymakers to develop strategies for climate change mitigation and adaptation. Understanding these models is key to comprehending the urgency and scale of the climate crisis facing our planet.','2025-06-12 07:30:00','2025-06-12 07:30:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000016','climate_change');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000016','science');
 
 -- Article 17: user2 - Cybersecurity in IoT
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000017','user2','Securing the Internet of Things: Challenges and Solutions','Addressing the unique cybersecurity risks of interconnected devices.','The proliferation of Internet of Things (IoT) devices, from smart home appliances to industrial sensors, has created an expansive and often vulnerable attack surface for cyber threats. Securing the IoT presents unique challenges due to the sheer number and diversity of devices, their limited processing power, and often inadequate security features. Common vulnerabilities include weak default passwords, unpatched firmware, insecure communication protocols, and lack of proper data encryption. Solutions involve implementing robust authentication mechanisms, end-to-end encryption, regular security updates, and secure-by-design principles from development. Furthermore, network segmentation and continuous monitoring are crucial for detecting and mitigating threats. As IoT devices become more deeply integrated into critical infrastructure and daily life, ensuring their security is paramount to prevent widespread disruptions, data breaches, and privacy violations, requiring a collaborative effort from manufacturers, developers, and users.','2025-06-14 11:10:00','2025-06-14 11:10:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000017','IoT');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000017','Cybersecurity');
-INSERT INTO Comments VALUES(10,'01980000-0000-0000-0000-000000000017','user3','So many devices, so little security awareness.','2025-06-14 11:40:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000017','user2','Securing the Internet of Things: Challenges and Solutions','Addressing the unique cybersecurity risks of interconnected devices.','The proliferation of Internet of Things (IoT) devices, from smart home appliances to industrial sensors, has created an expansive and often vulnerable attack surface for cyber threats. Securing the IoT presents unique challenges due to the sheer number and diversity of devices, their limited processing power, and often inadequate security features. Common vulnerabilities include weak default passwords, unpatched firmware, insecure communication protocols, and lack of proper data encryption. Solutions involve implementing robust authentication mechanisms, end-to-end encryption, regular security updates, and secure-by-design principles from development. Furthermore, network segmentation and continuous monitoring are crucial for detecting and mitigating threats. As IoT devices become more deeply integrated into critical infrastructure and daily life, ensuring their security is paramount to prevent widespread disruptions, data breaches, and privacy violations, requiring a collaborative effort from manufacturers, developers, and users.','2025-06-14 11:10:00','2025-06-14 11:10:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000017','iot');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000017','cybersecurity');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000017','user3','So many devices, so little security awareness.','2025-06-14 11:40:00');
 
 -- Article 18: user3 - AI in Education
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000018','user3','AI in Education: Personalized Learning and Future Classrooms','How artificial intelligence is reshaping the educational landscape.','Artificial intelligence is poised to transform education by offering personalized learning experiences, automating administrative tasks, and providing intelligent tutoring systems. AI-powered platforms can adapt content and pace to individual student needs, identifying areas where a student struggles and offering targeted support. This allows educators to focus more on complex teaching tasks and individual mentorship. AI can also assist with grading, provide real-time feedback, and analyze learning patterns to improve curriculum design. Challenges include ensuring equitable access to technology, addressing data privacy concerns, and preventing over-reliance on AI, which could diminish critical thinking skills. However, when integrated thoughtfully, AI can augment human teaching, making education more accessible, engaging, and effective for diverse learners, preparing them for a future increasingly shaped by technology.','2025-06-16 16:20:00','2025-06-16 16:20:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000018','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000018','Education');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000018','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000018','user3','AI in Education: Personalized Learning and Future Classrooms','How artificial intelligence is reshaping the educational landscape.','Artificial intelligence is poised to transform education by offering personalized learning experiences, automating administrative tasks, and providing intelligent tutoring systems. AI-powered platforms can adapt content and pace to individual student needs, identifying areas where a student struggles and offering targeted support. This allows educators to focus more on complex teaching tasks and individual mentorship. AI can also assist with grading, provide real-time feedback, and analyze learning patterns to improve curriculum design. Challenges include ensuring equitable access to technology, addressing data privacy concerns, and preventing over-reliance on AI, which could diminish critical thinking skills. However, when integrated thoughtfully, AI can augment human teaching, making education more accessible, engaging, and effective for diverse learners, preparing them for a future increasingly shaped by technology.','2025-06-16 16:20:00','2025-06-16 16:20:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000018','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000018','education');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000018','user4');
 
 -- Article 19: user4 - Advanced Materials
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000019','user4','Metamaterials: Engineering the Properties of Light and Sound','Exploring synthetic materials with extraordinary properties not found in nature.','Metamaterials are artificially engineered materials designed to possess properties not found in naturally occurring substances. Their unique characteristics arise not from their chemical composition, but from their meticulously designed internal structure, often at scales smaller than the wavelength of light or sound they interact with. This allows them to manipulate electromagnetic waves (including light) or acoustic waves in unprecedented ways, leading to exotic phenomena like negative refractive index, perfect lensing, and cloaking. Potential applications are vast, ranging from invisible cloaks and super-lenses for medical imaging to highly efficient antennas and acoustic insulation. While still largely in the research phase, metamaterials represent a revolutionary frontier in materials science, promising to unlock new technological capabilities that were once considered science fiction, fundamentally changing how we interact with and control energy.','2025-06-18 08:45:00','2025-06-18 08:45:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000019','Materials_Science');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000019','Physics');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000019','user5');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000019','user1');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000019','user4','Metamaterials: Engineering the Properties of Light and Sound','Exploring synthetic materials with extraordinary properties not found in nature.','Metamaterials are artificially engineered materials designed to possess properties not found in naturally occurring substances. Their unique characteristics arise not from their chemical composition, but from their meticulously designed internal structure, often at scales smaller than the wavelength of light or sound they interact with. This allows them to manipulate electromagnetic waves (including light) or acoustic waves in unprecedented ways, leading to exotic phenomena like negative refractive index, perfect lensing, and cloaking. Potential applications are vast, ranging from invisible cloaks and super-lenses for medical imaging to highly efficient antennas and acoustic insulation. While still largely in the research phase, metamaterials represent a revolutionary frontier in materials science, promising to unlock new technological capabilities that were once considered science fiction, fundamentally changing how we interact with and control energy.','2025-06-18 08:45:00','2025-06-18 08:45:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000019','materials_science');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000019','physics');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000019','user5');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000019','user1');
 
 -- Article 20: user5 - Smart Cities
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000020','user5','Smart Cities: Integrating Technology for Urban Living','How technology is being used to improve efficiency, sustainability, and quality of life in urban areas.','Smart cities leverage advanced technologies like IoT sensors, AI, big data analytics, and robust connectivity (e.g., 5G) to improve urban infrastructure and services. The goal is to enhance efficiency, sustainability, and the quality of life for residents. Applications include intelligent traffic management systems to reduce congestion, smart grids for optimized energy distribution, waste management systems that track fill levels, and public safety initiatives utilizing connected cameras. Data collected from these systems provides insights for urban planning and resource allocation. Challenges involve ensuring data privacy and security, addressing digital divides, and securing public acceptance. However, smart cities hold immense potential to create more livable, resilient, and environmentally friendly urban environments, addressing the complex demands of growing global populations and fostering sustainable development for the future.','2025-06-20 12:00:00','2025-06-20 12:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000020','Smart_Cities');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000020','Urban_Planning');
-INSERT INTO Comments VALUES(11,'01980000-0000-0000-0000-000000000020','user2','Privacy concerns are big here.','2025-06-20 12:45:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000020','user5','Smart Cities: Integrating Technology for Urban Living','How technology is being used to improve efficiency, sustainability, and quality of life in urban areas.','Smart cities leverage advanced technologies like IoT sensors, AI, big data analytics, and robust connectivity (e.g., 5G) to improve urban infrastructure and services. The goal is to enhance efficiency, sustainability, and the quality of life for residents. Applications include intelligent traffic management systems to reduce congestion, smart grids for optimized energy distribution, waste management systems that track fill levels, and public safety initiatives utilizing connected cameras. Data collected from these systems provides insights for urban planning and resource allocation. Challenges involve ensuring data privacy and security, addressing digital divides, and securing public acceptance. However, smart cities hold immense potential to create more livable, resilient, and environmentally friendly urban environments, addressing the complex demands of growing global populations and fostering sustainable development for the future.','2025-06-20 12:00:00','2025-06-20 12:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000020','smart_cities');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000020','urban_planning');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000020','user2','Privacy concerns are big here.','2025-06-20 12:45:00');
 
 -- Article 21: user1 - Space Tourism
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000021','user1','Space Tourism: The Final Frontier for Travelers','The emergence of commercial spaceflight and its implications for future travel.','Space tourism, once a distant dream, is rapidly becoming a reality, with private companies offering suborbital and orbital flights to paying customers. This emerging industry promises to democratize access to space, allowing individuals to experience weightlessness, view Earth from orbit, and witness the vastness of the cosmos firsthand. While currently exclusive due to high costs, advancements in reusable rocket technology and increasing competition are expected to drive prices down over time. Challenges include ensuring passenger safety, developing robust training protocols, and addressing the environmental impact of increased launches. Beyond the thrill of spaceflight, space tourism could spur innovation, create new industries, and inspire a greater appreciation for our planet. As the industry matures, it will redefine the boundaries of travel and open up unprecedented opportunities for human exploration and experience beyond Earth''s atmosphere.','2025-06-22 09:30:00','2025-06-22 09:30:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000021','Space');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000021','Tourism');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000021','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000021','user1','Space Tourism: The Final Frontier for Travelers','The emergence of commercial spaceflight and its implications for future travel.','Space tourism, once a distant dream, is rapidly becoming a reality, with private companies offering suborbital and orbital flights to paying customers. This emerging industry promises to democratize access to space, allowing individuals to experience weightlessness, view Earth from orbit, and witness the vastness of the cosmos firsthand. While currently exclusive due to high costs, advancements in reusable rocket technology and increasing competition are expected to drive prices down over time. Challenges include ensuring passenger safety, developing robust training protocols, and addressing the environmental impact of increased launches. Beyond the thrill of spaceflight, space tourism could spur innovation, create new industries, and inspire a greater appreciation for our planet. As the industry matures, it will redefine the boundaries of travel and open up unprecedented opportunities for human exploration and experience beyond Earth''s atmosphere.','2025-06-22 09:30:00','2025-06-22 09:30:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000021','space');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000021','tourism');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000021','user4');
 
 -- Article 22: user2 - Quantum Cryptography
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000022','user2','Quantum Cryptography: Unbreakable Security for the Digital Age','How quantum mechanics can provide truly secure communication.','Quantum cryptography, particularly Quantum Key Distribution (QKD), offers a revolutionary approach to secure communication by leveraging the fundamental laws of quantum mechanics. Unlike traditional encryption, which relies on computational complexity, QKD ensures that any attempt by an eavesdropper to intercept a quantum key will inevitably disturb the quantum state, immediately alerting the communicating parties. This makes it theoretically unbreakable. QKD systems transmit information using individual photons, whose quantum properties encode the key. While current implementations are limited by distance and require specialized hardware, ongoing research aims to extend its reach and integrate it into existing networks. Quantum cryptography is crucial for protecting highly sensitive data in the post-quantum era, where classical encryption methods may become vulnerable to powerful quantum computers, ensuring the long-term security of our digital infrastructure.','2025-06-24 14:55:00','2025-06-24 14:55:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000022','Quantum_Computing');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000022','Cybersecurity');
-INSERT INTO Comments VALUES(12,'01980000-0000-0000-0000-000000000022','user5','This is the future of secure communication.','2025-06-24 15:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000022','user2','Quantum Cryptography: Unbreakable Security for the Digital Age','How quantum mechanics can provide truly secure communication.','Quantum cryptography, particularly Quantum Key Distribution (QKD), offers a revolutionary approach to secure communication by leveraging the fundamental laws of quantum mechanics. Unlike traditional encryption, which relies on computational complexity, QKD ensures that any attempt by an eavesdropper to intercept a quantum key will inevitably disturb the quantum state, immediately alerting the communicating parties. This makes it theoretically unbreakable. QKD systems transmit information using individual photons, whose quantum properties encode the key. While current implementations are limited by distance and require specialized hardware, ongoing research aims to extend its reach and integrate it into existing networks. Quantum cryptography is crucial for protecting highly sensitive data in the post-quantum era, where classical encryption methods may become vulnerable to powerful quantum computers, ensuring the long-term security of our digital infrastructure.','2025-06-24 14:55:00','2025-06-24 14:55:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000022','quantum_computing');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000022','cybersecurity');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000022','user5','This is the future of secure communication.','2025-06-24 15:30:00');
 
 -- Article 23: user3 - Bio-printing
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000023','user3','Bio-printing: Engineering Organs and Tissues','The revolutionary technology of 3D printing living cells and tissues.','Bio-printing is an additive manufacturing technique that uses "bio-inks" (mixtures of living cells and biocompatible materials) to create complex three-dimensional biological structures, layer by layer. This revolutionary technology holds immense promise for regenerative medicine, enabling the fabrication of tissues and organs for transplantation, drug testing, and disease modeling. Scientists are working on printing functional organs like kidneys, hearts, and livers, which could address the critical shortage of donor organs. Beyond full organ replacement, bio-printing is also used to create tissue models for studying diseases and developing new drugs more effectively. Challenges include ensuring the viability and functionality of printed cells, vascularization (creating blood vessel networks), and scaling up production. Despite these hurdles, bio-printing is a rapidly advancing field that could fundamentally transform medicine, offering personalized treatments and new avenues for biological research.','2025-06-26 10:00:00','2025-06-26 10:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000023','Biotechnology');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000023','Medicine');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000023','user1');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000023','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000023','user3','Bio-printing: Engineering Organs and Tissues','The revolutionary technology of 3D printing living cells and tissues.','Bio-printing is an additive manufacturing technique that uses "bio-inks" (mixtures of living cells and biocompatible materials) to create complex three-dimensional biological structures, layer by layer. This revolutionary technology holds immense promise for regenerative medicine, enabling the fabrication of tissues and organs for transplantation, drug testing, and disease modeling. Scientists are working on printing functional organs like kidneys, hearts, and livers, which could address the critical shortage of donor organs. Beyond full organ replacement, bio-printing is also used to create tissue models for studying diseases and developing new drugs more effectively. Challenges include ensuring the viability and functionality of printed cells, vascularization (creating blood vessel networks), and scaling up production. Despite these hurdles, bio-printing is a rapidly advancing field that could fundamentally transform medicine, offering personalized treatments and new avenues for biological research.','2025-06-26 10:00:00','2025-06-26 10:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000023','biotechnology');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000023','medicine');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000023','user1');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000023','user4');
 
 -- Article 24: user4 - AI in Art
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000024','user4','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-28 13:10:00','2025-06-28 13:10:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000024','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000024','Art');
-INSERT INTO Comments VALUES(13,'01980000-0000-0000-0000-000000000024','user2','Is it truly creative, or just complex pattern matching?','2025-06-28 13:45:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000024','user4','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-28 13:10:00','2025-06-28 13:10:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000024','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000024','art');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000024','user2','Is it truly creative, or just complex pattern matching?','2025-06-28 13:45:00');
 
 -- Article 25: user5 - Nanotechnology
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000025','user5','Nanotechnology: Engineering at the Atomic Scale','The science of manipulating matter at the nanoscale for revolutionary applications.','Nanotechnology involves manipulating matter at an atomic, molecular, and supramolecular scale, typically ranging from 1 to 100 nanometers. This field explores how materials behave differently at these minuscule dimensions, often exhibiting novel physical, chemical, and biological properties. Applications are incredibly diverse, spanning medicine (targeted drug delivery, diagnostics), electronics (smaller, faster chips), energy (more efficient solar cells, batteries), and materials science (stronger, lighter composites). Nanoparticles are already found in sunscreens, cosmetics, and some food products. Challenges include ensuring safety and environmental impact, developing scalable manufacturing processes, and understanding long-term effects. Despite these hurdles, nanotechnology holds immense potential to revolutionize nearly every industry, offering solutions to complex problems and paving the way for unprecedented advancements in science and technology by building from the ground up at the molecular level.','2025-06-30 16:00:00','2025-06-30 16:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000025','Nanotechnology');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000025','Materials_Science');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000025','user3');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000025','user1');
-INSERT INTO Comments VALUES(14,'01980000-0000-0000-0000-000000000025','user4','Amazing potential, but safety needs careful consideration.','2025-06-30 16:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000025','user5','Nanotechnology: Engineering at the Atomic Scale','The science of manipulating matter at the nanoscale for revolutionary applications.','Nanotechnology involves manipulating matter at an atomic, molecular, and supramolecular scale, typically ranging from 1 to 100 nanometers. This field explores how materials behave differently at these minuscule dimensions, often exhibiting novel physical, chemical, and biological properties. Applications are incredibly diverse, spanning medicine (targeted drug delivery, diagnostics), electronics (smaller, faster chips), energy (more efficient solar cells, batteries), and materials science (stronger, lighter composites). Nanoparticles are already found in sunscreens, cosmetics, and some food products. Challenges include ensuring safety and environmental impact, developing scalable manufacturing processes, and understanding long-term effects. Despite these hurdles, nanotechnology holds immense potential to revolutionize nearly every industry, offering solutions to complex problems and paving the way for unprecedented advancements in science and technology by building from the ground up at the molecular level.','2025-06-30 16:00:00','2025-06-30 16:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000025','nanotechnology');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000025','materials_science');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000025','user3');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000025','user1');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000025','user4','Amazing potential, but safety needs careful consideration.','2025-06-30 16:30:00');
 
 -- Article 26: user1 - Space Debris
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000026','user1','Space Debris: A Growing Threat to Orbital Infrastructure','Addressing the challenge of orbital junk and its impact on space operations.','Space debris, consisting of defunct satellites, spent rocket stages, and fragments from collisions, poses a significant and growing threat to active satellites and future space missions. Traveling at incredibly high velocities, even small pieces of debris can cause catastrophic damage upon impact, creating more fragments and exacerbating the problem in a cascading effect known as the Kessler Syndrome. This orbital junk jeopardizes critical infrastructure like communication, navigation, and weather satellites, which are vital for modern life. Solutions being explored include active debris removal technologies (e.g., nets, harpoons, lasers), de-orbiting mechanisms for new satellites, and stricter international regulations for space launches and end-of-life disposal. Managing space debris is crucial for ensuring the long-term sustainability and safety of Earth''s orbital environment, enabling continued scientific discovery, technological advancement, and economic activity in space.','2025-06-01 09:00:00','2025-06-01 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000026','Space');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000026','Sustainability');
-INSERT INTO Comments VALUES(21,'01980000-0000-0000-0000-000000000026','user3','Kessler Syndrome is a terrifying prospect.','2025-06-01 09:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000026','user1','Space Debris: A Growing Threat to Orbital Infrastructure','Addressing the challenge of orbital junk and its impact on space operations.','Space debris, consisting of defunct satellites, spent rocket stages, and fragments from collisions, poses a significant and growing threat to active satellites and future space missions. Traveling at incredibly high velocities, even small pieces of debris can cause catastrophic damage upon impact, creating more fragments and exacerbating the problem in a cascading effect known as the Kessler Syndrome. This orbital junk jeopardizes critical infrastructure like communication, navigation, and weather satellites, which are vital for modern life. Solutions being explored include active debris removal technologies (e.g., nets, harpoons, lasers), de-orbiting mechanisms for new satellites, and stricter international regulations for space launches and end-of-life disposal. Managing space debris is crucial for ensuring the long-term sustainability and safety of Earth''s orbital environment, enabling continued scientific discovery, technological advancement, and economic activity in space.','2025-06-01 09:00:00','2025-06-01 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000026','space');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000026','sustainability');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000026','user3','Kessler Syndrome is a terrifying prospect.','2025-06-01 09:30:00');
 
 -- Article 27: user2 - Biometrics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000027','user2','Biometrics: The Future of Identity Verification','How biological characteristics are revolutionizing security and access control.','Biometrics involves using unique biological characteristics, such as fingerprints, facial features, iris patterns, or voiceprints, for identity verification and authentication. This technology offers a more secure and convenient alternative to traditional passwords and PINs, as biological traits are difficult to forge or steal. Biometric systems are increasingly integrated into smartphones, laptops, and access control systems for buildings, enhancing security across various domains. Beyond security, biometrics has applications in law enforcement, border control, and even personalized healthcare. Challenges include ensuring accuracy, preventing spoofing attacks, and addressing privacy concerns related to the storage and use of sensitive biological data. As biometric technology becomes more sophisticated and widespread, it promises to streamline authentication processes, improve security, and redefine how we prove our identity in an increasingly digital and interconnected world, balancing convenience with robust protection.','2025-06-03 13:00:00','2025-06-03 13:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000027','Security');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000027','Biometrics');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000027','user1');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000027','user2','Biometrics: The Future of Identity Verification','How biological characteristics are revolutionizing security and access control.','Biometrics involves using unique biological characteristics, such as fingerprints, facial features, iris patterns, or voiceprints, for identity verification and authentication. This technology offers a more secure and convenient alternative to traditional passwords and PINs, as biological traits are difficult to forge or steal. Biometric systems are increasingly integrated into smartphones, laptops, and access control systems for buildings, enhancing security across various domains. Beyond security, biometrics has applications in law enforcement, border control, and even personalized healthcare. Challenges include ensuring accuracy, preventing spoofing attacks, and addressing privacy concerns related to the storage and use of sensitive biological data. As biometric technology becomes more sophisticated and widespread, it promises to streamline authentication processes, improve security, and redefine how we prove our identity in an increasingly digital and interconnected world, balancing convenience with robust protection.','2025-06-03 13:00:00','2025-06-03 13:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000027','security');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000027','biometrics');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000027','user1');
 
 -- Article 28: user3 - Vertical Farming
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000028','user3','Vertical Farming: Growing Food in Urban Environments','The innovative approach to agriculture that optimizes space and resources.','Vertical farming is an innovative agricultural method where crops are grown in vertically stacked layers, often indoors in controlled environments. This approach offers numerous advantages, particularly for urban areas: it maximizes land use, significantly reduces water consumption through hydroponic or aeroponic systems, eliminates the need for pesticides, and allows for year-round production regardless of climate. By growing food closer to consumers, transportation costs and carbon emissions are minimized. While initial setup costs can be high and energy consumption for lighting is a consideration, advancements in LED technology and automation are making vertical farms more economically viable. Vertical farming contributes to food security, reduces reliance on traditional agriculture''s large land footprint, and provides fresh, locally grown produce, representing a key component of sustainable urban development and future food systems.','2025-06-05 16:00:00','2025-06-05 16:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000028','Agriculture');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000028','Sustainability');
-INSERT INTO Comments VALUES(22,'01980000-0000-0000-0000-000000000028','user4','Amazing potential for urban food deserts.','2025-06-05 16:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000028','user3','Vertical Farming: Growing Food in Urban Environments','The innovative approach to agriculture that optimizes space and resources.','Vertical farming is an innovative agricultural method where crops are grown in vertically stacked layers, often indoors in controlled environments. This approach offers numerous advantages, particularly for urban areas: it maximizes land use, significantly reduces water consumption through hydroponic or aeroponic systems, eliminates the need for pesticides, and allows for year-round production regardless of climate. By growing food closer to consumers, transportation costs and carbon emissions are minimized. While initial setup costs can be high and energy consumption for lighting is a consideration, advancements in LED technology and automation are making vertical farms more economically viable. Vertical farming contributes to food security, reduces reliance on traditional agriculture''s large land footprint, and provides fresh, locally grown produce, representing a key component of sustainable urban development and future food systems.','2025-06-05 16:00:00','2025-06-05 16:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000028','agriculture');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000028','sustainability');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000028','user4','Amazing potential for urban food deserts.','2025-06-05 16:30:00');
 
 -- Article 29: user4 - Digital Twins
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000029','user4','Digital Twins: Virtual Replicas for Real-World Optimization','How digital twins are revolutionizing design, operation, and maintenance across industries.','A digital twin is a virtual replica of a physical object, process, or system, updated in real-time with data from its real-world counterpart. This dynamic digital model allows for comprehensive analysis, simulation, and optimization before physical implementation or during operation. Industries from manufacturing and aerospace to healthcare and urban planning are leveraging digital twins to predict performance, identify potential issues, test modifications, and train personnel in a risk-free environment. For example, a digital twin of a jet engine can simulate its wear and tear, allowing for predictive maintenance. This technology combines IoT, AI, machine learning, and advanced analytics to create a powerful tool for informed decision-making, reducing costs, improving efficiency, and enhancing safety. Digital twins are becoming indispensable for complex systems, enabling a new era of proactive management and innovation.','2025-06-07 10:00:00','2025-06-07 10:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000029','Digital_Twin');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000029','Industry_4.0');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000029','user2');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000029','user4','Digital Twins: Virtual Replicas for Real-World Optimization','How digital twins are revolutionizing design, operation, and maintenance across industries.','A digital twin is a virtual replica of a physical object, process, or system, updated in real-time with data from its real-world counterpart. This dynamic digital model allows for comprehensive analysis, simulation, and optimization before physical implementation or during operation. Industries from manufacturing and aerospace to healthcare and urban planning are leveraging digital twins to predict performance, identify potential issues, test modifications, and train personnel in a risk-free environment. For example, a digital twin of a jet engine can simulate its wear and tear, allowing for predictive maintenance. This technology combines IoT, AI, machine learning, and advanced analytics to create a powerful tool for informed decision-making, reducing costs, improving efficiency, and enhancing safety. Digital twins are becoming indispensable for complex systems, enabling a new era of proactive management and innovation.','2025-06-07 10:00:00','2025-06-07 10:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000029','digital_twin');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000029','industry_4.0');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000029','user2');
 
 -- Article 30: user5 - Exoskeletons
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000030','user5','Exoskeletons: Enhancing Human Strength and Mobility','The development and applications of wearable robotic devices.','Exoskeletons are wearable robotic devices designed to augment, restore, or enhance human strength, endurance, and mobility. These external frameworks, powered by motors and controlled by sensors, can assist individuals with paralysis or mobility impairments in walking, standing, and performing daily tasks, significantly improving their quality of life. Beyond medical rehabilitation, exoskeletons are finding increasing applications in industrial settings, where they can reduce fatigue and prevent injuries for workers performing heavy lifting or repetitive motions. Military and emergency services also explore their use for enhanced strength and protection. Challenges include making them lighter, more energy-efficient, and more intuitive to control, as well as reducing their cost. As technology advances, exoskeletons promise to redefine human capabilities, offering new possibilities for rehabilitation, occupational safety, and personal augmentation, blurring the lines between human and machine.','2025-06-09 14:30:00','2025-06-09 14:30:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000030','Robotics');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000030','Healthcare');
-INSERT INTO Comments VALUES(23,'01980000-0000-0000-0000-000000000030','user1','Incredible for rehabilitation!','2025-06-09 15:00:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000030','user5','Exoskeletons: Enhancing Human Strength and Mobility','The development and applications of wearable robotic devices.','Exoskeletons are wearable robotic devices designed to augment, restore, or enhance human strength, endurance, and mobility. These external frameworks, powered by motors and controlled by sensors, can assist individuals with paralysis or mobility impairments in walking, standing, and performing daily tasks, significantly improving their quality of life. Beyond medical rehabilitation, exoskeletons are finding increasing applications in industrial settings, where they can reduce fatigue and prevent injuries for workers performing heavy lifting or repetitive motions. Military and emergency services also explore their use for enhanced strength and protection. Challenges include making them lighter, more energy-efficient, and more intuitive to control, as well as reducing their cost. As technology advances, exoskeletons promise to redefine human capabilities, offering new possibilities for rehabilitation, occupational safety, and personal augmentation, blurring the lines between human and machine.','2025-06-09 14:30:00','2025-06-09 14:30:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000030','robotics');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000030','healthcare');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000030','user1','Incredible for rehabilitation!','2025-06-09 15:00:00');
 
 -- Article 31: user1 - AI in Cybersecurity
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000031','user1','AI in Cybersecurity: The Arms Race in Digital Defense','How artificial intelligence is both a tool and a target in cybersecurity.','Artificial intelligence is rapidly becoming indispensable in the ongoing arms race of cybersecurity, offering powerful capabilities for both defense and offense. On the defensive side, AI-powered systems can analyze vast amounts of network traffic, detect anomalous behavior, and identify emerging threats like zero-day attacks with greater speed and accuracy than human analysts. Machine learning models are used for real-time threat detection, automated vulnerability assessment, and intelligent incident response, significantly enhancing an organization''s security posture. However, malicious actors are also leveraging AI to create more sophisticated phishing campaigns, polymorphic malware, and automated attack tools, making threats harder to detect. The challenge lies in staying ahead of these evolving AI-driven threats. The future of cybersecurity will increasingly involve AI systems battling other AI systems, necessitating continuous research and development in AI-driven defensive strategies to protect critical digital infrastructure from increasingly intelligent adversaries.','2025-06-11 09:00:00','2025-06-11 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000031','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000031','Cybersecurity');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000031','user3');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000031','user1','AI in Cybersecurity: The Arms Race in Digital Defense','How artificial intelligence is both a tool and a target in cybersecurity.','Artificial intelligence is rapidly becoming indispensable in the ongoing arms race of cybersecurity, offering powerful capabilities for both defense and offense. On the defensive side, AI-powered systems can analyze vast amounts of network traffic, detect anomalous behavior, and identify emerging threats like zero-day attacks with greater speed and accuracy than human analysts. Machine learning models are used for real-time threat detection, automated vulnerability assessment, and intelligent incident response, significantly enhancing an organization''s security posture. However, malicious actors are also leveraging AI to create more sophisticated phishing campaigns, polymorphic malware, and automated attack tools, making threats harder to detect. The challenge lies in staying ahead of these evolving AI-driven threats. The future of cybersecurity will increasingly involve AI systems battling other AI systems, necessitating continuous research and development in AI-driven defensive strategies to protect critical digital infrastructure from increasingly intelligent adversaries.','2025-06-11 09:00:00','2025-06-11 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000031','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000031','cybersecurity');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000031','user3');
 
 -- Article 32: user2 - Fusion Energy Progress
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000032','user2','Fusion Energy: Breakthroughs and the Path to Commercialization','Recent advancements bringing clean, limitless fusion power closer to reality.','Fusion energy, the power source of stars, promises a virtually limitless and clean energy future on Earth by fusing light atomic nuclei. After decades of research, recent breakthroughs are bringing this promise closer to reality. Projects like ITER (International Thermonuclear Experimental Reactor) are demonstrating the scientific feasibility of sustained fusion reactions, while private companies are making rapid progress with innovative approaches to achieve net energy gain. The primary challenges involve creating and maintaining plasma at millions of degrees Celsius and developing materials that can withstand such extreme conditions. However, the potential rewards—abundant energy with minimal long-lived radioactive waste and no greenhouse gas emissions—make the pursuit of fusion power a global imperative. Continued investment and international collaboration are crucial to overcome remaining engineering hurdles and transition fusion from laboratory experiments to commercial power plants, fundamentally transforming the global energy landscape.','2025-06-13 13:00:00','2025-06-13 13:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000032','Energy');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000032','Fusion');
-INSERT INTO Comments VALUES(24,'01980000-0000-0000-0000-000000000032','user5','Net energy gain would be incredible!','2025-06-13 13:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000032','user2','Fusion Energy: Breakthroughs and the Path to Commercialization','Recent advancements bringing clean, limitless fusion power closer to reality.','Fusion energy, the power source of stars, promises a virtually limitless and clean energy future on Earth by fusing light atomic nuclei. After decades of research, recent breakthroughs are bringing this promise closer to reality. Projects like ITER (International Thermonuclear Experimental Reactor) are demonstrating the scientific feasibility of sustained fusion reactions, while private companies are making rapid progress with innovative approaches to achieve net energy gain. The primary challenges involve creating and maintaining plasma at millions of degrees Celsius and developing materials that can withstand such extreme conditions. However, the potential rewards—abundant energy with minimal long-lived radioactive waste and no greenhouse gas emissions—make the pursuit of fusion power a global imperative. Continued investment and international collaboration are crucial to overcome remaining engineering hurdles and transition fusion from laboratory experiments to commercial power plants, fundamentally transforming the global energy landscape.','2025-06-13 13:00:00','2025-06-13 13:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000032','energy');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000032','fusion');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000032','user5','Net energy gain would be incredible!','2025-06-13 13:30:00');
 
 -- Article 33: user3 - Brain Mapping
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000033','user3','Brain Mapping: Unlocking the Secrets of the Human Mind','The ambitious effort to map the brain''s intricate connections and functions.','Brain mapping is an ambitious scientific endeavor to chart the intricate structure and function of the human brain, aiming to understand how billions of neurons and trillions of connections give rise to thought, emotion, and consciousness. Advanced imaging techniques like fMRI, EEG, and optogenetics, combined with computational neuroscience, are providing unprecedented insights into neural circuits and brain activity patterns. Projects like the Human Connectome Project are creating detailed maps of neural pathways. This research is crucial for understanding neurological and psychiatric disorders, from Alzheimer''s and Parkinson''s to depression and autism, and for developing more effective treatments. While a complete map remains a monumental challenge, each discovery brings us closer to unraveling the brain''s mysteries, potentially leading to breakthroughs in AI, personalized medicine, and even our fundamental understanding of what it means to be human.','2025-06-15 10:00:00','2025-06-15 10:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000033','Neuroscience');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000033','Brain');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000033','user2');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000033','user3','Brain Mapping: Unlocking the Secrets of the Human Mind','The ambitious effort to map the brain''s intricate connections and functions.','Brain mapping is an ambitious scientific endeavor to chart the intricate structure and function of the human brain, aiming to understand how billions of neurons and trillions of connections give rise to thought, emotion, and consciousness. Advanced imaging techniques like fMRI, EEG, and optogenetics, combined with computational neuroscience, are providing unprecedented insights into neural circuits and brain activity patterns. Projects like the Human Connectome Project are creating detailed maps of neural pathways. This research is crucial for understanding neurological and psychiatric disorders, from Alzheimer''s and Parkinson''s to depression and autism, and for developing more effective treatments. While a complete map remains a monumental challenge, each discovery brings us closer to unraveling the brain''s mysteries, potentially leading to breakthroughs in AI, personalized medicine, and even our fundamental understanding of what it means to be human.','2025-06-15 10:00:00','2025-06-15 10:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000033','neuroscience');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000033','brain');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000033','user2');
 
 -- Article 34: user4 - Explainable AI (XAI)
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000034','user4','Explainable AI (XAI): Demystifying Black Box Models','The importance of understanding how AI makes decisions.','As artificial intelligence models become increasingly complex and are deployed in critical applications like healthcare and finance, the need for Explainable AI (XAI) has grown. XAI aims to make AI systems more transparent and understandable, allowing humans to comprehend why an AI made a particular decision or prediction. This is crucial for building trust, ensuring fairness, and enabling accountability, especially when AI operates as a "black box" where its internal workings are opaque. Techniques in XAI include methods to highlight important features, visualize decision boundaries, and generate human-readable explanations. By providing insights into AI''s reasoning, XAI helps identify biases, debug errors, and ensure compliance with regulations. It''s a vital step towards responsible AI development, fostering collaboration between humans and AI, and ensuring that AI systems are not only powerful but also trustworthy and interpretable for human oversight.','2025-06-17 14:00:00','2025-06-17 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000034','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000034','XAI');
-INSERT INTO Comments VALUES(25,'01980000-0000-0000-0000-000000000034','user5','Transparency in AI is absolutely essential.','2025-06-17 14:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000034','user4','Explainable AI (XAI): Demystifying Black Box Models','The importance of understanding how AI makes decisions.','As artificial intelligence models become increasingly complex and are deployed in critical applications like healthcare and finance, the need for Explainable AI (XAI) has grown. XAI aims to make AI systems more transparent and understandable, allowing humans to comprehend why an AI made a particular decision or prediction. This is crucial for building trust, ensuring fairness, and enabling accountability, especially when AI operates as a "black box" where its internal workings are opaque. Techniques in XAI include methods to highlight important features, visualize decision boundaries, and generate human-readable explanations. By providing insights into AI''s reasoning, XAI helps identify biases, debug errors, and ensure compliance with regulations. It''s a vital step towards responsible AI development, fostering collaboration between humans and AI, and ensuring that AI systems are not only powerful but also trustworthy and interpretable for human oversight.','2025-06-17 14:00:00','2025-06-17 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000034','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000034','xai');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000034','user5','Transparency in AI is absolutely essential.','2025-06-17 14:30:00');
 
 -- Article 35: user5 - Smart Dust
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000035','user5','Smart Dust: Microscopic Sensors for a Connected World','Exploring the concept and potential applications of tiny, networked sensors.','Smart dust refers to hypothetical microscopic devices, typically the size of a grain of sand, containing sensors, communication capabilities, and power sources, designed to form wireless sensor networks. These tiny, distributed sensors could monitor environmental conditions, detect chemical or biological agents, track movements, or even perform medical diagnostics from within the body. Their small size and ability to operate in large, dispersed networks make them ideal for applications where traditional sensors are impractical, such as monitoring vast agricultural fields, detecting structural integrity in buildings, or pervasive surveillance. Challenges include power management, manufacturing at such small scales, and ensuring robust communication. While still largely a futuristic concept, smart dust represents the ultimate vision of ubiquitous computing and sensing, promising to provide unprecedented levels of data about our surroundings and transforming fields from environmental monitoring to defense.','2025-06-19 11:00:00','2025-06-19 11:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000035','IoT');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000035','Sensors');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000035','user3');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000035','user5','Smart Dust: Microscopic Sensors for a Connected World','Exploring the concept and potential applications of tiny, networked sensors.','Smart dust refers to hypothetical microscopic devices, typically the size of a grain of sand, containing sensors, communication capabilities, and power sources, designed to form wireless sensor networks. These tiny, distributed sensors could monitor environmental conditions, detect chemical or biological agents, track movements, or even perform medical diagnostics from within the body. Their small size and ability to operate in large, dispersed networks make them ideal for applications where traditional sensors are impractical, such as monitoring vast agricultural fields, detecting structural integrity in buildings, or pervasive surveillance. Challenges include power management, manufacturing at such small scales, and ensuring robust communication. While still largely a futuristic concept, smart dust represents the ultimate vision of ubiquitous computing and sensing, promising to provide unprecedented levels of data about our surroundings and transforming fields from environmental monitoring to defense.','2025-06-19 11:00:00','2025-06-19 11:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000035','iot');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000035','sensors');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000035','user3');
 
 -- Article 36: user1 - Space Mining
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000036','user1','Space Mining: The Asteroid Rush and Lunar Resources','The economic and technological prospects of extracting resources from celestial bodies.','Space mining, the extraction of valuable materials from asteroids, the Moon, or other celestial bodies, is emerging as a potential game-changer for future industry and space exploration. Asteroids are rich in precious metals like platinum, rare earth elements, and water ice, which can be used for rocket fuel or life support. The Moon holds vast reserves of helium-3, a potential fuel for future fusion reactors, and water ice in its polar regions. The economic viability depends on developing cost-effective robotic mining technologies, efficient transportation systems, and establishing off-Earth processing capabilities. While technical and legal challenges are significant, successful space mining could create new trillion-dollar industries, provide resources for sustained human presence beyond Earth, and reduce the cost of space missions by utilizing in-situ resources. It represents a bold new frontier for resource acquisition and economic expansion.','2025-06-21 15:00:00','2025-06-21 15:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000036','Space');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000036','Economics');
-INSERT INTO Comments VALUES(26,'01980000-0000-0000-0000-000000000036','user2','The legal framework for space mining is still so undefined.','2025-06-21 15:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000036','user1','Space Mining: The Asteroid Rush and Lunar Resources','The economic and technological prospects of extracting resources from celestial bodies.','Space mining, the extraction of valuable materials from asteroids, the Moon, or other celestial bodies, is emerging as a potential game-changer for future industry and space exploration. Asteroids are rich in precious metals like platinum, rare earth elements, and water ice, which can be used for rocket fuel or life support. The Moon holds vast reserves of helium-3, a potential fuel for future fusion reactors, and water ice in its polar regions. The economic viability depends on developing cost-effective robotic mining technologies, efficient transportation systems, and establishing off-Earth processing capabilities. While technical and legal challenges are significant, successful space mining could create new trillion-dollar industries, provide resources for sustained human presence beyond Earth, and reduce the cost of space missions by utilizing in-situ resources. It represents a bold new frontier for resource acquisition and economic expansion.','2025-06-21 15:00:00','2025-06-21 15:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000036','space');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000036','economics');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000036','user2','The legal framework for space mining is still so undefined.','2025-06-21 15:30:00');
 
 -- Article 37: user2 - Quantum Internet
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000037','user2','The Quantum Internet: A New Paradigm for Secure Communication','How quantum entanglement will create an unhackable network.','The quantum internet is a futuristic network that leverages the principles of quantum mechanics, primarily quantum entanglement, to enable fundamentally new forms of communication and computation. Unlike the classical internet, which transmits information as bits, the quantum internet would transmit quantum bits (qubits) and entangled particles. This would allow for perfectly secure communication through Quantum Key Distribution (QKD), where any attempt at eavesdropping is immediately detectable. Beyond secure communication, a quantum internet could enable distributed quantum computing, allowing distant quantum computers to work together, and highly precise synchronized clocks for advanced scientific instruments. While still in early research and development, building a quantum internet requires overcoming significant challenges in maintaining quantum coherence over long distances and developing robust quantum repeaters. Its eventual realization promises to revolutionize cybersecurity, scientific discovery, and distributed computing, creating a truly unhackable and powerful global network.','2025-06-23 09:00:00','2025-06-23 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000037','Quantum_Computing');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000037','Internet');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000037','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000037','user2','The Quantum Internet: A New Paradigm for Secure Communication','How quantum entanglement will create an unhackable network.','The quantum internet is a futuristic network that leverages the principles of quantum mechanics, primarily quantum entanglement, to enable fundamentally new forms of communication and computation. Unlike the classical internet, which transmits information as bits, the quantum internet would transmit quantum bits (qubits) and entangled particles. This would allow for perfectly secure communication through Quantum Key Distribution (QKD), where any attempt at eavesdropping is immediately detectable. Beyond secure communication, a quantum internet could enable distributed quantum computing, allowing distant quantum computers to work together, and highly precise synchronized clocks for advanced scientific instruments. While still in early research and development, building a quantum internet requires overcoming significant challenges in maintaining quantum coherence over long distances and developing robust quantum repeaters. Its eventual realization promises to revolutionize cybersecurity, scientific discovery, and distributed computing, creating a truly unhackable and powerful global network.','2025-06-23 09:00:00','2025-06-23 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000037','quantum_computing');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000037','internet');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000037','user4');
 
 -- Article 38: user3 - AI in Drug Discovery
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000038','user3','AI in Drug Discovery: Accelerating Medical Breakthroughs','How artificial intelligence is transforming pharmaceutical research and development.','Artificial intelligence is revolutionizing drug discovery, significantly accelerating the process of identifying, developing, and testing new therapeutic compounds. AI algorithms can analyze vast datasets of biological information, chemical structures, and patient data to predict potential drug candidates, optimize molecular designs, and identify novel disease targets. This drastically reduces the time and cost associated with traditional drug discovery methods. Machine learning models are used for virtual screening of millions of compounds, predicting drug efficacy and toxicity, and even designing entirely new molecules. AI also aids in optimizing clinical trial design and patient selection. While challenges remain in data quality and model validation, AI''s ability to rapidly process complex biological information promises to unlock new medical breakthroughs, leading to faster development of treatments for diseases that are currently untreatable, and ultimately improving global health outcomes.','2025-06-25 14:00:00','2025-06-25 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000038','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000038','Medicine');
-INSERT INTO Comments VALUES(27,'01980000-0000-0000-0000-000000000038','user1','This could really speed up finding cures.','2025-06-25 14:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000038','user3','AI in Drug Discovery: Accelerating Medical Breakthroughs','How artificial intelligence is transforming pharmaceutical research and development.','Artificial intelligence is revolutionizing drug discovery, significantly accelerating the process of identifying, developing, and testing new therapeutic compounds. AI algorithms can analyze vast datasets of biological information, chemical structures, and patient data to predict potential drug candidates, optimize molecular designs, and identify novel disease targets. This drastically reduces the time and cost associated with traditional drug discovery methods. Machine learning models are used for virtual screening of millions of compounds, predicting drug efficacy and toxicity, and even designing entirely new molecules. AI also aids in optimizing clinical trial design and patient selection. While challenges remain in data quality and model validation, AI''s ability to rapidly process complex biological information promises to unlock new medical breakthroughs, leading to faster development of treatments for diseases that are currently untreatable, and ultimately improving global health outcomes.','2025-06-25 14:00:00','2025-06-25 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000038','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000038','medicine');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000038','user1','This could really speed up finding cures.','2025-06-25 14:30:00');
 
 -- Article 39: user4 - Augmented Reality in Industry
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000039','user4','Augmented Reality in Industry: Enhancing Productivity and Training','How AR technology is transforming manufacturing, maintenance, and logistics.','Augmented Reality (AR) is rapidly moving beyond entertainment to become a powerful tool in industrial settings, significantly enhancing productivity, training, and operational efficiency. Unlike Virtual Reality, which creates fully immersive digital worlds, AR overlays digital information onto the real world, allowing workers to see critical data, instructions, or 3D models directly in their field of view. Applications include guided assembly for complex machinery, remote assistance for troubleshooting equipment, interactive training simulations, and real-time data visualization in logistics. AR headsets and smart glasses provide hands-free access to information, reducing errors and improving task completion times. While challenges exist in hardware design, software integration, and user acceptance, AR is poised to revolutionize industrial processes, empowering the workforce with enhanced capabilities and driving the next wave of digital transformation in manufacturing, maintenance, and field services.','2025-06-27 10:00:00','2025-06-27 10:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000039','AR');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000039','Industry_4.0');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000039','user3');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000039','user4','Augmented Reality in Industry: Enhancing Productivity and Training','How AR technology is transforming manufacturing, maintenance, and logistics.','Augmented Reality (AR) is rapidly moving beyond entertainment to become a powerful tool in industrial settings, significantly enhancing productivity, training, and operational efficiency. Unlike Virtual Reality, which creates fully immersive digital worlds, AR overlays digital information onto the real world, allowing workers to see critical data, instructions, or 3D models directly in their field of view. Applications include guided assembly for complex machinery, remote assistance for troubleshooting equipment, interactive training simulations, and real-time data visualization in logistics. AR headsets and smart glasses provide hands-free access to information, reducing errors and improving task completion times. While challenges exist in hardware design, software integration, and user acceptance, AR is poised to revolutionize industrial processes, empowering the workforce with enhanced capabilities and driving the next wave of digital transformation in manufacturing, maintenance, and field services.','2025-06-27 10:00:00','2025-06-27 10:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000039','ar');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000039','industry_4.0');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000039','user3');
 
 -- Article 40: user5 - Biomimetics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000040','user5','Biomimetics: Learning from Nature''s Designs','How scientists and engineers are drawing inspiration from biological systems.','Biomimetics, or biomimicry, is an interdisciplinary approach to innovation that seeks sustainable solutions to human challenges by emulating nature''s time-tested patterns and strategies. Instead of exploiting nature, biomimetics learns from its designs. Examples include Velcro, inspired by burrs; aerodynamic train designs based on kingfishers; and self-cleaning surfaces mimicking lotus leaves. Scientists and engineers study biological systems at various levels—from molecular to ecosystem—to understand their functional principles and apply them to technology. This approach often leads to highly efficient, resilient, and environmentally friendly designs. Biomimetics encourages a holistic view, considering not just the form but also the process and ecosystem context of natural designs. It represents a powerful paradigm for sustainable innovation, fostering a deeper connection with the natural world and developing solutions that are inherently more harmonious with the planet''s intricate systems.','2025-06-29 15:00:00','2025-06-29 15:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000040','Biology');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000040','Engineering');
-INSERT INTO Comments VALUES(28,'01980000-0000-0000-0000-000000000040','user2','Nature truly is the best engineer.','2025-06-29 15:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000040','user5','Biomimetics: Learning from Nature''s Designs','How scientists and engineers are drawing inspiration from biological systems.','Biomimetics, or biomimicry, is an interdisciplinary approach to innovation that seeks sustainable solutions to human challenges by emulating nature''s time-tested patterns and strategies. Instead of exploiting nature, biomimetics learns from its designs. Examples include Velcro, inspired by burrs; aerodynamic train designs based on kingfishers; and self-cleaning surfaces mimicking lotus leaves. Scientists and engineers study biological systems at various levels—from molecular to ecosystem—to understand their functional principles and apply them to technology. This approach often leads to highly efficient, resilient, and environmentally friendly designs. Biomimetics encourages a holistic view, considering not just the form but also the process and ecosystem context of natural designs. It represents a powerful paradigm for sustainable innovation, fostering a deeper connection with the natural world and developing solutions that are inherently more harmonious with the planet''s intricate systems.','2025-06-29 15:00:00','2025-06-29 15:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000040','biology');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000040','engineering');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000040','user2','Nature truly is the best engineer.','2025-06-29 15:30:00');
 
 -- Article 41: user1 - Explainable AI (XAI)
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000041','user1','AI Ethics: Navigating Bias and Fairness','Addressing the critical challenge of bias in AI systems.','The rapid advancement of artificial intelligence brings with it profound ethical challenges, particularly concerning bias and fairness. AI models, trained on vast datasets, can inadvertently learn and perpetuate existing societal biases present in the data, leading to discriminatory outcomes in areas such as facial recognition, hiring decisions, and credit scoring. Addressing algorithmic bias requires a multi-faceted approach, including careful data curation, developing fairness metrics, and implementing techniques for bias detection and mitigation. Researchers are exploring methods like adversarial training and re-weighting data to create more equitable AI systems. Beyond technical solutions, robust governance frameworks, ethical guidelines, and diverse development teams are crucial to ensure AI systems are developed and deployed responsibly. Prioritizing fairness and transparency is essential for building public trust and ensuring that AI serves as a tool for progress rather than exacerbating existing inequalities in society.','2025-06-04 09:00:00','2025-06-04 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000041','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000041','Ethics');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000041','user5');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000041','user1','AI Ethics: Navigating Bias and Fairness','Addressing the critical challenge of bias in AI systems.','The rapid advancement of artificial intelligence brings with it profound ethical challenges, particularly concerning bias and fairness. AI models, trained on vast datasets, can inadvertently learn and perpetuate existing societal biases present in the data, leading to discriminatory outcomes in areas such as facial recognition, hiring decisions, and credit scoring. Addressing algorithmic bias requires a multi-faceted approach, including careful data curation, developing fairness metrics, and implementing techniques for bias detection and mitigation. Researchers are exploring methods like adversarial training and re-weighting data to create more equitable AI systems. Beyond technical solutions, robust governance frameworks, ethical guidelines, and diverse development teams are crucial to ensure AI systems are developed and deployed responsibly. Prioritizing fairness and transparency is essential for building public trust and ensuring that AI serves as a tool for progress rather than exacerbating existing inequalities in society.','2025-06-04 09:00:00','2025-06-04 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000041','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000041','ethics');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000041','user5');
 
 -- Article 42: user2 - Deep Learning
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000042','user2','Deep Learning: Unlocking AI''s Full Potential','An introduction to neural networks and their transformative impact.','Deep learning, a subset of machine learning inspired by the structure and function of the human brain''s neural networks, has driven many of the recent breakthroughs in artificial intelligence. These deep neural networks, with multiple layers of interconnected nodes, can learn complex patterns from vast amounts of data, enabling remarkable performance in tasks like image recognition, natural language processing, and speech synthesis. From powering facial recognition in smartphones to enabling autonomous vehicles and assisting in medical diagnostics, deep learning algorithms are at the forefront of AI innovation. Training these models requires significant computational power and large datasets, leading to the development of specialized hardware like GPUs. While powerful, challenges include interpretability (understanding how decisions are made) and the need for extensive data. Deep learning continues to evolve rapidly, pushing the boundaries of what AI can achieve and transforming industries worldwide.','2025-06-06 13:00:00','2025-06-06 13:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000042','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000042','Machine_Learning');
-INSERT INTO Comments VALUES(29,'01980000-0000-0000-0000-000000000042','user3','It''s amazing how far neural networks have come.','2025-06-06 13:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000042','user2','Deep Learning: Unlocking AI''s Full Potential','An introduction to neural networks and their transformative impact.','Deep learning, a subset of machine learning inspired by the structure and function of the human brain''s neural networks, has driven many of the recent breakthroughs in artificial intelligence. These deep neural networks, with multiple layers of interconnected nodes, can learn complex patterns from vast amounts of data, enabling remarkable performance in tasks like image recognition, natural language processing, and speech synthesis. From powering facial recognition in smartphones to enabling autonomous vehicles and assisting in medical diagnostics, deep learning algorithms are at the forefront of AI innovation. Training these models requires significant computational power and large datasets, leading to the development of specialized hardware like GPUs. While powerful, challenges include interpretability (understanding how decisions are made) and the need for extensive data. Deep learning continues to evolve rapidly, pushing the boundaries of what AI can achieve and transforming industries worldwide.','2025-06-06 13:00:00','2025-06-06 13:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000042','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000042','machine_learning');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000042','user3','It''s amazing how far neural networks have come.','2025-06-06 13:30:00');
 
 -- Article 43: user3 - Robotics in Logistics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000043','user3','Robotics in Logistics: Automating the Supply Chain','How robots are revolutionizing warehouses, delivery, and inventory management.','The logistics and supply chain industry is undergoing a significant transformation driven by the increasing adoption of robotics and automation. Robots are being deployed in warehouses for tasks like picking, packing, sorting, and moving goods, drastically improving efficiency and accuracy. Autonomous mobile robots (AMRs) navigate warehouses independently, optimizing routes and reducing human effort. Drones are being explored for inventory checks and last-mile delivery in certain areas. These robotic solutions address labor shortages, reduce operational costs, and enable faster order fulfillment, crucial for the demands of e-commerce. While initial investment and integration complexities are challenges, the long-term benefits in terms of scalability, precision, and safety are compelling. Robotics is reshaping the entire supply chain, creating more resilient, efficient, and responsive logistics networks that can adapt to fluctuating market demands and deliver goods faster than ever before.','2025-06-08 16:00:00','2025-06-08 16:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000043','Robotics');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000043','Logistics');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000043','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000043','user3','Robotics in Logistics: Automating the Supply Chain','How robots are revolutionizing warehouses, delivery, and inventory management.','The logistics and supply chain industry is undergoing a significant transformation driven by the increasing adoption of robotics and automation. Robots are being deployed in warehouses for tasks like picking, packing, sorting, and moving goods, drastically improving efficiency and accuracy. Autonomous mobile robots (AMRs) navigate warehouses independently, optimizing routes and reducing human effort. Drones are being explored for inventory checks and last-mile delivery in certain areas. These robotic solutions address labor shortages, reduce operational costs, and enable faster order fulfillment, crucial for the demands of e-commerce. While initial investment and integration complexities are challenges, the long-term benefits in terms of scalability, precision, and safety are compelling. Robotics is reshaping the entire supply chain, creating more resilient, efficient, and responsive logistics networks that can adapt to fluctuating market demands and deliver goods faster than ever before.','2025-06-08 16:00:00','2025-06-08 16:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000043','robotics');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000043','logistics');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000043','user4');
 
 -- Article 44: user4 - Personalized Nutrition
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000044','user4','Personalized Nutrition: Diet Tailored to Your DNA','The science behind customized dietary recommendations based on individual biology.','Personalized nutrition is an emerging field that moves beyond generic dietary guidelines to provide customized eating recommendations based on an individual''s unique genetic makeup, microbiome, lifestyle, and health goals. Advances in genomics, metabolomics, and wearable technology allow scientists to understand how different foods affect an individual''s metabolism, blood sugar levels, and overall health. This data-driven approach aims to optimize health outcomes, prevent chronic diseases, and enhance well-being by tailoring diets to specific biological needs. For example, some individuals may metabolize certain nutrients differently due to genetic variations. While still in its early stages, personalized nutrition holds immense promise for transforming public health, offering more effective strategies for weight management, disease prevention, and athletic performance, moving towards a truly individualized approach to diet and health.','2025-06-10 11:00:00','2025-06-10 11:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000044','Health');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000044','Nutrition');
-INSERT INTO Comments VALUES(30,'01980000-0000-0000-0000-000000000044','user5','This is the future of diet plans!','2025-06-10 11:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000044','user4','Personalized Nutrition: Diet Tailored to Your DNA','The science behind customized dietary recommendations based on individual biology.','Personalized nutrition is an emerging field that moves beyond generic dietary guidelines to provide customized eating recommendations based on an individual''s unique genetic makeup, microbiome, lifestyle, and health goals. Advances in genomics, metabolomics, and wearable technology allow scientists to understand how different foods affect an individual''s metabolism, blood sugar levels, and overall health. This data-driven approach aims to optimize health outcomes, prevent chronic diseases, and enhance well-being by tailoring diets to specific biological needs. For example, some individuals may metabolize certain nutrients differently due to genetic variations. While still in its early stages, personalized nutrition holds immense promise for transforming public health, offering more effective strategies for weight management, disease prevention, and athletic performance, moving towards a truly individualized approach to diet and health.','2025-06-10 11:00:00','2025-06-10 11:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000044','health');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000044','nutrition');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000044','user5','This is the future of diet plans!','2025-06-10 11:30:00');
 
 -- Article 45: user5 - Smart Grids
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000045','user5','Smart Grids: Modernizing Power Distribution for a Sustainable Future','How digital technology is transforming electricity networks for efficiency and resilience.','Smart grids represent the modernization of electricity delivery systems through the integration of digital technology, two-way communication, and automation. Unlike traditional grids, smart grids can intelligently manage electricity demand and supply, optimize energy distribution, and seamlessly integrate renewable energy sources like solar and wind power. They use sensors, smart meters, and advanced analytics to monitor grid conditions in real-time, detect outages, and respond dynamically to fluctuations. This leads to increased efficiency, reduced energy waste, enhanced reliability, and greater resilience against disruptions. Smart grids are crucial for achieving energy sustainability goals, enabling consumers to become active participants in energy management, and supporting the widespread adoption of electric vehicles. While requiring significant infrastructure investment and addressing cybersecurity concerns, smart grids are fundamental to building a cleaner, more reliable, and more efficient energy future for urban and rural areas alike.','2025-06-12 14:00:00','2025-06-12 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000045','Energy');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000045','Smart_Cities');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000045','user1');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000045','user5','Smart Grids: Modernizing Power Distribution for a Sustainable Future','How digital technology is transforming electricity networks for efficiency and resilience.','Smart grids represent the modernization of electricity delivery systems through the integration of digital technology, two-way communication, and automation. Unlike traditional grids, smart grids can intelligently manage electricity demand and supply, optimize energy distribution, and seamlessly integrate renewable energy sources like solar and wind power. They use sensors, smart meters, and advanced analytics to monitor grid conditions in real-time, detect outages, and respond dynamically to fluctuations. This leads to increased efficiency, reduced energy waste, enhanced reliability, and greater resilience against disruptions. Smart grids are crucial for achieving energy sustainability goals, enabling consumers to become active participants in energy management, and supporting the widespread adoption of electric vehicles. While requiring significant infrastructure investment and addressing cybersecurity concerns, smart grids are fundamental to building a cleaner, more reliable, and more efficient energy future for urban and rural areas alike.','2025-06-12 14:00:00','2025-06-12 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000045','energy');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000045','smart_cities');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000045','user1');
 
 -- Article 46: user1 - Human Genome Project
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000046','user1','The Human Genome Project: Mapping the Blueprint of Life','The monumental scientific endeavor to sequence the entire human genome.','The Human Genome Project (HGP), completed in 2003, was a monumental international scientific research project that aimed to determine the sequence of chemical base pairs that make up human DNA, and to identify and map all of the genes of the human genome. This ambitious undertaking provided the first complete blueprint of human genetic instruction, revolutionizing biology and medicine. The HGP has led to an explosion of discoveries in genomics, enabling a deeper understanding of human health and disease. It has paved the way for personalized medicine, gene therapy, and advanced diagnostics. While the project raised ethical, legal, and social issues regarding genetic privacy and discrimination, its legacy continues to drive innovation in biotechnology, offering unprecedented insights into human biology and opening new avenues for preventing, diagnosing, and treating a wide range of diseases.','2025-06-14 08:00:00','2025-06-14 08:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000046','Genomics');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000046','Biology');
-INSERT INTO Comments VALUES(31,'01980000-0000-0000-0000-000000000046','user2','A truly landmark achievement for science.','2025-06-14 08:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000046','user1','The Human Genome Project: Mapping the Blueprint of Life','The monumental scientific endeavor to sequence the entire human genome.','The Human Genome Project (HGP), completed in 2003, was a monumental international scientific research project that aimed to determine the sequence of chemical base pairs that make up human DNA, and to identify and map all of the genes of the human genome. This ambitious undertaking provided the first complete blueprint of human genetic instruction, revolutionizing biology and medicine. The HGP has led to an explosion of discoveries in genomics, enabling a deeper understanding of human health and disease. It has paved the way for personalized medicine, gene therapy, and advanced diagnostics. While the project raised ethical, legal, and social issues regarding genetic privacy and discrimination, its legacy continues to drive innovation in biotechnology, offering unprecedented insights into human biology and opening new avenues for preventing, diagnosing, and treating a wide range of diseases.','2025-06-14 08:00:00','2025-06-14 08:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000046','genomics');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000046','biology');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000046','user2','A truly landmark achievement for science.','2025-06-14 08:30:00');
 
 -- Article 47: user2 - Quantum Entanglement
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000047','user2','Quantum Entanglement: Spooky Action at a Distance','Exploring one of the most bizarre and powerful phenomena in quantum mechanics.','Quantum entanglement is a phenomenon where two or more particles become linked in such a way that they share the same fate, regardless of the distance separating them. Measuring the property of one entangled particle instantaneously influences the property of the other, even if they are light-years apart. Albert Einstein famously called this "spooky action at a distance" because it seems to violate the principle that information cannot travel faster than light. Despite its counter-intuitive nature, entanglement is a well-confirmed phenomenon and a cornerstone of quantum mechanics. It is the fundamental resource for emerging quantum technologies like quantum computing, quantum cryptography (QKD), and quantum teleportation. Understanding and harnessing entanglement is crucial for developing these next-generation technologies that promise to revolutionize computation, communication, and sensing, opening up possibilities previously confined to science fiction.','2025-06-16 12:00:00','2025-06-16 12:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000047','Quantum_Physics');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000047','Physics');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000047','user3');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000047','user2','Quantum Entanglement: Spooky Action at a Distance','Exploring one of the most bizarre and powerful phenomena in quantum mechanics.','Quantum entanglement is a phenomenon where two or more particles become linked in such a way that they share the same fate, regardless of the distance separating them. Measuring the property of one entangled particle instantaneously influences the property of the other, even if they are light-years apart. Albert Einstein famously called this "spooky action at a distance" because it seems to violate the principle that information cannot travel faster than light. Despite its counter-intuitive nature, entanglement is a well-confirmed phenomenon and a cornerstone of quantum mechanics. It is the fundamental resource for emerging quantum technologies like quantum computing, quantum cryptography (QKD), and quantum teleportation. Understanding and harnessing entanglement is crucial for developing these next-generation technologies that promise to revolutionize computation, communication, and sensing, opening up possibilities previously confined to science fiction.','2025-06-16 12:00:00','2025-06-16 12:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000047','quantum_physics');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000047','physics');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000047','user3');
 
 -- Article 48: user3 - AI in Robotics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000048','user3','AI in Robotics: Building Intelligent Autonomous Systems','The synergy between artificial intelligence and robotics for advanced automation.','The integration of artificial intelligence into robotics is driving the development of truly intelligent and autonomous systems capable of perceiving, reasoning, and acting in complex environments. AI algorithms, particularly machine learning and computer vision, enable robots to learn from data, recognize objects, navigate dynamic spaces, and make decisions without constant human intervention. This synergy is transforming fields from manufacturing and logistics to healthcare and exploration. For instance, AI-powered robots can perform delicate surgeries, inspect hazardous environments, or provide personalized assistance to the elderly. Challenges include improving robot dexterity, enhancing human-robot collaboration, ensuring ethical decision-making, and addressing safety concerns. As AI continues to imbue robots with greater intelligence and adaptability, they will become increasingly ubiquitous, fundamentally changing industries, reshaping labor markets, and expanding the possibilities of automation in our daily lives.','2025-06-18 17:00:00','2025-06-18 17:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000048','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000048','Robotics');
-INSERT INTO Comments VALUES(32,'01980000-0000-0000-0000-000000000048','user4','Autonomous navigation is a huge leap.','2025-06-18 17:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000048','user3','AI in Robotics: Building Intelligent Autonomous Systems','The synergy between artificial intelligence and robotics for advanced automation.','The integration of artificial intelligence into robotics is driving the development of truly intelligent and autonomous systems capable of perceiving, reasoning, and acting in complex environments. AI algorithms, particularly machine learning and computer vision, enable robots to learn from data, recognize objects, navigate dynamic spaces, and make decisions without constant human intervention. This synergy is transforming fields from manufacturing and logistics to healthcare and exploration. For instance, AI-powered robots can perform delicate surgeries, inspect hazardous environments, or provide personalized assistance to the elderly. Challenges include improving robot dexterity, enhancing human-robot collaboration, ensuring ethical decision-making, and addressing safety concerns. As AI continues to imbue robots with greater intelligence and adaptability, they will become increasingly ubiquitous, fundamentally changing industries, reshaping labor markets, and expanding the possibilities of automation in our daily lives.','2025-06-18 17:00:00','2025-06-18 17:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000048','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000048','robotics');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000048','user4','Autonomous navigation is a huge leap.','2025-06-18 17:30:00');
 
 -- Article 49: user4 - Carbon Capture
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000049','user4','Carbon Capture Technologies: A Key to Climate Change Mitigation','Exploring methods to remove CO2 from the atmosphere and industrial emissions.','Carbon capture, utilization, and storage (CCUS) technologies are crucial for mitigating climate change by preventing large amounts of carbon dioxide (CO2) from entering the atmosphere or by removing it directly. These technologies capture CO2 from industrial sources (like power plants and factories) or directly from the air (Direct Air Capture - DAC). Once captured, the CO2 can be permanently stored underground in geological formations or utilized to create new products like fuels, building materials, or chemicals. While CCUS faces challenges in terms of cost, energy consumption, and public acceptance, ongoing research and development are making these technologies more efficient and scalable. CCUS is considered a vital tool in achieving net-zero emissions targets, especially for hard-to-abate sectors, complementing efforts to reduce emissions and accelerate the global transition to a sustainable, low-carbon economy.','2025-06-20 09:45:00','2025-06-20 09:45:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000049','Climate_Change');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000049','Sustainability');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000049','user5');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000049','user4','Carbon Capture Technologies: A Key to Climate Change Mitigation','Exploring methods to remove CO2 from the atmosphere and industrial emissions.','Carbon capture, utilization, and storage (CCUS) technologies are crucial for mitigating climate change by preventing large amounts of carbon dioxide (CO2) from entering the atmosphere or by removing it directly. These technologies capture CO2 from industrial sources (like power plants and factories) or directly from the air (Direct Air Capture - DAC). Once captured, the CO2 can be permanently stored underground in geological formations or utilized to create new products like fuels, building materials, or chemicals. While CCUS faces challenges in terms of cost, energy consumption, and public acceptance, ongoing research and development are making these technologies more efficient and scalable. CCUS is considered a vital tool in achieving net-zero emissions targets, especially for hard-to-abate sectors, complementing efforts to reduce emissions and accelerate the global transition to a sustainable, low-carbon economy.','2025-06-20 09:45:00','2025-06-20 09:45:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000049','climate_change');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000049','sustainability');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000049','user5');
 
 -- Article 50: user5 - Neuroprosthetics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000050','user5','Neuroprosthetics: Restoring Senses and Movement','The cutting-edge field of devices that interface with the nervous system.','Neuroprosthetics is an interdisciplinary field focused on creating devices that interface directly with the nervous system to restore lost sensory, motor, or cognitive functions. These devices range from cochlear implants that restore hearing and retinal implants that provide partial vision, to brain-computer interfaces (BCIs) that allow individuals with paralysis to control prosthetic limbs or communicate using their thoughts. The technology involves electrodes implanted in the brain or nerves that either stimulate neural activity or record brain signals, translating them into commands for external devices. Challenges include achieving long-term biocompatibility, improving signal processing, and refining surgical techniques. Neuroprosthetics offers profound hope for individuals living with disabilities, fundamentally transforming their quality of life and pushing the boundaries of medical science and engineering by bridging the gap between biology and technology.','2025-06-22 13:00:00','2025-06-22 13:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000050','Neuroscience');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000050','Medicine');
-INSERT INTO Comments VALUES(33,'01980000-0000-0000-0000-000000000050','user1','The advancements in this field are truly inspiring.','2025-06-22 13:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000050','user5','Neuroprosthetics: Restoring Senses and Movement','The cutting-edge field of devices that interface with the nervous system.','Neuroprosthetics is an interdisciplinary field focused on creating devices that interface directly with the nervous system to restore lost sensory, motor, or cognitive functions. These devices range from cochlear implants that restore hearing and retinal implants that provide partial vision, to brain-computer interfaces (BCIs) that allow individuals with paralysis to control prosthetic limbs or communicate using their thoughts. The technology involves electrodes implanted in the brain or nerves that either stimulate neural activity or record brain signals, translating them into commands for external devices. Challenges include achieving long-term biocompatibility, improving signal processing, and refining surgical techniques. Neuroprosthetics offers profound hope for individuals living with disabilities, fundamentally transforming their quality of life and pushing the boundaries of medical science and engineering by bridging the gap between biology and technology.','2025-06-22 13:00:00','2025-06-22 13:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000050','neuroscience');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000050','medicine');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000050','user1','The advancements in this field are truly inspiring.','2025-06-22 13:30:00');
 
 -- Article 51: user1 - AI in Art
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000051','user1','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-24 09:00:00','2025-06-24 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000051','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000051','Art');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000051','user3');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000051','user1','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-24 09:00:00','2025-06-24 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000051','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000051','art');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000051','user3');
 
 -- Article 52: user2 - Green Building
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000052','user2','Green Building: Sustainable Architecture for the Future','Principles and practices of environmentally responsible construction.','Green building, or sustainable architecture, is a practice that aims to minimize the environmental impact of buildings throughout their entire lifecycle, from design and construction to operation and demolition. Key principles include efficient use of energy, water, and other resources; protection of occupant health and productivity; and reduction of waste, pollution, and environmental degradation. This involves incorporating renewable energy systems (solar panels), using sustainable materials (recycled content, low-VOC), optimizing natural light and ventilation, and designing for water conservation. Certifications like LEED (Leadership in Energy and Environmental Design) guide the process. Green buildings offer numerous benefits, including lower operating costs, improved indoor air quality, and reduced carbon footprints. As urban populations grow and climate concerns intensify, green building practices are becoming essential for creating healthy, resilient, and environmentally responsible communities.','2025-06-26 14:00:00','2025-06-26 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000052','Sustainability');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000052','Architecture');
-INSERT INTO Comments VALUES(34,'01980000-0000-0000-0000-000000000052','user4','LEED certification is a great benchmark.','2025-06-26 14:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000052','user2','Green Building: Sustainable Architecture for the Future','Principles and practices of environmentally responsible construction.','Green building, or sustainable architecture, is a practice that aims to minimize the environmental impact of buildings throughout their entire lifecycle, from design and construction to operation and demolition. Key principles include efficient use of energy, water, and other resources; protection of occupant health and productivity; and reduction of waste, pollution, and environmental degradation. This involves incorporating renewable energy systems (solar panels), using sustainable materials (recycled content, low-VOC), optimizing natural light and ventilation, and designing for water conservation. Certifications like LEED (Leadership in Energy and Environmental Design) guide the process. Green buildings offer numerous benefits, including lower operating costs, improved indoor air quality, and reduced carbon footprints. As urban populations grow and climate concerns intensify, green building practices are becoming essential for creating healthy, resilient, and environmentally responsible communities.','2025-06-26 14:00:00','2025-06-26 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000052','sustainability');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000052','architecture');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000052','user4','LEED certification is a great benchmark.','2025-06-26 14:30:00');
 
 -- Article 53: user3 - Robotics in Agriculture
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000053','user3','Robotics in Agriculture: Precision Farming for a Sustainable Future','How artificial intelligence and robotics are optimizing crop yields and resource management.','The integration of robotics and artificial intelligence is transforming agriculture, enabling precision farming techniques that enhance efficiency, reduce waste, and promote sustainability. AI-powered systems can analyze vast datasets from drones, sensors, and satellite imagery to monitor crop health, predict yields, and detect diseases or pests early. This allows farmers to apply water, fertilizers, and pesticides precisely where needed, minimizing environmental impact and maximizing resource efficiency. Robotics integrated with AI can automate tasks like planting, harvesting, and weeding, addressing labor shortages and improving operational speed. Predictive analytics helps optimize planting schedules and irrigation strategies based on weather patterns and soil conditions. While initial investment can be high, AI in agriculture promises to increase food production to meet global demands, reduce environmental footprints, and create more resilient and profitable farming operations, contributing significantly to global food security and sustainable development goals.','2025-06-28 09:00:00','2025-06-28 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000053','Robotics');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000053','Agriculture');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000053','user2');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000053','user3','Robotics in Agriculture: Precision Farming for a Sustainable Future','How artificial intelligence and robotics are optimizing crop yields and resource management.','The integration of robotics and artificial intelligence is transforming agriculture, enabling precision farming techniques that enhance efficiency, reduce waste, and promote sustainability. AI-powered systems can analyze vast datasets from drones, sensors, and satellite imagery to monitor crop health, predict yields, and detect diseases or pests early. This allows farmers to apply water, fertilizers, and pesticides precisely where needed, minimizing environmental impact and maximizing resource efficiency. Robotics integrated with AI can automate tasks like planting, harvesting, and weeding, addressing labor shortages and improving operational speed. Predictive analytics helps optimize planting schedules and irrigation strategies based on weather patterns and soil conditions. While initial investment can be high, AI in agriculture promises to increase food production to meet global demands, reduce environmental footprints, and create more resilient and profitable farming operations, contributing significantly to global food security and sustainable development goals.','2025-06-28 09:00:00','2025-06-28 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000053','robotics');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000053','agriculture');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000053','user2');
 
 -- Article 54: user4 - AI in Gaming
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000054','user4','AI in Gaming: Smarter NPCs and Dynamic Worlds','How artificial intelligence is enhancing player experiences and game development.','Artificial intelligence has long been a core component of video games, driving the behavior of non-player characters (NPCs) and creating challenging gameplay. Modern AI advancements, particularly in machine learning, are taking gaming to new levels, enabling smarter and more adaptive NPCs, dynamically generated content, and personalized player experiences. AI can learn from player behavior to create more realistic opponents, design complex levels, or even generate entire game worlds. Beyond traditional AI, generative AI is being explored for creating unique quests, characters, and storylines. This not only enhances immersion and replayability but also streamlines game development by automating certain creative processes. While challenges remain in balancing AI difficulty and ensuring fair play, AI in gaming promises to deliver richer, more engaging, and infinitely varied interactive entertainment experiences, pushing the boundaries of digital creativity and player interaction.','2025-06-30 13:00:00','2025-06-30 13:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000054','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000054','Gaming');
-INSERT INTO Comments VALUES(35,'01980000-0000-0000-0000-000000000054','user1','Procedural generation with AI is mind-blowing.','2025-06-30 13:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000054','user4','AI in Gaming: Smarter NPCs and Dynamic Worlds','How artificial intelligence is enhancing player experiences and game development.','Artificial intelligence has long been a core component of video games, driving the behavior of non-player characters (NPCs) and creating challenging gameplay. Modern AI advancements, particularly in machine learning, are taking gaming to new levels, enabling smarter and more adaptive NPCs, dynamically generated content, and personalized player experiences. AI can learn from player behavior to create more realistic opponents, design complex levels, or even generate entire game worlds. Beyond traditional AI, generative AI is being explored for creating unique quests, characters, and storylines. This not only enhances immersion and replayability but also streamlines game development by automating certain creative processes. While challenges remain in balancing AI difficulty and ensuring fair play, AI in gaming promises to deliver richer, more engaging, and infinitely varied interactive entertainment experiences, pushing the boundaries of digital creativity and player interaction.','2025-06-30 13:00:00','2025-06-30 13:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000054','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000054','gaming');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000054','user1','Procedural generation with AI is mind-blowing.','2025-06-30 13:30:00');
 
 -- Article 55: user5 - Wearable Technology
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000055','user5','Wearable Technology: Enhancing Health and Daily Life','The growth and impact of smart devices worn on the body.','Wearable technology, encompassing smartwatches, fitness trackers, smart glasses, and even smart clothing, is increasingly integrated into our daily lives, offering a seamless blend of technology and personal well-being. These devices continuously collect data on health metrics (heart rate, sleep patterns, activity levels), provide notifications, facilitate communication, and offer personalized insights. Beyond consumer electronics, wearables are finding critical applications in healthcare for remote patient monitoring, disease management, and rehabilitation. In industrial settings, smart glasses can provide augmented reality overlays for workers, enhancing efficiency and safety. Challenges include battery life, data privacy, accuracy of sensors, and user acceptance. As wearable technology becomes more sophisticated, miniaturized, and integrated with AI, it promises to provide a more comprehensive and proactive approach to personal health management and human augmentation, transforming how we interact with information and our environment.','2025-06-01 17:00:00','2025-06-01 17:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000055','Wearables');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000055','Health');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000055','user3');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000055','user5','Wearable Technology: Enhancing Health and Daily Life','The growth and impact of smart devices worn on the body.','Wearable technology, encompassing smartwatches, fitness trackers, smart glasses, and even smart clothing, is increasingly integrated into our daily lives, offering a seamless blend of technology and personal well-being. These devices continuously collect data on health metrics (heart rate, sleep patterns, activity levels), provide notifications, facilitate communication, and offer personalized insights. Beyond consumer electronics, wearables are finding critical applications in healthcare for remote patient monitoring, disease management, and rehabilitation. In industrial settings, smart glasses can provide augmented reality overlays for workers, enhancing efficiency and safety. Challenges include battery life, data privacy, accuracy of sensors, and user acceptance. As wearable technology becomes more sophisticated, miniaturized, and integrated with AI, it promises to provide a more comprehensive and proactive approach to personal health management and human augmentation, transforming how we interact with information and our environment.','2025-06-01 17:00:00','2025-06-01 17:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000055','wearables');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000055','health');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000055','user3');
 
 -- Article 56: user1 - Exoplanet Atmospheres
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000056','user1','Exoplanet Atmospheres: Searching for Biosignatures Beyond Earth','How scientists are analyzing distant worlds for signs of life.','The study of exoplanet atmospheres is a cutting-edge field in astronomy, crucial for the ongoing search for life beyond Earth. By analyzing the light that passes through an exoplanet''s atmosphere as it transits its star, astronomers can detect the chemical fingerprints of various gases. The presence of certain combinations of gases, known as biosignatures (e.g., oxygen, methane, water vapor), could indicate the presence of biological activity. Telescopes like the James Webb Space Telescope are providing unprecedented data, allowing for detailed spectroscopic analysis of these distant atmospheres. While current technology limits our ability to definitively confirm life, each new atmospheric detection narrows down the possibilities and refines our understanding of planetary habitability. This research is a pivotal step in answering one of humanity''s most profound questions: Are we alone in the universe?','2025-06-03 09:30:00','2025-06-03 09:30:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000056','Space');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000056','Exoplanets');
-INSERT INTO Comments VALUES(36,'01980000-0000-0000-0000-000000000056','user2','JWST is truly a game-changer for this field.','2025-06-03 10:00:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000056','user1','Exoplanet Atmospheres: Searching for Biosignatures Beyond Earth','How scientists are analyzing distant worlds for signs of life.','The study of exoplanet atmospheres is a cutting-edge field in astronomy, crucial for the ongoing search for life beyond Earth. By analyzing the light that passes through an exoplanet''s atmosphere as it transits its star, astronomers can detect the chemical fingerprints of various gases. The presence of certain combinations of gases, known as biosignatures (e.g., oxygen, methane, water vapor), could indicate the presence of biological activity. Telescopes like the James Webb Space Telescope are providing unprecedented data, allowing for detailed spectroscopic analysis of these distant atmospheres. While current technology limits our ability to definitively confirm life, each new atmospheric detection narrows down the possibilities and refines our understanding of planetary habitability. This research is a pivotal step in answering one of humanity''s most profound questions: Are we alone in the universe?','2025-06-03 09:30:00','2025-06-03 09:30:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000056','space');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000056','exoplanets');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000056','user2','JWST is truly a game-changer for this field.','2025-06-03 10:00:00');
 
 -- Article 57: user2 - AI in Art
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000057','user2','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-05 14:00:00','2025-06-05 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000057','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000057','Art');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000057','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000057','user2','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-05 14:00:00','2025-06-05 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000057','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000057','art');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000057','user4');
 
 -- Article 58: user3 - Sustainable Fashion
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000058','user3','Sustainable Fashion: Redefining the Industry for a Greener Planet','Innovations and practices driving eco-friendly and ethical clothing production.','Sustainable fashion is a movement and process of fostering change to fashion products and the fashion system towards greater ecological integrity and social justice. It addresses the environmental impact of fast fashion, which includes excessive water consumption, pesticide use, chemical pollution, and textile waste. Practices include using eco-friendly materials (organic cotton, recycled fabrics), adopting ethical labor practices, promoting circularity (repair, reuse, recycle), and reducing carbon emissions throughout the supply chain. Innovations like bio-fabricated textiles (grown from microbes) and closed-loop manufacturing systems are emerging. While challenges remain in scaling these practices and consumer awareness, sustainable fashion aims to create a more responsible and equitable industry. It encourages consumers to make conscious choices, supporting brands that prioritize environmental protection and social well-being, moving towards a future where fashion is both stylish and sustainable.','2025-06-07 10:00:00','2025-06-07 10:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000058','Sustainability');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000058','Fashion');
-INSERT INTO Comments VALUES(37,'01980000-0000-0000-0000-000000000058','user5','Fast fashion is such a huge problem.','2025-06-07 10:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000058','user3','Sustainable Fashion: Redefining the Industry for a Greener Planet','Innovations and practices driving eco-friendly and ethical clothing production.','Sustainable fashion is a movement and process of fostering change to fashion products and the fashion system towards greater ecological integrity and social justice. It addresses the environmental impact of fast fashion, which includes excessive water consumption, pesticide use, chemical pollution, and textile waste. Practices include using eco-friendly materials (organic cotton, recycled fabrics), adopting ethical labor practices, promoting circularity (repair, reuse, recycle), and reducing carbon emissions throughout the supply chain. Innovations like bio-fabricated textiles (grown from microbes) and closed-loop manufacturing systems are emerging. While challenges remain in scaling these practices and consumer awareness, sustainable fashion aims to create a more responsible and equitable industry. It encourages consumers to make conscious choices, supporting brands that prioritize environmental protection and social well-being, moving towards a future where fashion is both stylish and sustainable.','2025-06-07 10:00:00','2025-06-07 10:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000058','sustainability');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000058','fashion');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000058','user5','Fast fashion is such a huge problem.','2025-06-07 10:30:00');
 
 -- Article 59: user4 - Digital Therapeutics
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000059','user4','Digital Therapeutics: Software as Medical Treatment','The rise of evidence-based software interventions for health conditions.','Digital therapeutics (DTx) are evidence-based software programs that deliver medical interventions directly to patients to prevent, manage, or treat a medical disorder or disease. Unlike general health apps, DTx products are rigorously tested in clinical trials, regulated by health authorities, and prescribed by healthcare professionals. They can address a wide range of conditions, including chronic diseases like diabetes, mental health disorders, and substance abuse, by providing behavioral interventions, cognitive exercises, or educational content. DTx offers scalable, accessible, and personalized treatment options, often complementing or replacing traditional therapies. Challenges include regulatory pathways, reimbursement models, and ensuring patient engagement. As technology integrates deeper into healthcare, DTx represents a significant shift towards software-driven medicine, offering innovative solutions to improve patient outcomes and expand access to high-quality care, especially in underserved areas.','2025-06-09 14:00:00','2025-06-09 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000059','Healthcare');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000059','Digital_Health');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000059','user1');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000059','user4','Digital Therapeutics: Software as Medical Treatment','The rise of evidence-based software interventions for health conditions.','Digital therapeutics (DTx) are evidence-based software programs that deliver medical interventions directly to patients to prevent, manage, or treat a medical disorder or disease. Unlike general health apps, DTx products are rigorously tested in clinical trials, regulated by health authorities, and prescribed by healthcare professionals. They can address a wide range of conditions, including chronic diseases like diabetes, mental health disorders, and substance abuse, by providing behavioral interventions, cognitive exercises, or educational content. DTx offers scalable, accessible, and personalized treatment options, often complementing or replacing traditional therapies. Challenges include regulatory pathways, reimbursement models, and ensuring patient engagement. As technology integrates deeper into healthcare, DTx represents a significant shift towards software-driven medicine, offering innovative solutions to improve patient outcomes and expand access to high-quality care, especially in underserved areas.','2025-06-09 14:00:00','2025-06-09 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000059','healthcare');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000059','digital_health');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000059','user1');
 
 -- Article 60: user5 - Geothermal Energy
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000060','user5','Geothermal Energy: Tapping Earth''s Inner Heat','Exploring the potential of geothermal power as a renewable energy source.','Geothermal energy harnesses the heat from within the Earth''s core, providing a continuous, reliable, and renewable source of power. Geothermal power plants tap into hot water and steam reservoirs deep underground to generate electricity, while geothermal heat pumps use the stable underground temperature for heating and cooling buildings. Unlike solar or wind, geothermal energy is not dependent on weather conditions, offering a constant base-load power supply. Its environmental footprint is relatively low, with minimal greenhouse gas emissions. Challenges include high upfront drilling costs, geographical limitations to suitable reservoirs, and induced seismicity in some cases. However, advancements in drilling technology and enhanced geothermal systems (EGS) are expanding its potential. Geothermal energy is a vital component of a diversified renewable energy portfolio, offering a sustainable and consistent power solution for a decarbonized future.','2025-06-11 11:00:00','2025-06-11 11:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000060','Energy');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000060','Renewables');
-INSERT INTO Comments VALUES(38,'01980000-0000-0000-0000-000000000060','user2','Constant base-load power is a huge advantage.','2025-06-11 11:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000060','user5','Geothermal Energy: Tapping Earth''s Inner Heat','Exploring the potential of geothermal power as a renewable energy source.','Geothermal energy harnesses the heat from within the Earth''s core, providing a continuous, reliable, and renewable source of power. Geothermal power plants tap into hot water and steam reservoirs deep underground to generate electricity, while geothermal heat pumps use the stable underground temperature for heating and cooling buildings. Unlike solar or wind, geothermal energy is not dependent on weather conditions, offering a constant base-load power supply. Its environmental footprint is relatively low, with minimal greenhouse gas emissions. Challenges include high upfront drilling costs, geographical limitations to suitable reservoirs, and induced seismicity in some cases. However, advancements in drilling technology and enhanced geothermal systems (EGS) are expanding its potential. Geothermal energy is a vital component of a diversified renewable energy portfolio, offering a sustainable and consistent power solution for a decarbonized future.','2025-06-11 11:00:00','2025-06-11 11:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000060','energy');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000060','renewables');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000060','user2','Constant base-load power is a huge advantage.','2025-06-11 11:30:00');
 
 -- Article 61: user1 - Space-Based Solar Power
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000061','user1','Space-Based Solar Power: Harvesting Energy from Orbit','The ambitious concept of collecting solar energy in space and beaming it to Earth.','Space-based solar power (SBSP) is an ambitious concept that involves collecting solar energy in orbit and wirelessly transmitting it to Earth. Unlike ground-based solar panels, SBSP systems would receive sunlight 24/7, unaffected by weather, night, or atmospheric absorption, providing a constant stream of clean energy. The idea involves large orbital solar arrays that convert sunlight into electricity, then into microwaves or lasers, which are beamed to receiving antennas (rectennas) on Earth. While the engineering challenges are immense—requiring massive structures in space and efficient wireless power transmission—the potential benefits are revolutionary: limitless, clean, and dispatchable energy that could power the entire planet. Ongoing research and technological advancements in lightweight materials, robotics for in-space assembly, and wireless power transfer are bringing this futuristic energy solution closer to reality, offering a powerful tool in the fight against climate change.','2025-06-13 15:00:00','2025-06-13 15:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000061','Space');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000061','Energy');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000061','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000061','user1','Space-Based Solar Power: Harvesting Energy from Orbit','The ambitious concept of collecting solar energy in space and beaming it to Earth.','Space-based solar power (SBSP) is an ambitious concept that involves collecting solar energy in orbit and wirelessly transmitting it to Earth. Unlike ground-based solar panels, SBSP systems would receive sunlight 24/7, unaffected by weather, night, or atmospheric absorption, providing a constant stream of clean energy. The idea involves large orbital solar arrays that convert sunlight into electricity, then into microwaves or lasers, which are beamed to receiving antennas (rectennas) on Earth. While the engineering challenges are immense—requiring massive structures in space and efficient wireless power transmission—the potential benefits are revolutionary: limitless, clean, and dispatchable energy that could power the entire planet. Ongoing research and technological advancements in lightweight materials, robotics for in-space assembly, and wireless power transfer are bringing this futuristic energy solution closer to reality, offering a powerful tool in the fight against climate change.','2025-06-13 15:00:00','2025-06-13 15:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000061','space');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000061','energy');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000061','user4');
 
 -- Article 62: user2 - AI in Art
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000062','user2','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-15 10:00:00','2025-06-15 10:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000062','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000062','Art');
-INSERT INTO Comments VALUES(39,'01980000-0000-0000-0000-000000000062','user3','The debate around AI art is so interesting.','2025-06-15 10:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000062','user2','AI and Creativity: The Dawn of Algorithmic Art','Exploring how artificial intelligence is influencing and creating art.','The intersection of artificial intelligence and art is a rapidly evolving field, challenging traditional notions of creativity and authorship. AI algorithms, particularly generative adversarial networks (GANs) and transformers, are now capable of producing original artworks, music compositions, and even literary pieces. These systems learn from vast datasets of existing art to generate new creations that often surprise with their aesthetic qualities. AI can also assist human artists by providing inspiration, automating tedious tasks, or enabling new forms of interactive art. This raises fascinating questions about what constitutes "art" and who the "artist" is when an algorithm is involved. While some view AI art as a threat to human creativity, others see it as a powerful new tool that expands artistic possibilities. As AI becomes more sophisticated, the collaboration between human ingenuity and algorithmic power promises to unlock unprecedented forms of artistic expression and redefine the boundaries of creative endeavor.','2025-06-15 10:00:00','2025-06-15 10:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000062','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000062','art');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000062','user3','The debate around AI art is so interesting.','2025-06-15 10:30:00');
 
 -- Article 63: user3 - Sustainable Agriculture
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000063','user3','Sustainable Agriculture: Feeding the World Responsibly','Practices and innovations for environmentally friendly food production.','Sustainable agriculture is a method of farming that aims to produce food in a way that is environmentally friendly, economically viable, and socially responsible. It focuses on long-term ecological balance and resource conservation, moving away from practices that deplete soil, pollute water, and reduce biodiversity. Key practices include crop rotation, cover cropping, reduced tillage, integrated pest management, and efficient water use. Innovations like precision agriculture (using AI and sensors), vertical farming, and agroforestry are enhancing sustainability. The goal is to ensure food security for a growing global population while preserving natural resources and ecosystems for future generations. Sustainable agriculture is a critical component of addressing climate change, biodiversity loss, and rural development, fostering resilient food systems that can adapt to environmental challenges and provide healthy food for all.','2025-06-17 14:00:00','2025-06-17 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000063','Agriculture');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000063','Sustainability');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000063','user5');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000063','user3','Sustainable Agriculture: Feeding the World Responsibly','Practices and innovations for environmentally friendly food production.','Sustainable agriculture is a method of farming that aims to produce food in a way that is environmentally friendly, economically viable, and socially responsible. It focuses on long-term ecological balance and resource conservation, moving away from practices that deplete soil, pollute water, and reduce biodiversity. Key practices include crop rotation, cover cropping, reduced tillage, integrated pest management, and efficient water use. Innovations like precision agriculture (using AI and sensors), vertical farming, and agroforestry are enhancing sustainability. The goal is to ensure food security for a growing global population while preserving natural resources and ecosystems for future generations. Sustainable agriculture is a critical component of addressing climate change, biodiversity loss, and rural development, fostering resilient food systems that can adapt to environmental challenges and provide healthy food for all.','2025-06-17 14:00:00','2025-06-17 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000063','agriculture');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000063','sustainability');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000063','user5');
 
 -- Article 64: user4 - Digital Health Records
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000064','user4','Digital Health Records: Streamlining Patient Care','The benefits and challenges of electronic health records in modern medicine.','Digital health records, also known as Electronic Health Records (EHRs) or Electronic Medical Records (EMRs), are digital versions of a patient''s paper chart. They are designed to make patient information instantly available to authorized healthcare providers, improving the quality, safety, and efficiency of care. Benefits include better coordination of care, reduced medical errors, improved diagnostics, and more efficient billing. EHRs can also facilitate research by aggregating anonymized patient data. Challenges involve ensuring data privacy and security, interoperability between different systems, high implementation costs, and the need for robust cybersecurity measures to protect sensitive patient information. Despite these hurdles, digital health records are fundamental to modern healthcare, enabling a more integrated, data-driven, and patient-centric approach to health management, ultimately leading to better health outcomes and a more efficient healthcare system.','2025-06-19 09:00:00','2025-06-19 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000064','Healthcare');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000064','Digital_Health');
-INSERT INTO Comments VALUES(40,'01980000-0000-0000-0000-000000000064','user1','Interoperability is still a major pain point.','2025-06-19 09:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000064','user4','Digital Health Records: Streamlining Patient Care','The benefits and challenges of electronic health records in modern medicine.','Digital health records, also known as Electronic Health Records (EHRs) or Electronic Medical Records (EMRs), are digital versions of a patient''s paper chart. They are designed to make patient information instantly available to authorized healthcare providers, improving the quality, safety, and efficiency of care. Benefits include better coordination of care, reduced medical errors, improved diagnostics, and more efficient billing. EHRs can also facilitate research by aggregating anonymized patient data. Challenges involve ensuring data privacy and security, interoperability between different systems, high implementation costs, and the need for robust cybersecurity measures to protect sensitive patient information. Despite these hurdles, digital health records are fundamental to modern healthcare, enabling a more integrated, data-driven, and patient-centric approach to health management, ultimately leading to better health outcomes and a more efficient healthcare system.','2025-06-19 09:00:00','2025-06-19 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000064','healthcare');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000064','digital_health');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000064','user1','Interoperability is still a major pain point.','2025-06-19 09:30:00');
 
 -- Article 65: user5 - Smart Homes
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000065','user5','Smart Homes: The Connected Living Experience','How IoT devices are transforming residential spaces into intelligent environments.','Smart homes integrate various Internet of Things (IoT) devices and systems to automate and remotely control functions like lighting, heating, security, and entertainment. From smart thermostats that learn preferences to voice-activated assistants and connected security cameras, these technologies enhance convenience, energy efficiency, and safety. Users can manage their home environment via smartphones, voice commands, or automated schedules. Smart homes contribute to energy savings by optimizing heating and cooling, and enhance security through intelligent surveillance and access control. Challenges include interoperability between different brands and platforms, cybersecurity risks, and ensuring user privacy. However, as technology becomes more affordable and user-friendly, smart homes are becoming increasingly popular, offering a personalized, efficient, and interconnected living experience that adapts to the needs and preferences of its occupants, fundamentally changing the residential landscape.','2025-06-21 14:00:00','2025-06-21 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000065','IoT');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000065','Smart_Home');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000065','user2');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000065','user5','Smart Homes: The Connected Living Experience','How IoT devices are transforming residential spaces into intelligent environments.','Smart homes integrate various Internet of Things (IoT) devices and systems to automate and remotely control functions like lighting, heating, security, and entertainment. From smart thermostats that learn preferences to voice-activated assistants and connected security cameras, these technologies enhance convenience, energy efficiency, and safety. Users can manage their home environment via smartphones, voice commands, or automated schedules. Smart homes contribute to energy savings by optimizing heating and cooling, and enhance security through intelligent surveillance and access control. Challenges include interoperability between different brands and platforms, cybersecurity risks, and ensuring user privacy. However, as technology becomes more affordable and user-friendly, smart homes are becoming increasingly popular, offering a personalized, efficient, and interconnected living experience that adapts to the needs and preferences of its occupants, fundamentally changing the residential landscape.','2025-06-21 14:00:00','2025-06-21 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000065','iot');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000065','smart_home');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000065','user2');
 
 -- Article 66: user1 - Asteroid Deflection
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000066','user1','Asteroid Deflection: Protecting Earth from Cosmic Impacts','The science and technology behind safeguarding our planet from asteroid threats.','Asteroid deflection is the science and engineering discipline focused on preventing potentially hazardous asteroids or comets from impacting Earth. While large, civilization-ending impacts are rare, smaller, regional impact events could cause significant devastation. Scientists track near-Earth objects (NEOs) to identify potential threats. Various deflection methods are being researched and tested, including kinetic impactors (hitting the asteroid with a spacecraft to alter its trajectory), gravity tractors (using a spacecraft''s gravitational pull to gently nudge the asteroid), and even laser ablation. The key is early detection, as even a small change in velocity applied far from Earth can result in a large miss distance. International collaboration and continued investment in planetary defense are crucial to develop and deploy the necessary technologies to protect our planet from cosmic impacts, ensuring the long-term safety of life on Earth.','2025-06-23 09:00:00','2025-06-23 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000066','Space');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000066','Planetary_Defense');
-INSERT INTO Comments VALUES(41,'01980000-0000-0000-0000-000000000066','user3','The DART mission was a great step!','2025-06-23 09:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000066','user1','Asteroid Deflection: Protecting Earth from Cosmic Impacts','The science and technology behind safeguarding our planet from asteroid threats.','Asteroid deflection is the science and engineering discipline focused on preventing potentially hazardous asteroids or comets from impacting Earth. While large, civilization-ending impacts are rare, smaller, regional impact events could cause significant devastation. Scientists track near-Earth objects (NEOs) to identify potential threats. Various deflection methods are being researched and tested, including kinetic impactors (hitting the asteroid with a spacecraft to alter its trajectory), gravity tractors (using a spacecraft''s gravitational pull to gently nudge the asteroid), and even laser ablation. The key is early detection, as even a small change in velocity applied far from Earth can result in a large miss distance. International collaboration and continued investment in planetary defense are crucial to develop and deploy the necessary technologies to protect our planet from cosmic impacts, ensuring the long-term safety of life on Earth.','2025-06-23 09:00:00','2025-06-23 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000066','space');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000066','planetary_defense');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000066','user3','The DART mission was a great step!','2025-06-23 09:30:00');
 
 -- Article 67: user2 - AI in Sports
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000067','user2','AI in Sports: Enhancing Performance and Fan Experience','How artificial intelligence is transforming athletics and sports entertainment.','Artificial intelligence is increasingly integrated into the world of sports, revolutionizing everything from athlete training and performance analysis to fan engagement and broadcasting. AI-powered analytics can process vast amounts of data from wearables, cameras, and sensors to provide coaches with deep insights into player performance, strategy optimization, and injury prevention. Computer vision systems track player movements and ball trajectories in real-time, offering enhanced statistics and automated highlights. Beyond the field, AI is transforming fan experience through personalized content delivery, immersive virtual reality broadcasts, and predictive analytics for fantasy sports. While raising questions about fairness and data privacy, AI in sports promises to push the boundaries of human athletic achievement, make games more engaging for viewers, and create new opportunities for sports science and entertainment, fundamentally changing how we play, watch, and understand sports.','2025-06-25 14:00:00','2025-06-25 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000067','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000067','Sports');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000067','user4');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000067','user2','AI in Sports: Enhancing Performance and Fan Experience','How artificial intelligence is transforming athletics and sports entertainment.','Artificial intelligence is increasingly integrated into the world of sports, revolutionizing everything from athlete training and performance analysis to fan engagement and broadcasting. AI-powered analytics can process vast amounts of data from wearables, cameras, and sensors to provide coaches with deep insights into player performance, strategy optimization, and injury prevention. Computer vision systems track player movements and ball trajectories in real-time, offering enhanced statistics and automated highlights. Beyond the field, AI is transforming fan experience through personalized content delivery, immersive virtual reality broadcasts, and predictive analytics for fantasy sports. While raising questions about fairness and data privacy, AI in sports promises to push the boundaries of human athletic achievement, make games more engaging for viewers, and create new opportunities for sports science and entertainment, fundamentally changing how we play, watch, and understand sports.','2025-06-25 14:00:00','2025-06-25 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000067','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000067','sports');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000067','user4');
 
 -- Article 68: user3 - Sustainable Packaging
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000068','user3','Sustainable Packaging: Reducing Waste and Environmental Impact','Innovations in packaging materials and design for a circular economy.','Sustainable packaging aims to minimize environmental impact throughout its lifecycle, from raw material sourcing to disposal. It addresses critical issues like plastic pollution, deforestation, and carbon emissions associated with traditional packaging. Key strategies include using renewable or recycled materials, designing for recyclability or compostability, reducing material usage, and optimizing for efficient transport. Innovations range from plant-based bioplastics and edible packaging to reusable container systems and advanced material recycling technologies. While challenges remain in cost, performance, and infrastructure for collection and processing, sustainable packaging is crucial for transitioning to a circular economy, where resources are kept in use for as long as possible. It empowers consumers to make more environmentally conscious choices and drives industries towards more responsible production and consumption patterns, contributing significantly to global waste reduction and climate goals.','2025-06-27 10:00:00','2025-06-27 10:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000068','Sustainability');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000068','Recycling');
-INSERT INTO Comments VALUES(42,'01980000-0000-0000-0000-000000000068','user5','Bioplastics are promising, but need to be truly compostable.','2025-06-27 10:30:00');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000068','user3','Sustainable Packaging: Reducing Waste and Environmental Impact','Innovations in packaging materials and design for a circular economy.','Sustainable packaging aims to minimize environmental impact throughout its lifecycle, from raw material sourcing to disposal. It addresses critical issues like plastic pollution, deforestation, and carbon emissions associated with traditional packaging. Key strategies include using renewable or recycled materials, designing for recyclability or compostability, reducing material usage, and optimizing for efficient transport. Innovations range from plant-based bioplastics and edible packaging to reusable container systems and advanced material recycling technologies. While challenges remain in cost, performance, and infrastructure for collection and processing, sustainable packaging is crucial for transitioning to a circular economy, where resources are kept in use for as long as possible. It empowers consumers to make more environmentally conscious choices and drives industries towards more responsible production and consumption patterns, contributing significantly to global waste reduction and climate goals.','2025-06-27 10:00:00','2025-06-27 10:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000068','sustainability');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000068','recycling');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000068','user5','Bioplastics are promising, but need to be truly compostable.','2025-06-27 10:30:00');
 
 -- Article 69: user4 - Digital Twins in Healthcare
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000069','user4','Digital Twins in Healthcare: Personalized Patient Models','Creating virtual replicas of patients for precision medicine and diagnostics.','Digital twins are emerging as a transformative technology in healthcare, allowing for the creation of virtual replicas of individual patients, organs, or even entire physiological systems. These "patient twins" are continuously updated with real-time data from wearables, medical imaging, electronic health records, and genomic information. By simulating how a patient''s body might react to different treatments, medications, or lifestyle changes, clinicians can develop highly personalized and predictive care plans. This technology holds immense promise for optimizing drug dosages, predicting disease progression, guiding surgical procedures, and enhancing medical training. While challenges include data integration, computational complexity, and ethical considerations around privacy, digital twins in healthcare offer an unprecedented level of precision and personalization, moving medicine towards a truly proactive, preventative, and individualized approach to patient care that could revolutionize diagnostics and treatment outcomes.','2025-06-29 14:00:00','2025-06-29 14:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000069','Digital_Twin');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000069','Healthcare');
-INSERT INTO FavArticles VALUES('01980000-0000-0000-0000-000000000069','user1');
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000069','user4','Digital Twins in Healthcare: Personalized Patient Models','Creating virtual replicas of patients for precision medicine and diagnostics.','Digital twins are emerging as a transformative technology in healthcare, allowing for the creation of virtual replicas of individual patients, organs, or even entire physiological systems. These "patient twins" are continuously updated with real-time data from wearables, medical imaging, electronic health records, and genomic information. By simulating how a patient''s body might react to different treatments, medications, or lifestyle changes, clinicians can develop highly personalized and predictive care plans. This technology holds immense promise for optimizing drug dosages, predicting disease progression, guiding surgical procedures, and enhancing medical training. While challenges include data integration, computational complexity, and ethical considerations around privacy, digital twins in healthcare offer an unprecedented level of precision and personalization, moving medicine towards a truly proactive, preventative, and individualized approach to patient care that could revolutionize diagnostics and treatment outcomes.','2025-06-29 14:00:00','2025-06-29 14:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000069','digital_twin');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000069','healthcare');
+INSERT INTO FavArticles(article, username) VALUES('01980000-0000-0000-0000-000000000069','user1');
 
 -- Article 70: user5 - AI in Climate Science
-INSERT INTO Articles VALUES('01980000-0000-0000-0000-000000000070','user5','AI in Climate Science: Enhancing Environmental Understanding','How artificial intelligence is accelerating climate research and solutions.','Artificial intelligence is becoming an indispensable tool in climate science, accelerating research, improving predictions, and aiding in the development of climate solutions. AI algorithms can analyze vast, complex climate datasets from satellites, sensors, and historical records to identify subtle patterns, predict extreme weather events, and model future climate scenarios with greater accuracy. Machine learning is used to refine climate models, optimize renewable energy systems, and monitor deforestation or glacier melt. AI also assists in developing smart grids, optimizing energy consumption, and designing new sustainable materials. While AI models require careful validation and can be computationally intensive, their ability to process and interpret massive amounts of environmental data offers unprecedented insights into Earth''s changing climate. AI is a powerful ally in the fight against climate change, helping scientists understand the crisis more deeply and empowering policymakers and industries to develop more effective mitigation and adaptation strategies.','2025-06-30 09:00:00','2025-06-30 09:00:00');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000070','AI');
-INSERT INTO ArticleTags VALUES('01980000-0000-0000-0000-000000000070','Climate_Change');
-INSERT INTO Comments VALUES(43,'01980000-0000-0000-0000-000000000070','user2','AI for predicting extreme weather is so important.','2025-06-30 09:30:00');
-
-INSERT INTO sqlite_sequence VALUES('Comments',43);
+INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at) VALUES('01980000-0000-0000-0000-000000000070','user5','AI in Climate Science: Enhancing Environmental Understanding','How artificial intelligence is accelerating climate research and solutions.','Artificial intelligence is becoming an indispensable tool in climate science, accelerating research, improving predictions, and aiding in the development of climate solutions. AI algorithms can analyze vast, complex climate datasets from satellites, sensors, and historical records to identify subtle patterns, predict extreme weather events, and model future climate scenarios with greater accuracy. Machine learning is used to refine climate models, optimize renewable energy systems, and monitor deforestation or glacier melt. AI also assists in developing smart grids, optimizing energy consumption, and designing new sustainable materials. While AI models require careful validation and can be computationally intensive, their ability to process and interpret massive amounts of environmental data offers unprecedented insights into Earth''s changing climate. AI is a powerful ally in the fight against climate change, helping scientists understand the crisis more deeply and empowering policymakers and industries to develop more effective mitigation and adaptation strategies.','2025-06-30 09:00:00','2025-06-30 09:00:00');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000070','ai');
+INSERT INTO ArticleTags(article, tag) VALUES('01980000-0000-0000-0000-000000000070','climate_change');
+INSERT INTO Comments(article, username, body, created_at) VALUES('01980000-0000-0000-0000-000000000070','user2','AI for predicting extreme weather is so important.','2025-06-30 09:30:00');
@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS DigestSubscriptions(
+    username text NOT NULL PRIMARY KEY REFERENCES Users(username) ON DELETE CASCADE ON UPDATE CASCADE,
+    frequency text NOT NULL CHECK (frequency IN ('daily', 'weekly')),
+    last_sent_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS TagFollows(
+    username text NOT NULL REFERENCES Users(username) ON DELETE CASCADE ON UPDATE CASCADE,
+    tag text NOT NULL,
+    PRIMARY KEY (username, tag)
+);
+
+CREATE INDEX IF NOT EXISTS tag_follows_tag ON TagFollows(tag);
@@ -0,0 +1,4 @@
+-- The original spelling of the normalised tags is lost
+DROP TABLE IF EXISTS TagAliases;
+
+ALTER TABLE Users DROP COLUMN role;
@@ -0,0 +1,11 @@
+ALTER TABLE Users ADD COLUMN role text NOT NULL DEFAULT 'user' CHECK (role IN ('user', 'admin'));
+
+CREATE TABLE IF NOT EXISTS TagAliases(
+    alias text NOT NULL PRIMARY KEY,
+    tag text NOT NULL CHECK (alias != tag)
+);
+
+CREATE INDEX IF NOT EXISTS tag_aliases_tag ON TagAliases(tag);
+
+-- Tags used to be stored as typed, the existing ones are folded together with the same rules as
+-- the new ones by the `normalize-tags` command, run once after this migration.
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS SeriesArticles;
+DROP TABLE IF EXISTS Series;
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS Series(
+    id INTEGER PRIMARY KEY,
+    author text NOT NULL REFERENCES Users(username) ON DELETE CASCADE ON UPDATE CASCADE,
+    title text NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (author, title)
+);
+
+-- An article is part of one series at most
+CREATE TABLE IF NOT EXISTS SeriesArticles(
+    article text NOT NULL PRIMARY KEY REFERENCES Articles(slug) ON DELETE CASCADE ON UPDATE CASCADE,
+    series INTEGER NOT NULL REFERENCES Series(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS series_articles_series ON SeriesArticles(series, position);
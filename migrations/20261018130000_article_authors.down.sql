@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS articles_owner;
+DROP TABLE IF EXISTS ArticleAuthors;
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS ArticleAuthors(
+    article text NOT NULL REFERENCES Articles(slug) ON DELETE CASCADE ON UPDATE CASCADE,
+    username text NOT NULL REFERENCES Users(username) ON DELETE CASCADE ON UPDATE CASCADE,
+    role text NOT NULL CHECK (role IN ('owner', 'editor')),
+    invited_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    -- NULL while the invitation is pending
+    accepted_at DATETIME NULL,
+    PRIMARY KEY (article, username)
+);
+
+CREATE INDEX IF NOT EXISTS article_authors_username ON ArticleAuthors(username);
+
+INSERT INTO ArticleAuthors(article, username, role, invited_at, accepted_at)
+SELECT slug, author, 'owner', created_at, created_at FROM Articles;
+
+-- Articles.author stays the owner, the trigger keeps both in sync for the new articles
+CREATE TRIGGER IF NOT EXISTS articles_owner AFTER INSERT ON Articles BEGIN
+    INSERT INTO ArticleAuthors(article, username, role, accepted_at) VALUES (NEW.slug, NEW.author, 'owner', CURRENT_TIMESTAMP);
+END;
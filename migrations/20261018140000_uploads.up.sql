@@ -0,0 +1,12 @@
+-- Files are stored on disk, named by the sha256 of their content; a row per uploader
+CREATE TABLE IF NOT EXISTS Uploads(
+    hash text NOT NULL,
+    username text NOT NULL REFERENCES Users(username) ON DELETE CASCADE ON UPDATE CASCADE,
+    mime text NOT NULL,
+    extension text NOT NULL,
+    size INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (hash, username)
+);
+
+CREATE INDEX IF NOT EXISTS uploads_username ON Uploads(username);
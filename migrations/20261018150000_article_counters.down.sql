@@ -0,0 +1,18 @@
+DROP INDEX IF EXISTS fav_articles_username;
+DROP INDEX IF EXISTS comments_article;
+DROP INDEX IF EXISTS articles_author;
+DROP INDEX IF EXISTS articles_created_at;
+
+DROP TRIGGER IF EXISTS comments_count_ad;
+DROP TRIGGER IF EXISTS comments_count_ai;
+DROP TRIGGER IF EXISTS fav_articles_count_ad;
+DROP TRIGGER IF EXISTS fav_articles_count_ai;
+
+DROP TRIGGER IF EXISTS articles_au;
+CREATE TRIGGER IF NOT EXISTS articles_au AFTER UPDATE ON articles BEGIN
+    INSERT INTO articles_fts(articles_fts, rowid, slug, title, description, body) VALUES ('delete', OLD.oid, OLD.slug, OLD.title, OLD.description, OLD.body);
+    INSERT INTO articles_fts(rowid, slug, title, description, body) VALUES (NEW.oid, NEW.slug, NEW.title, NEW.description, NEW.body);
+END;
+
+ALTER TABLE Articles DROP COLUMN comments_count;
+ALTER TABLE Articles DROP COLUMN favorites_count;
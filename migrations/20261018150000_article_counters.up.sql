@@ -0,0 +1,37 @@
+-- The listings read the counters from the article instead of counting them for every row
+ALTER TABLE Articles ADD COLUMN favorites_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE Articles ADD COLUMN comments_count INTEGER NOT NULL DEFAULT 0;
+
+-- Bumping a counter must not rebuild the full text entry of the article
+DROP TRIGGER IF EXISTS articles_au;
+CREATE TRIGGER IF NOT EXISTS articles_au AFTER UPDATE OF slug, title, description, body ON articles BEGIN
+    INSERT INTO articles_fts(articles_fts, rowid, slug, title, description, body) VALUES ('delete', OLD.oid, OLD.slug, OLD.title, OLD.description, OLD.body);
+    INSERT INTO articles_fts(rowid, slug, title, description, body) VALUES (NEW.oid, NEW.slug, NEW.title, NEW.description, NEW.body);
+END;
+
+UPDATE Articles SET
+    favorites_count = (SELECT COUNT(*) FROM FavArticles WHERE article = Articles.slug),
+    comments_count = (SELECT COUNT(*) FROM Comments WHERE article = Articles.slug);
+
+CREATE TRIGGER IF NOT EXISTS fav_articles_count_ai AFTER INSERT ON FavArticles BEGIN
+    UPDATE Articles SET favorites_count = favorites_count + 1 WHERE slug = NEW.article;
+END;
+
+CREATE TRIGGER IF NOT EXISTS fav_articles_count_ad AFTER DELETE ON FavArticles BEGIN
+    UPDATE Articles SET favorites_count = favorites_count - 1 WHERE slug = OLD.article;
+END;
+
+CREATE TRIGGER IF NOT EXISTS comments_count_ai AFTER INSERT ON Comments BEGIN
+    UPDATE Articles SET comments_count = comments_count + 1 WHERE slug = NEW.article;
+END;
+
+CREATE TRIGGER IF NOT EXISTS comments_count_ad AFTER DELETE ON Comments BEGIN
+    UPDATE Articles SET comments_count = comments_count - 1 WHERE slug = OLD.article;
+END;
+
+-- The listings are sorted by date, and the pages after a cursor start from this index
+CREATE INDEX IF NOT EXISTS articles_created_at ON Articles(created_at, slug);
+CREATE INDEX IF NOT EXISTS articles_author ON Articles(author);
+CREATE INDEX IF NOT EXISTS comments_article ON Comments(article, created_at);
+-- The primary key of FavArticles starts with the article, the favourites of a user need their own
+CREATE INDEX IF NOT EXISTS fav_articles_username ON FavArticles(username);
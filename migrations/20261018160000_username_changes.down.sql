@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS username_changes_username;
+DROP TABLE IF EXISTS UsernameChanges;
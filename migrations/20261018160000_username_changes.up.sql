@@ -0,0 +1,9 @@
+-- The former usernames of a user: the old profile links redirect to the current one, and
+-- nobody else can take them. The cascade keeps `username` current across later changes.
+CREATE TABLE IF NOT EXISTS UsernameChanges(
+    old_username text NOT NULL PRIMARY KEY,
+    username text NOT NULL REFERENCES Users(username) ON DELETE CASCADE ON UPDATE CASCADE,
+    changed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS username_changes_username ON UsernameChanges(username, changed_at);
@@ -0,0 +1,9 @@
+//! The personal data of a user as a ZIP archive of JSON files, downloaded from the settings.
+
+#[cfg(feature = "ssr")]
+mod server;
+#[cfg(feature = "ssr")]
+pub use server::*;
+
+/// Where the settings link to, behind the authentication like the settings page.
+pub const DOWNLOAD_PATH: &str = "/settings/data.zip";
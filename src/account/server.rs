@@ -0,0 +1,375 @@
+use axum::{
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use std::io::Write;
+
+#[derive(Debug)]
+pub enum ArchiveError {
+    Io(std::io::Error),
+    Database(sqlx::Error),
+    Zip(zip::result::ZipError),
+}
+
+impl std::fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(x) => write!(f, "{x}"),
+            Self::Database(x) => write!(f, "{x}"),
+            Self::Zip(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+impl From<std::io::Error> for ArchiveError {
+    fn from(x: std::io::Error) -> Self {
+        Self::Io(x)
+    }
+}
+
+impl From<sqlx::Error> for ArchiveError {
+    fn from(x: sqlx::Error) -> Self {
+        Self::Database(x)
+    }
+}
+
+impl From<zip::result::ZipError> for ArchiveError {
+    fn from(x: zip::result::ZipError) -> Self {
+        Self::Zip(x)
+    }
+}
+
+#[derive(Serialize)]
+struct Profile {
+    username: String,
+    email: String,
+    bio: Option<String>,
+    image: Option<String>,
+    role: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArticleData {
+    slug: String,
+    /// `owner` or `editor`, for the articles they were invited to.
+    role: String,
+    title: String,
+    description: String,
+    body: String,
+    tag_list: Vec<String>,
+    created_at: String,
+    updated_at: String,
+    favorites_count: i64,
+    comments_count: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentData {
+    article: String,
+    article_title: String,
+    body: String,
+    created_at: String,
+}
+
+#[derive(Serialize)]
+struct FavoriteData {
+    article: String,
+    title: String,
+}
+
+#[derive(Serialize)]
+struct Follows {
+    following: Vec<String>,
+    followers: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BookmarkData {
+    article: String,
+    title: String,
+    created_at: String,
+    read_at: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SeriesData {
+    title: String,
+    created_at: String,
+    articles: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UploadData {
+    url: String,
+    mime: String,
+    size: i64,
+    created_at: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DigestData {
+    frequency: String,
+    last_sent_at: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UsernameChangeData {
+    old_username: String,
+    changed_at: String,
+}
+
+/// What the user chose to follow and receive, besides the other users.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Subscriptions {
+    tags: Vec<String>,
+    digest: Option<DigestData>,
+}
+
+fn add_file(
+    zip: &mut zip::ZipWriter<std::io::Cursor<Vec<u8>>>,
+    name: &str,
+    content: &impl Serialize,
+) -> Result<(), ArchiveError> {
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    zip.start_file(name, options)?;
+    serde_json::to_writer_pretty(&mut *zip, content).map_err(std::io::Error::from)?;
+    zip.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Everything of `username`: the profile and its former usernames, the articles they own or
+/// co-author, the comments, the favorites, the bookmarks, the series, the uploads, the follows
+/// and the subscriptions, one JSON file each.
+#[tracing::instrument]
+pub async fn archive(username: &str) -> Result<Vec<u8>, ArchiveError> {
+    let db = crate::database::get_db();
+    let profile = sqlx::query_as!(
+        Profile,
+        "SELECT username, email, bio, image, role FROM Users WHERE username=$1",
+        username
+    )
+    .fetch_one(db)
+    .await?;
+
+    let articles = sqlx::query!(
+        "
+SELECT slug, title, description, body, created_at, updated_at, favorites_count, comments_count,
+    CASE WHEN author=$1 THEN 'owner' ELSE 'editor' END as \"role!: String\",
+    (SELECT json_group_array(tag) FROM ArticleTags WHERE article = Articles.slug) as \"tag_list!: String\"
+FROM Articles
+WHERE author=$1
+    OR slug IN (SELECT article FROM ArticleAuthors WHERE username=$1 and accepted_at IS NOT NULL)
+ORDER BY created_at",
+        username
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|x| ArticleData {
+        slug: x.slug,
+        role: x.role,
+        title: x.title,
+        description: x.description,
+        body: x.body,
+        tag_list: serde_json::from_str(&x.tag_list).unwrap_or_default(),
+        created_at: crate::transfer::format_date(x.created_at),
+        updated_at: crate::transfer::format_date(x.updated_at),
+        favorites_count: x.favorites_count,
+        comments_count: x.comments_count,
+    })
+    .collect::<Vec<_>>();
+
+    let comments = sqlx::query!(
+        "
+SELECT c.article, a.title, c.body, c.created_at
+FROM Comments as c JOIN Articles as a ON a.slug = c.article
+WHERE c.username=$1
+ORDER BY c.created_at, c.id",
+        username
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|x| CommentData {
+        article: x.article,
+        article_title: x.title,
+        body: x.body,
+        created_at: crate::transfer::format_date(x.created_at),
+    })
+    .collect::<Vec<_>>();
+
+    let favorites = sqlx::query_as!(
+        FavoriteData,
+        "
+SELECT f.article, a.title
+FROM FavArticles as f JOIN Articles as a ON a.slug = f.article
+WHERE f.username=$1
+ORDER BY a.created_at",
+        username
+    )
+    .fetch_all(db)
+    .await?;
+
+    let follows = Follows {
+        following: sqlx::query_scalar!(
+            "SELECT influencer FROM Follows WHERE follower=$1 ORDER BY influencer",
+            username
+        )
+        .fetch_all(db)
+        .await?,
+        followers: sqlx::query_scalar!(
+            "SELECT follower FROM Follows WHERE influencer=$1 ORDER BY follower",
+            username
+        )
+        .fetch_all(db)
+        .await?,
+    };
+
+    let bookmarks = sqlx::query!(
+        "
+SELECT b.article, a.title, b.created_at, b.read_at
+FROM Bookmarks as b JOIN Articles as a ON a.slug = b.article
+WHERE b.username=$1
+ORDER BY b.created_at",
+        username
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|x| BookmarkData {
+        article: x.article,
+        title: x.title,
+        created_at: crate::transfer::format_date(x.created_at),
+        read_at: x.read_at.map(crate::transfer::format_date),
+    })
+    .collect::<Vec<_>>();
+
+    let series = sqlx::query!(
+        "
+SELECT title, created_at,
+    (SELECT json_group_array(article) FROM
+        (SELECT article FROM SeriesArticles WHERE series = Series.id ORDER BY position)
+    ) as \"articles!: String\"
+FROM Series
+WHERE author=$1
+ORDER BY created_at, id",
+        username
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|x| SeriesData {
+        title: x.title,
+        created_at: crate::transfer::format_date(x.created_at),
+        articles: serde_json::from_str(&x.articles).unwrap_or_default(),
+    })
+    .collect::<Vec<_>>();
+
+    let uploads = sqlx::query!(
+        "SELECT hash, extension, mime, size, created_at FROM Uploads WHERE username=$1 ORDER BY created_at, hash",
+        username
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|x| UploadData {
+        url: format!("{}/{}.{}", crate::uploads::UPLOADS_PATH, x.hash, x.extension),
+        mime: x.mime,
+        size: x.size,
+        created_at: crate::transfer::format_date(x.created_at),
+    })
+    .collect::<Vec<_>>();
+
+    let subscriptions = Subscriptions {
+        tags: sqlx::query_scalar!(
+            "SELECT tag FROM TagFollows WHERE username=$1 ORDER BY tag",
+            username
+        )
+        .fetch_all(db)
+        .await?,
+        digest: sqlx::query!(
+            "SELECT frequency, last_sent_at FROM DigestSubscriptions WHERE username=$1",
+            username
+        )
+        .fetch_optional(db)
+        .await?
+        .map(|x| DigestData {
+            frequency: x.frequency,
+            last_sent_at: crate::transfer::format_date(x.last_sent_at),
+        }),
+    };
+
+    let username_changes = sqlx::query!(
+        "SELECT old_username, changed_at FROM UsernameChanges WHERE username=$1 ORDER BY changed_at",
+        username
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|x| UsernameChangeData {
+        old_username: x.old_username,
+        changed_at: crate::transfer::format_date(x.changed_at),
+    })
+    .collect::<Vec<_>>();
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    add_file(&mut zip, "profile.json", &profile)?;
+    add_file(&mut zip, "username_changes.json", &username_changes)?;
+    add_file(&mut zip, "articles.json", &articles)?;
+    add_file(&mut zip, "comments.json", &comments)?;
+    add_file(&mut zip, "favorites.json", &favorites)?;
+    add_file(&mut zip, "bookmarks.json", &bookmarks)?;
+    add_file(&mut zip, "series.json", &series)?;
+    add_file(&mut zip, "uploads.json", &uploads)?;
+    add_file(&mut zip, "follows.json", &follows)?;
+    add_file(&mut zip, "subscriptions.json", &subscriptions)?;
+    Ok(zip.finish()?.into_inner())
+}
+
+/// The archive of the logged in user, as an attachment.
+#[tracing::instrument]
+pub async fn download(headers: HeaderMap) -> Response {
+    let Some(username) = crate::auth::get_username_from_headers(&headers) else {
+        return (StatusCode::UNAUTHORIZED, "You need to be authenticated").into_response();
+    };
+    match archive(&username).await {
+        Ok(archive) => (
+            [
+                (header::CONTENT_TYPE, "application/zip".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"realworld-data-{}.zip\"",
+                        sqlx::types::chrono::Utc::now().format("%Y%m%d")
+                    ),
+                ),
+                (header::CACHE_CONTROL, "no-store".to_string()),
+            ],
+            archive,
+        )
+            .into_response(),
+        Err(ArchiveError::Database(sqlx::Error::RowNotFound)) => {
+            StatusCode::NOT_FOUND.into_response()
+        }
+        Err(x) => {
+            tracing::error!("problem while building the data archive of {username}: {x}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Problem while preparing your data",
+            )
+                .into_response()
+        }
+    }
+}
@@ -1,13 +1,14 @@
 use crate::components::navitems::NavItems;
+use crate::components::seo::{SITE_DESCRIPTION, SITE_NAME};
 use crate::routes::{
     article_modal::*, editor_modal::*, home_main::*, login_modal::*, reset_password_modal::*,
-    settings_modal::*, signup_modal::*, user_home::SearchAction,
+    settings_modal::*, signup_modal::*, tags_home::*, user_home::SearchAction,
 };
 use leptos::prelude::*;
-use leptos_meta::{provide_meta_context, Body, MetaTags, Stylesheet, Title};
+use leptos_meta::{provide_meta_context, Body, Meta, MetaTags, Stylesheet, Title};
 use leptos_router::{
     components::{Route, Router, Routes},
-    path,
+    path, SsrMode,
 };
 
 use reactive_stores::Store;
@@ -22,6 +23,18 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
                 <AutoReload options=options.clone() />
                 <HydrationScripts options />
                 <MetaTags />
+                <link
+                    rel="alternate"
+                    type="application/rss+xml"
+                    title="Latest articles"
+                    href="/feed.xml"
+                />
+                <link
+                    rel="alternate"
+                    type="application/atom+xml"
+                    title="Latest articles"
+                    href="/feed.xml?format=atom"
+                />
                 <link
                     rel="stylesheet"
                     href="https://cdnjs.cloudflare.com/ajax/libs/font-awesome/6.5.1/css/all.min.css"
@@ -117,8 +130,9 @@ pub fn App() -> impl IntoView {
         <Stylesheet id="leptos" href="/pkg/realworld-app-leptos-axum-sqlite.css" />
         <Body {..} class=body_class />
 
-        // sets the document title
-        <Title text="Welcome to Leptos" />
+        // every page sets its own title, followed by the name of the site
+        <Title formatter=|text: String| format!("{text} | {SITE_NAME}") />
+        <Meta name="description" content=SITE_DESCRIPTION />
 
         <Router>
             <nav class=move || {
@@ -142,9 +156,12 @@ pub fn App() -> impl IntoView {
                         path=path!("/")
                         view=move || view! { <HomeMain username user_profile=false /> }
                     />
+                    // the meta tags of the article are only known once it is loaded,
+                    // they have to be in the html sent to the crawlers
                     <Route
                         path=path!("article/:slug")
                         view=move || view! { <Article username /> }
+                        ssr=SsrMode::Async
                     />
                     <Route path=path!("/login") view=move || view! { <LoginForm login /> } />
                     <Route
@@ -152,7 +169,8 @@ pub fn App() -> impl IntoView {
                         view=move || view! { <ResetPassword logout /> }
                     />
                     <Route path=path!("/signup") view=move || view! { <SignupForm signup /> } />
-                    <Route path=path!("/settings") view=move || view! { <Settings logout /> } />
+                    <Route path=path!("/settings") view=move || view! { <Settings logout username /> } />
+                    <Route path=path!("/tags") view=move || view! { <TagsPage username /> } />
                     <Route path=path!("/editor") view=|| view! { <Editor /> } />
                     <Route path=path!("/editor/:slug") view=|| view! { <EditArticle /> } />
                     <Route
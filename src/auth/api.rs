@@ -9,6 +9,7 @@ pub enum SignupResponse {
     Success,
 }
 
+#[cfg(feature = "ssr")]
 #[tracing::instrument]
 pub fn validate_signup(
     username: String,
@@ -17,8 +18,8 @@ pub fn validate_signup(
 ) -> Result<crate::models::User, String> {
     crate::models::User::default()
         .set_username(username)?
-        .set_password(password)?
-        .set_email(email)
+        .set_email(email)?
+        .set_password(password)
 }
 
 #[tracing::instrument]
@@ -28,7 +29,13 @@ pub async fn signup_action(
     email: String,
     password: String,
 ) -> Result<SignupResponse, ServerFnError> {
-    match validate_signup(username.clone(), email, password) {
+    let validated = validate_signup(username.clone(), email, password);
+    if validated.is_ok() && crate::models::User::username_taken(username.clone()).await? {
+        return Ok(SignupResponse::CreateUserError(
+            "Duplicated user".to_string(),
+        ));
+    }
+    match validated {
         Ok(user) => match user.insert().await {
             Ok(_) => {
                 crate::auth::set_username(username).await;
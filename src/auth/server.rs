@@ -66,7 +66,7 @@ async fn redirect(req: Request<axum::body::Body>, next: axum::middleware::Next)
 pub(crate) fn decode_token(
     token: &str,
 ) -> Result<jsonwebtoken::TokenData<TokenClaims>, jsonwebtoken::errors::Error> {
-    let secret = env!("JWT_SECRET");
+    let secret = &crate::config::get_config().auth.jwt_secret;
 
     decode::<TokenClaims>(
         token,
@@ -76,7 +76,7 @@ pub(crate) fn decode_token(
 }
 
 pub(crate) fn encode_token(token_claims: TokenClaims) -> jsonwebtoken::errors::Result<String> {
-    let secret = env!("JWT_SECRET");
+    let secret = &crate::config::get_config().auth.jwt_secret;
 
     jsonwebtoken::encode(
         &jsonwebtoken::Header::default(),
@@ -85,6 +85,34 @@ pub(crate) fn encode_token(token_claims: TokenClaims) -> jsonwebtoken::errors::R
     )
 }
 
+/// Tokens for a single purpose (like unsubscribe links) are signed with a key derived from the
+/// scope, so they can never be used as a login token.
+pub(crate) fn encode_scoped_token(
+    scope: &str,
+    token_claims: TokenClaims,
+) -> jsonwebtoken::errors::Result<String> {
+    let secret = format!("{}/{scope}", crate::config::get_config().auth.jwt_secret);
+
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &token_claims,
+        &jsonwebtoken::EncodingKey::from_secret(secret.as_bytes()),
+    )
+}
+
+pub(crate) fn decode_scoped_token(
+    scope: &str,
+    token: &str,
+) -> Result<jsonwebtoken::TokenData<TokenClaims>, jsonwebtoken::errors::Error> {
+    let secret = format!("{}/{scope}", crate::config::get_config().auth.jwt_secret);
+
+    decode::<TokenClaims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )
+}
+
 #[tracing::instrument]
 pub(crate) fn get_username_from_headers(headers: &axum::http::HeaderMap) -> Option<String> {
     headers.get(header::COOKIE).and_then(|x| {
@@ -111,7 +139,7 @@ pub async fn set_username(username: String) -> bool {
     if let Some(res) = use_context::<leptos_axum::ResponseOptions>() {
         let token = encode_token(TokenClaims {
             sub: username,
-            exp: (sqlx::types::chrono::Utc::now().timestamp() as usize) + 3_600_000,
+            exp: crate::config::expires_in(crate::config::get_config().auth.session_lifetime),
         })
         .unwrap();
         res.insert_header(
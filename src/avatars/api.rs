@@ -0,0 +1,13 @@
+/// Where the generated avatars are served from, as `{AVATARS_PATH}/{username}.svg`.
+pub const AVATARS_PATH: &str = "/avatar";
+
+/// The image chosen by the user, or the avatar generated from the username when there is none.
+pub fn avatar_url(username: &str, image: Option<&str>) -> String {
+    match image.filter(|x| !x.is_empty()) {
+        Some(image) => image.to_string(),
+        None => format!(
+            "{AVATARS_PATH}/{}.svg",
+            crate::urls::encode_path_segment(username)
+        ),
+    }
+}
@@ -0,0 +1,6 @@
+mod api;
+#[cfg(feature = "ssr")]
+mod server;
+pub use api::*;
+#[cfg(feature = "ssr")]
+pub use server::*;
@@ -0,0 +1,63 @@
+use axum::{
+    extract::Path,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+
+const AVATAR_SIZE: u32 = 128;
+
+/// Serves the avatar of `{username}.svg`. It only depends on the username, so it can be
+/// cached forever.
+#[tracing::instrument]
+pub async fn avatar(Path(file): Path<String>, headers: HeaderMap) -> Response {
+    let Some(username) = file.strip_suffix(".svg").filter(|x| !x.is_empty()) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let hash = Sha256::digest(username.as_bytes());
+    let etag = format!("\"{:x}\"", hash);
+    if headers
+        .get(header::IF_NONE_MATCH)
+        .is_some_and(|x| x.as_bytes() == etag.as_bytes())
+    {
+        return StatusCode::NOT_MODIFIED.into_response();
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, "image/svg+xml".to_string()),
+            (
+                header::CACHE_CONTROL,
+                "public, max-age=31536000, immutable".to_string(),
+            ),
+            (header::ETAG, etag),
+        ],
+        render(username, &hash),
+    )
+        .into_response()
+}
+
+/// The initials of the username on a background colour picked from its hash.
+fn render(username: &str, hash: &[u8]) -> String {
+    let hue = u16::from_be_bytes([hash[0], hash[1]]) % 360;
+    let words = username
+        .split(|x: char| !x.is_alphanumeric())
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+    let initials = match words.as_slice() {
+        [] => "?".to_string(),
+        [word] => word.chars().take(2).collect(),
+        [first, second, ..] => first
+            .chars()
+            .take(1)
+            .chain(second.chars().take(1))
+            .collect(),
+    }
+    .to_uppercase();
+
+    let size = AVATAR_SIZE;
+    let font_size = size * 2 / 5;
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}"><rect width="{size}" height="{size}" fill="hsl({hue}, 55%, 45%)"/><text x="50%" y="50%" dy=".35em" text-anchor="middle" font-family="sans-serif" font-size="{font_size}" fill="#ffffff">{initials}</text></svg>"##
+    )
+}
@@ -0,0 +1,320 @@
+use crate::database::{MIGRATOR, RETIRED_MIGRATIONS};
+use axum::{
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqliteConnection, SqliteLockingMode},
+    ConnectOptions, Connection,
+};
+use std::path::{Path, PathBuf};
+
+/// The snapshots are named after the time they were taken, so they sort in that order.
+const SNAPSHOT_PREFIX: &str = "snapshot-";
+const SNAPSHOT_EXTENSION: &str = "db";
+
+#[derive(Debug)]
+pub enum BackupError {
+    Io(std::io::Error),
+    Database(sqlx::Error),
+    Invalid(String),
+    /// The database to replace is open somewhere else, most likely by the server.
+    InUse(sqlx::Error),
+}
+
+impl std::fmt::Display for BackupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(x) => write!(f, "{x}"),
+            Self::Database(x) => write!(f, "{x}"),
+            Self::Invalid(x) => write!(f, "invalid snapshot: {x}"),
+            Self::InUse(x) => write!(f, "the database is in use, stop the server first: {x}"),
+        }
+    }
+}
+
+impl std::error::Error for BackupError {}
+
+impl From<std::io::Error> for BackupError {
+    fn from(x: std::io::Error) -> Self {
+        Self::Io(x)
+    }
+}
+
+impl From<sqlx::Error> for BackupError {
+    fn from(x: sqlx::Error) -> Self {
+        Self::Database(x)
+    }
+}
+
+#[derive(Debug)]
+pub struct Snapshot {
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// A snapshot as the administrators see it, the layout of the server stays on the server.
+#[derive(Debug, serde::Serialize)]
+struct SnapshotResponse {
+    name: String,
+    size: u64,
+}
+
+impl From<Snapshot> for SnapshotResponse {
+    fn from(x: Snapshot) -> Self {
+        Self {
+            name: x
+                .path
+                .file_name()
+                .map(|x| x.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            size: x.size,
+        }
+    }
+}
+
+/// What a restore did, the previous database is kept next to the restored one.
+#[derive(Debug)]
+pub struct Restored {
+    pub previous: Option<PathBuf>,
+    /// The migrations of this build the snapshot is missing, they run at the next start.
+    pub pending_migrations: usize,
+}
+
+/// Takes a consistent copy of the live database with `VACUUM INTO`, the reads and the writes
+/// go on meanwhile. The copy is written under a temporary name, so a snapshot in `dir` is
+/// always complete.
+#[tracing::instrument]
+pub async fn snapshot(dir: &Path) -> Result<Snapshot, BackupError> {
+    tokio::fs::create_dir_all(dir).await?;
+    // The milliseconds tell apart a scheduled backup and one asked for in the same second, the
+    // fixed width keeps the names sorted by time for `prune`
+    let name = format!(
+        "{SNAPSHOT_PREFIX}{}.{SNAPSHOT_EXTENSION}",
+        sqlx::types::chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+    );
+    let path = dir.join(&name);
+    let partial = dir.join(format!("{name}.partial"));
+    sqlx::query("VACUUM INTO $1")
+        .bind(partial.to_string_lossy().into_owned())
+        .execute(crate::database::get_db())
+        .await?;
+    tokio::fs::rename(&partial, &path).await?;
+    let size = tokio::fs::metadata(&path).await?.len();
+    Ok(Snapshot { path, size })
+}
+
+/// The snapshots in `dir`, the oldest first.
+async fn snapshots(dir: &Path) -> Result<Vec<PathBuf>, BackupError> {
+    let mut snapshots = vec![];
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let is_snapshot = path
+            .file_name()
+            .and_then(|x| x.to_str())
+            .is_some_and(|x| x.starts_with(SNAPSHOT_PREFIX))
+            && path.extension().is_some_and(|x| x == SNAPSHOT_EXTENSION);
+        if is_snapshot {
+            snapshots.push(path);
+        }
+    }
+    snapshots.sort();
+    Ok(snapshots)
+}
+
+/// Removes the oldest snapshots of `dir` beyond the `keep` latest ones.
+#[tracing::instrument]
+pub async fn prune(dir: &Path, keep: usize) -> Result<usize, BackupError> {
+    let snapshots = snapshots(dir).await?;
+    let outdated = snapshots.len().saturating_sub(keep);
+    for path in &snapshots[..outdated] {
+        tokio::fs::remove_file(path).await?;
+    }
+    Ok(outdated)
+}
+
+/// A snapshot into the configured directory, then the retention.
+pub async fn backup() -> Result<Snapshot, BackupError> {
+    let config = &crate::config::get_config().backup;
+    let snapshot = snapshot(&config.dir).await?;
+    let removed = prune(&config.dir, config.keep).await?;
+    tracing::info!(
+        "backup {} taken ({} bytes), {removed} old ones removed",
+        snapshot.path.display(),
+        snapshot.size
+    );
+    Ok(snapshot)
+}
+
+/// Takes a backup every `backup.interval`, forever. Does nothing when the interval is 0.
+pub async fn run_scheduler() {
+    let interval = crate::config::get_config().backup.interval;
+    if interval == 0 {
+        return;
+    }
+    let period = std::time::Duration::from_secs(interval);
+    // Not at startup, the restarts would fill the directory
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+    loop {
+        interval.tick().await;
+        if let Err(x) = backup().await {
+            tracing::error!("problem while taking the backup: {x}");
+        }
+    }
+}
+
+/// Checks the snapshot is a sound database with a schema this build knows: every migration it
+/// has applied has to be one of ours, with the same checksum. Returns how many of ours it lacks.
+async fn validate(snapshot: &Path) -> Result<usize, BackupError> {
+    let mut conn = SqliteConnectOptions::new()
+        .filename(snapshot)
+        .read_only(true)
+        .connect()
+        .await?;
+    let integrity = sqlx::query_scalar::<_, String>("PRAGMA integrity_check")
+        .fetch_one(&mut conn)
+        .await?;
+    if integrity != "ok" {
+        return Err(BackupError::Invalid(format!(
+            "integrity check: {integrity}"
+        )));
+    }
+    let applied = sqlx::query_as::<_, (i64, bool, Vec<u8>)>(
+        "SELECT version, success, checksum FROM _sqlx_migrations ORDER BY version",
+    )
+    .fetch_all(&mut conn)
+    .await
+    .map_err(|x| BackupError::Invalid(format!("no migration history: {x}")))?
+    .into_iter()
+    .filter(|(version, ..)| !RETIRED_MIGRATIONS.contains(version))
+    .collect::<Vec<_>>();
+    conn.close().await?;
+
+    let known = MIGRATOR
+        .iter()
+        .filter(|x| x.migration_type.is_up_migration())
+        .collect::<Vec<_>>();
+    for (version, success, checksum) in &applied {
+        let Some(migration) = known.iter().find(|x| x.version == *version) else {
+            return Err(BackupError::Invalid(format!(
+                "migration {version} is unknown to this version of the application"
+            )));
+        };
+        if !success {
+            return Err(BackupError::Invalid(format!(
+                "migration {version} did not complete"
+            )));
+        }
+        if *migration.checksum != checksum[..] {
+            return Err(BackupError::Invalid(format!(
+                "migration {version} differs from this version of the application"
+            )));
+        }
+    }
+    Ok(known.len() - applied.len())
+}
+
+/// A connection holding the exclusive lock of the database until it is closed, refused
+/// right away while anything else has it open.
+async fn lock_exclusive(options: &SqliteConnectOptions) -> Result<SqliteConnection, BackupError> {
+    let mut conn = options
+        .clone()
+        .create_if_missing(false)
+        .locking_mode(SqliteLockingMode::Exclusive)
+        .busy_timeout(std::time::Duration::ZERO)
+        .connect()
+        .await
+        .map_err(BackupError::InUse)?;
+    // In the exclusive locking mode, the lock taken by a write transaction is only released
+    // when the connection closes
+    sqlx::query("BEGIN EXCLUSIVE")
+        .execute(&mut conn)
+        .await
+        .map_err(BackupError::InUse)?;
+    sqlx::query("COMMIT").execute(&mut conn).await?;
+    Ok(conn)
+}
+
+/// Replaces the configured database with `snapshot`, once validated. The server has to be
+/// stopped, the restore is refused while the database is open. The previous database is
+/// checkpointed and kept as `<name>.before-restore-<time>`.
+#[tracing::instrument]
+pub async fn restore(snapshot: &Path) -> Result<Restored, BackupError> {
+    let pending_migrations = validate(snapshot).await?;
+
+    let options = crate::database::connect_options(&crate::config::get_config().database)?;
+    let target = options.get_filename().to_path_buf();
+    let with_suffix = |suffix: &str| {
+        let mut path = target.clone().into_os_string();
+        path.push(suffix);
+        PathBuf::from(path)
+    };
+    let lock = if tokio::fs::try_exists(&target).await? {
+        Some(lock_exclusive(&options).await?)
+    } else {
+        None
+    };
+    // Copied next to the target first, the rename that swaps it in is atomic
+    let incoming = with_suffix(".restoring");
+    tokio::fs::copy(snapshot, &incoming).await?;
+
+    let previous = if let Some(mut conn) = lock {
+        // Everything in the WAL goes into the file before it is moved away
+        sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+            .execute(&mut conn)
+            .await?;
+        let previous = with_suffix(&format!(
+            ".before-restore-{}",
+            sqlx::types::chrono::Utc::now().format("%Y%m%dT%H%M%SZ")
+        ));
+        tokio::fs::rename(&target, &previous).await?;
+        // Only released once the file is out of the way
+        conn.close().await?;
+        Some(previous)
+    } else {
+        None
+    };
+    for suffix in ["-wal", "-shm"] {
+        let _ = tokio::fs::remove_file(with_suffix(suffix)).await;
+    }
+    tokio::fs::rename(&incoming, &target).await?;
+    Ok(Restored {
+        previous,
+        pending_migrations,
+    })
+}
+
+/// Takes a backup right away, for the administrators.
+#[tracing::instrument]
+pub async fn backup_now(headers: HeaderMap) -> Response {
+    let Some(username) = crate::auth::get_username_from_headers(&headers) else {
+        return (StatusCode::UNAUTHORIZED, "You need to be authenticated").into_response();
+    };
+    match crate::models::User::is_admin(username).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return (
+                StatusCode::FORBIDDEN,
+                "Only the administrators can take backups",
+            )
+                .into_response()
+        }
+        Err(x) => {
+            tracing::error!("problem while fetching the user role: {x:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+    match backup().await {
+        Ok(x) => Json(SnapshotResponse::from(x)).into_response(),
+        Err(x) => {
+            tracing::error!("problem while taking the backup: {x}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Problem while taking the backup",
+            )
+                .into_response()
+        }
+    }
+}
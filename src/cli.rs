@@ -0,0 +1,298 @@
+//! The maintenance commands of the server binary, run with the same configuration and models.
+
+use crate::models::User;
+use crate::transfer::OnConflict;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(about = "The Realworld Leptos server and its maintenance commands")]
+pub struct Cli {
+    /// Starts the server without any
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Starts the server
+    Serve,
+    /// Runs the pending migrations
+    Migrate,
+    /// Creates a user, the password is read from stdin when not given
+    CreateUser {
+        username: String,
+        email: String,
+        #[arg(long)]
+        password: Option<String>,
+        #[arg(long, value_enum, default_value_t = Role::User)]
+        role: Role,
+    },
+    /// Sets the password of a user, read from stdin when not given
+    ResetPassword {
+        username: String,
+        #[arg(long)]
+        password: Option<String>,
+    },
+    SetRole {
+        username: String,
+        #[arg(value_enum)]
+        role: Role,
+    },
+    /// Deletes a user with their articles, comments, follows and favourites
+    DeleteUser {
+        username: String,
+        /// Confirms the deletion
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Rebuilds the full-text search indexes from the articles and tags
+    ReindexSearch,
+    /// Folds the tags stored before the normalisation into their normalised spelling
+    NormalizeTags,
+    /// Writes the users, tags, articles, comments, follows and favorites as JSON Lines
+    Export {
+        /// Standard output without it
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+        /// Includes the password hashes, keep the file safe
+        #[arg(long)]
+        with_passwords: bool,
+    },
+    /// Reads an export in one transaction, all or nothing
+    Import {
+        /// Standard input without it
+        input: Option<PathBuf>,
+        /// What to do with the users and articles which already exist
+        #[arg(long, value_enum, default_value_t = OnConflict::Skip)]
+        on_conflict: OnConflict,
+    },
+    /// Generates users, articles, tags, follows, favourites and comments, the same ones for the
+    /// same options
+    Seed {
+        #[arg(long, default_value_t = 50)]
+        users: usize,
+        #[arg(long, default_value_t = 500)]
+        articles: usize,
+        /// How many distinct tags, their popularity follows a Zipf law
+        #[arg(long, default_value_t = 40)]
+        tags: usize,
+        /// The mean number of comments per article
+        #[arg(long, default_value_t = 3)]
+        comments: usize,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// Loads the demo content instead, user1 to user5 with their username as password
+        #[arg(long, conflicts_with_all = ["users", "articles", "tags", "comments", "seed"])]
+        demo: bool,
+    },
+    /// Takes a snapshot of the database into the backup directory
+    Backup,
+    /// Replaces the database with a snapshot, with the server stopped
+    Restore { snapshot: PathBuf },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Role {
+    User,
+    Admin,
+}
+
+impl Role {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+type CommandResult = Result<(), Box<dyn std::error::Error>>;
+
+fn read_password(password: Option<String>) -> Result<String, std::io::Error> {
+    if let Some(password) = password {
+        return Ok(password);
+    }
+    eprint!("password: ");
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// A change that has to touch exactly one row, the user exists.
+fn one_row(
+    result: sqlx::sqlite::SqliteQueryResult,
+    username: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if result.rows_affected() != 1 {
+        return Err(format!("no user {username}").into());
+    }
+    Ok(())
+}
+
+/// Runs a maintenance command, anything but `Serve`.
+pub async fn run(command: Command) -> CommandResult {
+    // The restore replaces the file, nothing may have it open
+    if let Command::Restore { snapshot } = &command {
+        let restored = crate::backup::restore(snapshot).await?;
+        if let Some(previous) = restored.previous {
+            println!("the previous database is kept as {}", previous.display());
+        }
+        println!(
+            "restored, {} migrations will run at the next start",
+            restored.pending_migrations
+        );
+        return Ok(());
+    }
+    crate::database::init_db()
+        .await
+        .map_err(|_| "the database was already initialized")?;
+
+    match command {
+        Command::Serve | Command::Restore { .. } => unreachable!("handled by the caller"),
+        Command::Migrate => {
+            let version = sqlx::query_scalar::<_, Option<i64>>(
+                "SELECT MAX(version) FROM _sqlx_migrations WHERE success",
+            )
+            .fetch_one(crate::database::get_db())
+            .await?;
+            println!(
+                "the database is at migration {}",
+                version.unwrap_or_default()
+            );
+        }
+        Command::CreateUser {
+            username,
+            email,
+            password,
+            role,
+        } => {
+            User::default()
+                .set_username(username.clone())?
+                .set_email(email)?
+                .set_password(read_password(password)?)?
+                .insert()
+                .await?;
+            User::set_role(username.clone(), role.as_str()).await?;
+            println!("user {username} created");
+        }
+        Command::ResetPassword { username, password } => {
+            let user = User::get(username.clone())
+                .await
+                .map_err(|_| format!("no user {username}"))?;
+            user.set_password(read_password(password)?)?
+                .update()
+                .await?;
+            println!("password of {username} changed");
+        }
+        Command::SetRole { username, role } => {
+            one_row(
+                User::set_role(username.clone(), role.as_str()).await?,
+                &username,
+            )?;
+            println!("{username} is now {}", role.as_str());
+        }
+        Command::DeleteUser { username, yes } => {
+            if !yes {
+                return Err(format!(
+                    "this deletes {username} and all their content, confirm with --yes"
+                )
+                .into());
+            }
+            one_row(User::delete(username.clone()).await?, &username)?;
+            println!("user {username} deleted");
+        }
+        Command::ReindexSearch => {
+            let mut transaction = crate::database::begin_write().await?;
+            for statement in [
+                "INSERT INTO articles_fts(articles_fts) VALUES ('rebuild')",
+                "INSERT INTO articletags_fts(articletags_fts) VALUES ('rebuild')",
+                "INSERT INTO articles_fts(articles_fts) VALUES ('optimize')",
+                "INSERT INTO articletags_fts(articletags_fts) VALUES ('optimize')",
+            ] {
+                sqlx::query(statement).execute(transaction.as_mut()).await?;
+            }
+            transaction.commit().await?;
+            println!("search indexes rebuilt");
+        }
+        Command::NormalizeTags => {
+            let mut transaction = crate::database::begin_write().await?;
+            let normalized = crate::models::normalize_stored_tags(&mut transaction).await?;
+            transaction.commit().await?;
+            println!("{normalized} tags normalized");
+        }
+        Command::Export {
+            output,
+            with_passwords,
+        } => {
+            let counts = match output {
+                Some(path) => {
+                    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+                    crate::transfer::export(&mut file, with_passwords).await?
+                }
+                None => {
+                    crate::transfer::export(&mut std::io::stdout().lock(), with_passwords).await?
+                }
+            };
+            eprintln!("{counts} exported");
+        }
+        Command::Import { input, on_conflict } => {
+            let progress = |counts: &crate::transfer::Counts| {
+                eprint!("\r{} lines read, {counts}", counts.lines);
+            };
+            let counts = match input {
+                Some(path) => {
+                    crate::transfer::import(
+                        std::io::BufReader::new(std::fs::File::open(path)?),
+                        on_conflict,
+                        progress,
+                    )
+                    .await?
+                }
+                None => {
+                    crate::transfer::import(std::io::stdin().lock(), on_conflict, progress).await?
+                }
+            };
+            eprintln!(
+                "\n{counts} imported, {} overwritten, {} renamed, {} skipped",
+                counts.overwritten, counts.renamed, counts.skipped
+            );
+        }
+        Command::Seed {
+            users,
+            articles,
+            tags,
+            comments,
+            seed,
+            demo,
+        } => {
+            if demo {
+                crate::seed::load_demo().await?;
+                println!("demo content loaded, log in as user1 to user5");
+                return Ok(());
+            }
+            let dataset = crate::seed::seed(&crate::seed::SeedOptions {
+                seed,
+                users,
+                articles,
+                tags,
+                comments,
+            })
+            .await?;
+            println!(
+                "{} users, {} articles, {} follows, {} favourites and {} comments added, the password is {}",
+                dataset.users.len(),
+                dataset.articles.len(),
+                dataset.follows.len(),
+                dataset.favourites.len(),
+                dataset.comments.len(),
+                crate::seed::PASSWORD
+            );
+        }
+        Command::Backup => {
+            let snapshot = crate::backup::backup().await?;
+            println!("{} ({} bytes)", snapshot.path.display(), snapshot.size);
+        }
+    }
+    Ok(())
+}
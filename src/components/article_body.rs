@@ -0,0 +1,58 @@
+use leptos::prelude::*;
+
+/// A piece of the body of an article: paragraphs of text, or an image on its own line.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BodyBlock {
+    Text(String),
+    Image { alt: String, src: String },
+}
+
+/// Splits the body of an article, where a line like `![description](url)` is an image.
+pub fn body_blocks(body: &str) -> Vec<BodyBlock> {
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    for line in body.lines() {
+        match parse_image(line) {
+            Some(image) => {
+                if !text.trim().is_empty() {
+                    blocks.push(BodyBlock::Text(text.trim().to_string()));
+                }
+                text.clear();
+                blocks.push(image);
+            }
+            None => {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+    if !text.trim().is_empty() {
+        blocks.push(BodyBlock::Text(text.trim().to_string()));
+    }
+    blocks
+}
+
+fn parse_image(line: &str) -> Option<BodyBlock> {
+    let (alt, src) = line
+        .trim()
+        .strip_prefix("![")?
+        .strip_suffix(')')?
+        .split_once("](")?;
+    crate::uploads::is_image_url(src).then(|| BodyBlock::Image {
+        alt: alt.to_string(),
+        src: src.to_string(),
+    })
+}
+
+#[component]
+pub fn ArticleBody(body: String) -> impl IntoView {
+    body_blocks(&body)
+        .into_iter()
+        .map(|x| match x {
+            BodyBlock::Text(text) => view! { <p>{text}</p> }.into_any(),
+            BodyBlock::Image { alt, src } => {
+                view! { <img src=src alt=alt class="my-2 max-w-full rounded-lg" /> }.into_any()
+            }
+        })
+        .collect_view()
+}
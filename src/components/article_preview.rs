@@ -1,18 +1,21 @@
+use super::series::SeriesBadge;
 use super::user_icons::AuthorUserIcon;
 use crate::app::{GlobalState, GlobalStateStoreFields};
 use leptos::prelude::*;
 use leptos_router::{components::*, hooks::use_query_map};
 use reactive_stores::Store;
 
-use super::buttons::{ButtonFav, ButtonFavFavourited, ButtonFollow};
-use crate::models::Article;
+use super::buttons::{
+    ButtonBookmark, ButtonBookmarkRead, ButtonFav, ButtonFavFavourited, ButtonFollow,
+};
+use crate::models::ArticleFeed;
 
 pub type ArticleSignal = RwSignal<crate::models::Article>;
 
 #[component]
 pub fn ArticlePreviewList(
     username: crate::auth::UsernameSignal,
-    articles: Resource<Result<Vec<Article>, ServerFnError>>,
+    articles: Resource<Result<ArticleFeed, ServerFnError>>,
 ) -> impl IntoView {
     let articles_view = move || {
         articles.with(move |x| {
@@ -20,7 +23,7 @@ pub fn ArticlePreviewList(
                 view! {
                     <Suspense fallback=move || view! { <p>"Loading..."</p> }>
                         <For
-                            each=move || res.clone().unwrap_or_default().into_iter().enumerate()
+                            each=move || res.clone().map(|x| x.articles).unwrap_or_default().into_iter().enumerate()
                             key=|(i, _)| *i
                             children=move |(_, article): (usize, crate::models::Article)| {
                                 let article = RwSignal::new(article);
@@ -53,6 +56,7 @@ fn ArticlePreview(username: crate::auth::UsernameSignal, article: ArticleSignal)
             <div class="flex items-center gap-4 mb-4">
                 <ArticleMeta username=username article=article is_preview=true />
             </div>
+            <SeriesBadge article />
             <A href=move || format!("/article/{}", article.with(|x| x.slug.clone()))>
                 <h2 class="text-2xl font-bold mb-2 text-gray-800">
                     {move || article.with(|x| x.title.to_string())}
@@ -70,9 +74,7 @@ fn ArticlePreview(username: crate::auth::UsernameSignal, article: ArticleSignal)
                         "Read more..."
                     </span>
                 </A>
-                <Show when=move || {
-                    article.with(|x| !x.tag_list.is_empty() && x.tag_list.first().unwrap() != "")
-                }>
+                <Show when=move || article.with(|x| !x.tag_list.is_empty())>
                     // fallback=|| view! { <span>"No tags"</span> }
                     <div class="flex flex-wrap gap-1">
                         <i class="fa-solid fa-hashtag py-1"></i>
@@ -81,7 +83,7 @@ fn ArticlePreview(username: crate::auth::UsernameSignal, article: ArticleSignal)
                                 article.with(|x| x.tag_list.clone().into_iter().enumerate())
                             }
                             key=|(i, _)| *i
-                            children=move |(_, tag): (usize, String)| {
+                            children=move |(_, tag): (usize, crate::models::Tag)| {
                                 let tag_now = tag.clone();
                                 view! {
                                     <span class="bg-gray-200 text-gray-700 px-2 py-1 rounded text-xs flex items-center gap-1">
@@ -91,7 +93,7 @@ fn ArticlePreview(username: crate::auth::UsernameSignal, article: ArticleSignal)
                                                 .set_tag(&tag_now)
                                                 .set_amount(per_page.get_untracked().unwrap())
                                                 .to_string()
-                                        }>{tag}</A>
+                                        }>{tag.to_string()}</A>
                                     </span>
                                 }
                             }
@@ -113,6 +115,13 @@ pub fn ArticleMeta(
     let article_owner = username.get_untracked().unwrap_or_default()
         == article.with_untracked(|x| x.author.username.to_string());
 
+    let co_author = move || {
+        username.with(|u| {
+            u.as_ref()
+                .is_some_and(|u| article.with(|x| x.co_authors.contains(u)))
+        })
+    };
+
     let (author, set_author) = signal(String::new());
     let author_user = move || {
         set_author(article.with(|x| x.author.username.to_string()));
@@ -124,6 +133,7 @@ pub fn ArticleMeta(
 
     let query = use_query_map();
     let favourite = move || query.with(|x| x.get("favourites").map(|_| true));
+    let bookmarks = move || query.with(|x| x.get("bookmarks").is_some());
 
     view! {
         <div class="article-meta">
@@ -170,7 +180,14 @@ pub fn ArticleMeta(
                                             }
                                         >
                                             <ButtonFav username article />
+                                            <ButtonBookmark username article />
                                             <ButtonFollow logged_user=username author />
+                                            <Show when=co_author>
+                                                <A href=editor_ref>
+                                                    <i class="fa-solid fa-pen-to-square w-4 h-4"></i>
+                                                    " Edit Article"
+                                                </A>
+                                            </Show>
                                         </Show>
                                     }
                                 }
@@ -206,6 +223,10 @@ pub fn ArticleMeta(
                     >
                         <ButtonFavFavourited article />
                     </Show>
+                    <ButtonBookmark username article />
+                    <Show when=bookmarks>
+                        <ButtonBookmarkRead article />
+                    </Show>
                 </Show>
             </div>
         </div>
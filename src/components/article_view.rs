@@ -1,5 +1,7 @@
 use super::buttons::ButtonFav;
+use crate::components::article_body::ArticleBody;
 use crate::components::article_preview::ArticleSignal;
+use crate::components::series::SeriesNavigator;
 use crate::components::user_icons::AuthorUserIcon;
 use crate::models::User;
 use crate::routes::article_modal::{get_article, ArticleResult, CommentSection};
@@ -69,8 +71,9 @@ pub fn ArticleViewPageModal(
                             </div>
                         </div>
                     </div>
+                    <SeriesNavigator article=article_signal />
                     <div class="mb-5">
-                        <p>{article_signal.get_untracked().body}</p>
+                        <ArticleBody body=article_signal.get_untracked().body.unwrap_or_default() />
                     </div>
                 </div>
                 <div class="mb-5 px-1 py-1">
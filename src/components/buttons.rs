@@ -18,7 +18,7 @@ pub async fn follow_action(other_user: String) -> Result<bool, ServerFnError> {
 #[cfg(feature = "ssr")]
 #[tracing::instrument]
 async fn toggle_follow(current: String, other: String) -> Result<bool, sqlx::Error> {
-    let db = crate::database::get_db();
+    let db = crate::database::get_writer();
     match sqlx::query!(
         "SELECT * FROM Follows WHERE follower=$1 and influencer=$2",
         current,
@@ -47,6 +47,100 @@ async fn toggle_follow(current: String, other: String) -> Result<bool, sqlx::Err
     }
 }
 
+#[server(FollowTagAction, "/api")]
+#[tracing::instrument]
+pub async fn follow_tag_action(tag: String, follow: bool) -> Result<bool, ServerFnError> {
+    let Some(username) = crate::auth::get_username() else {
+        return Err(ServerFnError::ServerError(
+            "You need to be authenticated".into(),
+        ));
+    };
+    let Some(tag) = crate::models::normalize_tag(&tag) else {
+        return Err(ServerFnError::ServerError("This is not a valid tag".into()));
+    };
+    let tag = crate::models::TagAlias::resolve(tag).await.map_err(|x| {
+        tracing::error!("problem while resolving the tag: {x:?}");
+        ServerFnError::new("error while updating the tag follow")
+    })?;
+    set_tag_follow(username, tag, follow).await.map_err(|x| {
+        tracing::error!("problem while updating the database: {x:?}");
+        ServerFnError::ServerError("error while updating the tag follow".into())
+    })
+}
+
+#[cfg(feature = "ssr")]
+#[tracing::instrument]
+async fn set_tag_follow(username: String, tag: String, follow: bool) -> Result<bool, sqlx::Error> {
+    let db = crate::database::get_writer();
+    if follow {
+        sqlx::query!(
+            "INSERT OR IGNORE INTO TagFollows(username, tag) VALUES ($1, $2)",
+            username,
+            tag
+        )
+        .execute(db)
+        .await
+        .map(|_| true)
+    } else {
+        sqlx::query!(
+            "DELETE FROM TagFollows WHERE username=$1 and tag=$2",
+            username,
+            tag
+        )
+        .execute(db)
+        .await
+        .map(|_| false)
+    }
+}
+
+#[server(FollowedTagsAction, "/api", "GetJson")]
+#[tracing::instrument]
+pub async fn followed_tags() -> Result<Vec<String>, ServerFnError> {
+    let Some(username) = crate::auth::get_username() else {
+        return Ok(vec![]);
+    };
+    sqlx::query_scalar!(
+        "SELECT tag FROM TagFollows WHERE username=$1 ORDER BY tag",
+        username
+    )
+    .fetch_all(crate::database::get_db())
+    .await
+    .map_err(|x| {
+        tracing::error!("problem while fetching the followed tags: {x:?}");
+        ServerFnError::ServerError("Problem while fetching the followed tags".into())
+    })
+}
+
+/// Follows or unfollows `tag` through the given action, so the caller can refetch what depends on it.
+#[component]
+pub fn ButtonFollowTag(
+    follow_tag: ServerAction<FollowTagAction>,
+    tag: String,
+    followed: bool,
+) -> impl IntoView {
+    view! {
+        <button
+            type="button"
+            class="rounded px-1 py-0.5 bg-gray-200 hover:bg-green-300 disabled:cursor-not-allowed"
+            disabled=move || follow_tag.pending().get()
+            on:click=move |_| {
+                follow_tag
+                    .dispatch(FollowTagAction {
+                        tag: tag.clone(),
+                        follow: !followed,
+                    });
+            }
+        >
+            <i class=if followed {
+                "fa-solid fa-minus w-4 h-4"
+            } else {
+                "fa-solid fa-plus w-4 h-4"
+            }></i>
+            {if followed { " Unfollow" } else { " Follow" }}
+        </button>
+    }
+}
+
 #[component]
 pub fn ButtonFollow(
     logged_user: crate::auth::UsernameSignal,
@@ -141,16 +235,42 @@ pub async fn fav_action(slug: String) -> Result<bool, ServerFnError> {
             "You need to be authenticated".into(),
         ));
     };
-    toggle_fav(slug, username).await.map_err(|x| {
-        tracing::error!("problem while updating the database: {x:?}");
-        ServerFnError::ServerError("error while updating the follow".into())
-    })
+    let fav = toggle_fav(slug.clone(), username.clone())
+        .await
+        .map_err(|x| {
+            tracing::error!("problem while updating the database: {x:?}");
+            ServerFnError::new("error while updating the follow")
+        })?;
+
+    match sqlx::query_scalar!("SELECT favorites_count FROM Articles WHERE slug=$1", slug)
+        .fetch_one(crate::database::get_db())
+        .await
+    {
+        Ok(count) => crate::live::publish(
+            crate::live::Topic::Article(slug.clone()),
+            crate::live::LiveEvent::FavouritesChanged {
+                article: slug.clone(),
+                count,
+                by: username.clone(),
+            },
+        ),
+        Err(x) => tracing::error!("problem while counting favourites: {x:?}"),
+    }
+    if fav {
+        crate::live::notify_author(
+            slug,
+            username.clone(),
+            format!("{username} favourited your article"),
+        )
+        .await;
+    }
+    Ok(fav)
 }
 
 #[cfg(feature = "ssr")]
 #[tracing::instrument]
 async fn toggle_fav(slug: String, username: String) -> Result<bool, sqlx::Error> {
-    let db = crate::database::get_db();
+    let db = crate::database::get_writer();
     match sqlx::query!(
         "SELECT * FROM FavArticles WHERE article=$1 and username=$2",
         slug,
@@ -309,3 +429,172 @@ pub fn ButtonFavFavourited(article: super::article_preview::ArticleSignal) -> im
         </div>
     }
 }
+
+#[server(BookmarkAction, "/api")]
+#[tracing::instrument]
+pub async fn bookmark_action(slug: String) -> Result<bool, ServerFnError> {
+    let Some(username) = crate::auth::get_username() else {
+        return Err(ServerFnError::ServerError(
+            "You need to be authenticated".into(),
+        ));
+    };
+    toggle_bookmark(slug, username).await.map_err(|x| {
+        tracing::error!("problem while updating the database: {x:?}");
+        ServerFnError::ServerError("error while updating the reading list".into())
+    })
+}
+
+#[cfg(feature = "ssr")]
+#[tracing::instrument]
+async fn toggle_bookmark(slug: String, username: String) -> Result<bool, sqlx::Error> {
+    let db = crate::database::get_writer();
+    match sqlx::query!(
+        "SELECT * FROM Bookmarks WHERE article=$1 and username=$2",
+        slug,
+        username
+    )
+    .fetch_one(db)
+    .await
+    {
+        Ok(_) => sqlx::query!(
+            "DELETE FROM Bookmarks WHERE article=$1 and username=$2",
+            slug,
+            username
+        )
+        .execute(db)
+        .await
+        .map(|_| false),
+        Err(sqlx::error::Error::RowNotFound) => sqlx::query!(
+            "INSERT INTO Bookmarks(article, username) VALUES ($1, $2)",
+            slug,
+            username
+        )
+        .execute(db)
+        .await
+        .map(|_| true),
+        Err(x) => Err(x),
+    }
+}
+
+#[server(BookmarkReadAction, "/api")]
+#[tracing::instrument]
+pub async fn bookmark_read_action(slug: String, read: bool) -> Result<bool, ServerFnError> {
+    let Some(username) = crate::auth::get_username() else {
+        return Err(ServerFnError::ServerError(
+            "You need to be authenticated".into(),
+        ));
+    };
+    sqlx::query!(
+        "UPDATE Bookmarks SET read_at=CASE WHEN $3 THEN CURRENT_TIMESTAMP ELSE NULL END
+WHERE article=$1 and username=$2",
+        slug,
+        username,
+        read,
+    )
+    .execute(crate::database::get_writer())
+    .await
+    .map(|_| read)
+    .map_err(|x| {
+        tracing::error!("problem while updating the database: {x:?}");
+        ServerFnError::ServerError("error while updating the reading list".into())
+    })
+}
+
+/// Adds the article to the private reading list of the logged user, or removes it.
+#[component]
+pub fn ButtonBookmark(
+    username: crate::auth::UsernameSignal,
+    article: super::article_preview::ArticleSignal,
+) -> impl IntoView {
+    let bookmark = ServerAction::<BookmarkAction>::new();
+
+    Effect::new(move || {
+        if let Some(result) = bookmark.value().get() {
+            match result {
+                Ok(bookmarked) => article.update(|x| {
+                    x.bookmarked = bookmarked;
+                    x.bookmark_read = false;
+                }),
+                Err(err) => tracing::error!("problem while bookmarking {err:?}"),
+            }
+        }
+    });
+
+    view! {
+        <Show when=move || username.with(Option::is_some)>
+            <button
+                type="button"
+                class="text-gray-600 hover:text-blue-500 transition-colors duration-200"
+                disabled=move || bookmark.pending().get()
+                on:click=move |_| {
+                    bookmark
+                        .dispatch(BookmarkAction {
+                            slug: article.with(|x| x.slug.to_string()),
+                        });
+                }
+            >
+                <Show
+                    when=move || article.with(|x| x.bookmarked)
+                    fallback=|| {
+                        view! {
+                            <i class="far fa-bookmark"></i>
+                            " Read later"
+                        }
+                    }
+                >
+                    <span class="text-blue-500">
+                        <i class="fas fa-bookmark"></i>
+                        " In reading list"
+                    </span>
+                </Show>
+            </button>
+        </Show>
+    }
+}
+
+/// Marks a bookmarked article as read or unread, used in the reading list.
+#[component]
+pub fn ButtonBookmarkRead(article: super::article_preview::ArticleSignal) -> impl IntoView {
+    let mark_read = ServerAction::<BookmarkReadAction>::new();
+
+    Effect::new(move || {
+        if let Some(result) = mark_read.value().get() {
+            match result {
+                Ok(read) => article.update(|x| x.bookmark_read = read),
+                Err(err) => tracing::error!("problem while marking as read {err:?}"),
+            }
+        }
+    });
+
+    view! {
+        <Show when=move || article.with(|x| x.bookmarked)>
+            <button
+                type="button"
+                class="text-gray-600 hover:text-green-600 transition-colors duration-200"
+                disabled=move || mark_read.pending().get()
+                on:click=move |_| {
+                    mark_read
+                        .dispatch(BookmarkReadAction {
+                            slug: article.with(|x| x.slug.to_string()),
+                            read: !article.with(|x| x.bookmark_read),
+                        });
+                }
+            >
+                <Show
+                    when=move || article.with(|x| x.bookmark_read)
+                    fallback=|| {
+                        view! {
+                            <i class="far fa-circle"></i>
+                            " Unread"
+                        }
+                    }
+                >
+                    <span class="text-green-600">
+                        <i class="fas fa-circle-check"></i>
+                        " Read"
+                    </span>
+                </Show>
+            </button>
+        </Show>
+    }
+}
@@ -0,0 +1,279 @@
+use super::article_preview::ArticleSignal;
+use crate::models::{ArticleAuthor, ArticleRole};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+#[server(ArticleAuthorsAction, "/api", "GetJson")]
+#[tracing::instrument]
+pub async fn article_authors(slug: String) -> Result<Vec<ArticleAuthor>, ServerFnError> {
+    let mut authors = ArticleAuthor::for_article(slug).await.map_err(|x| {
+        tracing::error!("problem while fetching the article authors: {x:?}");
+        ServerFnError::new("Problem while fetching the authors")
+    })?;
+    // The pending invitations are only shown to the owner, and each to the one invited
+    let me = crate::auth::get_username();
+    let owner = authors
+        .iter()
+        .any(|x| x.role == ArticleRole::Owner && Some(&x.username) == me.as_ref());
+    if !owner {
+        authors.retain(|x| x.accepted || Some(&x.username) == me.as_ref());
+    }
+    Ok(authors)
+}
+
+#[cfg(feature = "ssr")]
+async fn require_role(slug: &str, username: &str) -> Result<Option<ArticleRole>, ServerFnError> {
+    ArticleAuthor::role(slug.to_string(), username.to_string())
+        .await
+        .map_err(|x| {
+            tracing::error!("problem while fetching the article role: {x:?}");
+            ServerFnError::new("Problem while checking the permissions")
+        })
+}
+
+#[server(InviteCoAuthorAction, "/api")]
+#[tracing::instrument]
+pub async fn invite_co_author(slug: String, username: String) -> Result<(), ServerFnError> {
+    let Some(logged_user) = crate::auth::get_username() else {
+        return Err(ServerFnError::new("You need to be authenticated"));
+    };
+    if require_role(&slug, &logged_user).await? != Some(ArticleRole::Owner) {
+        return Err(ServerFnError::new(
+            "Only the owner of the article can invite co-authors",
+        ));
+    }
+    let username = username.trim().to_string();
+    if crate::models::User::get(username.clone()).await.is_err() {
+        return Err(ServerFnError::new(format!("There is no user {username}")));
+    }
+    ArticleAuthor::invite(slug.clone(), username.clone())
+        .await
+        .map_err(|x| {
+            if x.to_string().contains("UNIQUE constraint failed") {
+                ServerFnError::new(format!("{username} is already an author of this article"))
+            } else {
+                tracing::error!("problem while inviting a co-author: {x:?}");
+                ServerFnError::new("Problem while inviting the co-author")
+            }
+        })?;
+    crate::live::publish(
+        crate::live::Topic::User(username),
+        crate::live::LiveEvent::Notification {
+            message: format!("{logged_user} invited you to co-author an article"),
+            link: format!("/article/{slug}"),
+        },
+    );
+    Ok(())
+}
+
+#[server(RespondInviteAction, "/api")]
+#[tracing::instrument]
+pub async fn respond_invite(slug: String, accept: bool) -> Result<(), ServerFnError> {
+    let Some(logged_user) = crate::auth::get_username() else {
+        return Err(ServerFnError::new("You need to be authenticated"));
+    };
+    if accept {
+        ArticleAuthor::accept(slug, logged_user).await
+    } else {
+        ArticleAuthor::remove(slug, logged_user).await
+    }
+    .map(|_| ())
+    .map_err(|x| {
+        tracing::error!("problem while answering the invitation: {x:?}");
+        ServerFnError::new("Problem while answering the invitation")
+    })
+}
+
+/// Removes a co-author, by the owner or by the co-author leaving the article.
+#[server(RemoveCoAuthorAction, "/api")]
+#[tracing::instrument]
+pub async fn remove_co_author(slug: String, username: String) -> Result<(), ServerFnError> {
+    let Some(logged_user) = crate::auth::get_username() else {
+        return Err(ServerFnError::new("You need to be authenticated"));
+    };
+    if logged_user != username
+        && require_role(&slug, &logged_user).await? != Some(ArticleRole::Owner)
+    {
+        return Err(ServerFnError::new(
+            "Only the owner of the article can remove co-authors",
+        ));
+    }
+    ArticleAuthor::remove(slug, username)
+        .await
+        .map(|_| ())
+        .map_err(|x| {
+            tracing::error!("problem while removing a co-author: {x:?}");
+            ServerFnError::new("Problem while removing the co-author")
+        })
+}
+
+/// Invitations and co-authors of the article, for the people involved in writing it.
+#[component]
+pub fn CoAuthors(username: crate::auth::UsernameSignal, article: ArticleSignal) -> impl IntoView {
+    let slug = article.with_untracked(|x| x.slug.to_string());
+    let invite = ServerAction::<InviteCoAuthorAction>::new();
+    let respond = ServerAction::<RespondInviteAction>::new();
+    let remove = ServerAction::<RemoveCoAuthorAction>::new();
+    let authors = Resource::new(
+        move || {
+            (
+                invite.version().get(),
+                respond.version().get(),
+                remove.version().get(),
+            )
+        },
+        move |_| article_authors(slug.clone()),
+    );
+
+    // Keeps the co-authors shown next to the author in sync
+    Effect::new(move || {
+        if let Some(Ok(authors)) = authors.get() {
+            let co_authors = authors
+                .into_iter()
+                .filter(|x| x.role == ArticleRole::Editor && x.accepted)
+                .map(|x| x.username)
+                .collect::<Vec<_>>();
+            if article.with_untracked(|x| x.co_authors != co_authors) {
+                article.update(|x| x.co_authors = co_authors);
+            }
+        }
+    });
+
+    let slug = move || article.with(|x| x.slug.to_string());
+
+    view! {
+        <Transition>
+            {move || {
+                let me = username.get()?;
+                let authors = authors.get()?.ok()?;
+                let mine = authors.iter().find(|x| x.username == me).cloned()?;
+                Some(match (mine.role, mine.accepted) {
+                    (_, false) => {
+                        view! {
+                            <div class="mb-5 p-3 rounded-lg bg-yellow-100 flex gap-4 items-center">
+                                <span>"You are invited to co-author this article."</span>
+                                <button
+                                    type="button"
+                                    class="bg-blue-700 hover:bg-blue-800 text-white px-3 rounded-lg"
+                                    disabled=move || respond.pending().get()
+                                    on:click=move |_| {
+                                        respond
+                                            .dispatch(RespondInviteAction {
+                                                slug: slug(),
+                                                accept: true,
+                                            });
+                                    }
+                                >
+                                    "Accept"
+                                </button>
+                                <button
+                                    type="button"
+                                    class="text-red-600 hover:underline"
+                                    disabled=move || respond.pending().get()
+                                    on:click=move |_| {
+                                        respond
+                                            .dispatch(RespondInviteAction {
+                                                slug: slug(),
+                                                accept: false,
+                                            });
+                                    }
+                                >
+                                    "Decline"
+                                </button>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                    (ArticleRole::Editor, true) => {
+                        view! {
+                            <div class="mb-5 flex gap-4 items-center text-gray-600">
+                                <span>"You are a co-author of this article."</span>
+                                <button
+                                    type="button"
+                                    class="text-red-600 hover:underline"
+                                    disabled=move || remove.pending().get()
+                                    on:click=move |_| {
+                                        remove
+                                            .dispatch(RemoveCoAuthorAction {
+                                                slug: slug(),
+                                                username: me.clone(),
+                                            });
+                                    }
+                                >
+                                    "Leave"
+                                </button>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                    (ArticleRole::Owner, true) => {
+                        view! {
+                            <div class="mb-5 p-3 rounded-lg bg-gray-100">
+                                <h3 class="font-bold mb-2">"Co-authors"</h3>
+                                <ul class="mb-2">
+                                    {authors
+                                        .into_iter()
+                                        .filter(|x| x.role == ArticleRole::Editor)
+                                        .map(|x| {
+                                            let co_author = x.username.clone();
+                                            view! {
+                                                <li class="flex gap-2 items-center">
+                                                    <A href=format!(
+                                                        "/profile/{}",
+                                                        x.username,
+                                                    )>{x.username.clone()}</A>
+                                                    <Show when=move || !x.accepted>
+                                                        <span class="text-sm text-gray-500">
+                                                            "(invitation pending)"
+                                                        </span>
+                                                    </Show>
+                                                    <button
+                                                        type="button"
+                                                        class="text-red-600 hover:underline"
+                                                        disabled=move || remove.pending().get()
+                                                        on:click=move |_| {
+                                                            remove
+                                                                .dispatch(RemoveCoAuthorAction {
+                                                                    slug: slug(),
+                                                                    username: co_author.clone(),
+                                                                });
+                                                        }
+                                                    >
+                                                        "Remove"
+                                                    </button>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                                <ActionForm action=invite>
+                                    <input type="hidden" name="slug" value=slug />
+                                    <div class="flex gap-2">
+                                        <input
+                                            name="username"
+                                            class="input-field-common"
+                                            type="text"
+                                            placeholder="Invite a co-author by username"
+                                            required=true
+                                        />
+                                        <button class="bg-blue-700 hover:bg-blue-800 text-white font-bold px-3 rounded-lg">
+                                            "Invite"
+                                        </button>
+                                    </div>
+                                </ActionForm>
+                                {move || {
+                                    invite
+                                        .value()
+                                        .get()
+                                        .and_then(Result::err)
+                                        .map(|x| view! { <p class="text-red-500">{x.to_string()}</p> })
+                                }}
+                            </div>
+                        }
+                            .into_any()
+                    }
+                })
+            }}
+        </Transition>
+    }
+}
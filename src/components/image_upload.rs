@@ -0,0 +1,48 @@
+use crate::uploads::{upload_input, UploadedFile, ALLOWED_MIME_TYPES};
+use leptos::{html::Input, prelude::*};
+
+/// A file picker which uploads the chosen image right away and hands over its urls.
+#[component]
+pub fn ImageUpload<F>(label: &'static str, on_upload: F) -> impl IntoView
+where
+    F: Fn(UploadedFile) + Copy + 'static,
+{
+    let input: NodeRef<Input> = NodeRef::new();
+    let status = RwSignal::new(String::new());
+
+    let on_change = move |_| {
+        let Some(input) = input.get() else {
+            return;
+        };
+        status.set("Uploading...".into());
+        leptos::task::spawn_local(async move {
+            let result = upload_input(input.clone()).await;
+            // Choosing the same file again has to trigger a new upload
+            input.set_value("");
+            match result {
+                Ok(x) => {
+                    status.set(String::new());
+                    on_upload(x);
+                }
+                Err(x) => status.set(x),
+            }
+        });
+    };
+
+    view! {
+        <div class="flex items-center gap-2">
+            <label class="text-gray-600 hover:text-blue-600 cursor-pointer">
+                <i class="fa-solid fa-upload w-4 h-4"></i>
+                {label}
+                <input
+                    node_ref=input
+                    type="file"
+                    class="hidden"
+                    accept=ALLOWED_MIME_TYPES.join(",")
+                    on:change=on_change
+                />
+            </label>
+            <span class="text-red-500">{move || status.get()}</span>
+        </div>
+    }
+}
@@ -1,7 +1,14 @@
+pub mod article_body;
 pub mod article_preview;
 pub mod article_view;
 pub mod buttons;
+pub mod co_authors;
+pub mod image_upload;
 pub mod items_per_page;
 pub mod navitems;
+pub mod password_strength;
 pub mod prev_next_buttons;
+pub mod seo;
+pub mod series;
+pub mod tag_input;
 pub mod user_icons;
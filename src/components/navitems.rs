@@ -26,6 +26,12 @@ pub(crate) fn NavItems(
                         <span class="text-xs md:text-base mt-1 font-semibold">Home</span>
                     </div>
                 </A>
+                <A href="/tags">
+                    <div class="group navitem">
+                        <i class="fa-solid fa-tags navitem-icon"></i>
+                        <span class="text-xs md:text-base mt-1 font-semibold">Tags</span>
+                    </div>
+                </A>
                 <Show
                     when=move || username.with(Option::is_none)
                     fallback=move || {
@@ -47,6 +53,7 @@ pub(crate) fn NavItems(
                                     </span>
                                 </div>
                             </A>
+                            <NotificationBell username />
                             <A href=profile_href.clone()>
                                 <div class="group navitem">
                                     <i class="fa-regular fa-circle-user navitem-icon"></i>
@@ -94,3 +101,71 @@ pub(crate) fn NavItems(
         </div>
     }
 }
+
+#[component]
+fn NotificationBell(username: UsernameSignal) -> impl IntoView {
+    let notifications = RwSignal::new(Vec::<(String, String)>::new());
+    let show_list = RwSignal::new(false);
+
+    Effect::new(move || {
+        if username.with(Option::is_some) {
+            crate::live::use_live_events("/live/user".to_string(), move |event| {
+                if let crate::live::LiveEvent::Notification { message, link } = event {
+                    notifications.update(|n| n.push((message, link)));
+                }
+            });
+        }
+    });
+
+    view! {
+        <div class="relative">
+            <button
+                class="focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2"
+                on:click=move |_| show_list.update(|s| *s = !*s)
+            >
+                <div class="group navitem">
+                    <i class="fa-solid fa-bell navitem-icon"></i>
+                    <span class="text-xs md:text-base mt-1 font-semibold">
+                        Notifications
+                    </span>
+                </div>
+                <Show when=move || notifications.with(|n| !n.is_empty())>
+                    <span class="absolute top-0 right-0 rounded-full bg-red-500 px-2 text-xs">
+                        {move || notifications.with(Vec::len)}
+                    </span>
+                </Show>
+            </button>
+            <Show when=move || show_list.get()>
+                <div class="absolute right-0 z-20 w-64 rounded-lg bg-white text-gray-700 shadow-lg">
+                    <Show
+                        when=move || notifications.with(|n| !n.is_empty())
+                        fallback=|| view! { <p class="px-3 py-2">"No new notifications"</p> }
+                    >
+                        <For
+                            each=move || notifications.get().into_iter().enumerate()
+                            key=|(i, _)| *i
+                            children=move |(_, (message, link)): (usize, (String, String))| {
+                                view! {
+                                    <div class="px-3 py-2 border-b hover:bg-gray-100">
+                                        <A href=link on:click=move |_| show_list.set(false)>
+                                            {message}
+                                        </A>
+                                    </div>
+                                }
+                            }
+                        />
+                        <button
+                            class="w-full px-3 py-2 text-blue-500 hover:underline"
+                            on:click=move |_| {
+                                notifications.set(Vec::new());
+                                show_list.set(false);
+                            }
+                        >
+                            "Clear all"
+                        </button>
+                    </Show>
+                </div>
+            </Show>
+        </div>
+    }
+}
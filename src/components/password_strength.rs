@@ -0,0 +1,92 @@
+use crate::password::{
+    breached_range, password_policy, sha1_hex, PasswordFeedback, PasswordPolicy, PREFIX_LENGTH,
+};
+use leptos::prelude::*;
+
+const LABELS: [&str; 5] = ["Very weak", "Weak", "Fair", "Strong", "Very strong"];
+
+/// The live feedback on a new password: a strength meter and what the server would refuse.
+/// Only the first digits of its hash leave the browser, to look for it in the breached ones.
+#[component]
+pub fn PasswordStrength(
+    #[prop(into)] password: Signal<String>,
+    /// The username and email, the password shouldn't be made of them
+    #[prop(into, optional)]
+    user_inputs: Signal<Vec<String>>,
+) -> impl IntoView {
+    let policy = LocalResource::new(password_policy);
+    let hash = Memo::new(move |_| password.with(|x| (!x.is_empty()).then(|| sha1_hex(x))));
+    let prefix =
+        Memo::new(move |_| hash.with(|x| x.as_ref().map(|x| x[..PREFIX_LENGTH].to_string())));
+    let range = LocalResource::new(move || {
+        let prefix = prefix.get();
+        async move {
+            match prefix {
+                Some(prefix) => breached_range(prefix).await.unwrap_or_default(),
+                None => Vec::new(),
+            }
+        }
+    });
+
+    let feedback = Memo::new(move |_| {
+        // The server has the same defaults until its policy arrives
+        let policy: PasswordPolicy = policy.get().and_then(Result::ok).unwrap_or_default();
+        let breached = policy.check_breached
+            && hash.with(|hash| {
+                let suffix = hash.as_ref().map(|x| &x[PREFIX_LENGTH..]);
+                range
+                    .get()
+                    .is_some_and(|range| range.iter().any(|x| Some(x.as_str()) == suffix))
+            });
+        let user_inputs = user_inputs.get();
+        let user_inputs: Vec<&str> = user_inputs.iter().map(String::as_str).collect();
+        password.with(|x| policy.check(x, &user_inputs, breached))
+    });
+    let score = move || feedback.with(|x: &PasswordFeedback| x.score);
+    let colour = move || match score() {
+        0 | 1 => "bg-red-500",
+        2 => "bg-yellow-500",
+        3 => "bg-blue-500",
+        _ => "bg-green-500",
+    };
+
+    view! {
+        <Show when=move || password.with(|x| !x.is_empty())>
+            <div class="mb-5">
+                <div class="flex gap-1 mb-1">
+                    {(0..4u8)
+                        .map(|i| {
+                            view! {
+                                <div class=move || {
+                                    format!(
+                                        "h-1 flex-1 rounded {}",
+                                        if score() > i { colour() } else { "bg-gray-200" },
+                                    )
+                                }></div>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+                <p class="text-xs text-gray-600">{move || LABELS[score() as usize]}</p>
+                <ul class="text-xs text-red-500">
+                    {move || {
+                        feedback
+                            .with(|x| x.problems.clone())
+                            .into_iter()
+                            .map(|x| view! { <li>{x}</li> })
+                            .collect_view()
+                    }}
+                </ul>
+                <ul class="text-xs text-gray-600">
+                    {move || {
+                        feedback
+                            .with(|x| x.suggestions.clone())
+                            .into_iter()
+                            .map(|x| view! { <li>{x}</li> })
+                            .collect_view()
+                    }}
+                </ul>
+            </div>
+        </Show>
+    }
+}
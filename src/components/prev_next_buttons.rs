@@ -1,20 +1,55 @@
 use crate::app::{GlobalState, GlobalStateStoreFields};
-use crate::models::Article;
+use crate::models::{ArticleFeed, Pagination};
 use leptos::prelude::*;
 use leptos_router::hooks::{use_params_map, use_query, use_query_map};
 use reactive_stores::Store;
 
 #[component]
-pub fn PreviousNextButton(
-    articles: Resource<Result<Vec<Article>, ServerFnError>>,
-) -> impl IntoView {
+pub fn PreviousNextButton(articles: Resource<Result<ArticleFeed, ServerFnError>>) -> impl IntoView {
     let params = use_params_map();
     let route_user = move || params.with(|x| x.get("user").unwrap_or_default());
     let query = use_query_map();
     let favourite = move || query.with(|x| x.get("favourites").map(|_| true));
+    let bookmarks = move || query.with(|x| x.get("bookmarks").is_some());
 
     let global_state = expect_context::<Store<GlobalState>>();
     let pagination = use_query::<crate::models::Pagination>();
+    let cursors = move || {
+        articles.with(|x| {
+            x.as_ref()
+                .and_then(|x| x.as_ref().ok())
+                .map(|x| (x.prev.clone(), x.next.clone()))
+                .unwrap_or_default()
+        })
+    };
+    let total = move || {
+        articles.with(|x| {
+            x.as_ref()
+                .and_then(|x| x.as_ref().ok())
+                .map(|x| x.total)
+                .unwrap_or_default()
+        })
+    };
+    let go_to = move |pagination: Pagination| {
+        let url = format!(
+            "{}{}{}{}",
+            if global_state.is_profile().get() {
+                format!("/profile/{}", route_user())
+            } else {
+                "".to_string()
+            },
+            pagination,
+            if favourite().unwrap_or_default() {
+                "&favourites=true"
+            } else {
+                ""
+            },
+            if bookmarks() { "&bookmarks=true" } else { "" },
+        );
+        let navigate = leptos_router::hooks::use_navigate();
+        global_state.back_url().set(url.clone());
+        navigate(&url, Default::default())
+    };
 
     view! {
         <Show
@@ -22,7 +57,7 @@ pub fn PreviousNextButton(
                 pagination
                     .with(|x| {
                         x.as_ref().map(crate::models::Pagination::get_page).unwrap_or_default()
-                    }) > 0
+                    }) > 0 || cursors().0.is_some()
             }
             fallback=|| ()
         >
@@ -30,19 +65,7 @@ pub fn PreviousNextButton(
                 type="button"
                 class="px-4 cursor-pointer hover:text-blue-500 border rounded-full bg-gray-100"
                 on:click=move |_| {
-                    let prev_page = format!(
-                        "{}{}{}",
-                        if global_state.is_profile().get() {
-                            format!("/profile/{}", route_user())
-                        } else {
-                            "".to_string()
-                        },
-                        pagination.get().unwrap_or_default().previous_page().to_string(),
-                        if favourite().unwrap_or_default() { "&favourites=true" } else { "" },
-                    );
-                    let navigate = leptos_router::hooks::use_navigate();
-                    global_state.back_url().set(prev_page.clone());
-                    navigate(&prev_page, Default::default())
+                    go_to(pagination.get().unwrap_or_default().previous_page(cursors().0))
                 }
             >
                 "<< Previous page      "
@@ -51,18 +74,13 @@ pub fn PreviousNextButton(
         <Suspense fallback=move || view! { <p>"Loading..."</p> }>
             <Show
                 when=move || {
-                    let n_articles = articles
-                        .with(|x| {
-                            x.as_ref().map_or(0, |y| y.as_ref().map(Vec::len).unwrap_or_default())
-                        });
-                    n_articles > 0
-                        && n_articles
-                            >= pagination
-                                .with(|x| {
-                                    x.as_ref()
-                                        .map(crate::models::Pagination::get_amount)
-                                        .unwrap_or_default()
-                                }) as usize
+                    if !bookmarks() {
+                        return cursors().1.is_some();
+                    }
+                    // The reading list has no cursors, the total tells if there is more
+                    let pagination = pagination.get().unwrap_or_default();
+                    i64::from(pagination.get_page() + 1) * i64::from(pagination.get_amount())
+                        < total()
                 }
                 fallback=|| ()
             >
@@ -70,24 +88,113 @@ pub fn PreviousNextButton(
                     type="button"
                     class="px-4 cursor-pointer hover:text-blue-500 border rounded-full bg-gray-100"
                     on:click=move |_| {
-                        let next_page = format!(
-                            "{}{}{}",
-                            if global_state.is_profile().get() {
-                                format!("/profile/{}", route_user())
-                            } else {
-                                "".to_string()
-                            },
-                            pagination.get().unwrap_or_default().next_page().to_string(),
-                            if favourite().unwrap_or_default() { "&favourites=true" } else { "" },
-                        );
-                        let navigate = leptos_router::hooks::use_navigate();
-                        global_state.back_url().set(next_page.clone());
-                        navigate(&next_page, Default::default())
+                        go_to(pagination.get().unwrap_or_default().next_page(cursors().1))
                     }
                 >
                     "Next page >>"
                 </button>
             </Show>
+            {move || {
+                let pagination = pagination.get().unwrap_or_default();
+                view! {
+                    <PageNavigator
+                        total=total()
+                        page=i64::from(pagination.get_page())
+                        amount=i64::from(pagination.get_amount())
+                        on_page=move |page| {
+                            go_to(
+                                pagination
+                                    .clone()
+                                    .set_page(u32::try_from(page).unwrap_or_default()),
+                            )
+                        }
+                    />
+                }
+            }}
         </Suspense>
     }
 }
+
+/// The numbered pages of a listing, with the first and the last one and a field to jump to any.
+/// `page` counts from 0, the pages are shown counting from 1.
+#[component]
+pub fn PageNavigator<F>(total: i64, page: i64, amount: i64, on_page: F) -> impl IntoView
+where
+    F: Fn(i64) + Clone + Send + Sync + 'static,
+{
+    // How many pages are listed on each side of the current one
+    const AROUND: i64 = 2;
+    let last = page_count(total, amount) - 1;
+    let jump_to = RwSignal::new(String::new());
+
+    let button = {
+        let on_page = on_page.clone();
+        move |target: i64, label: String| {
+            let on_page = on_page.clone();
+            let current = target == page;
+            view! {
+                <button
+                    type="button"
+                    class=if current {
+                        "px-3 border rounded-full bg-blue-500 text-white"
+                    } else {
+                        "px-3 cursor-pointer hover:text-blue-500 border rounded-full bg-gray-100"
+                    }
+                    disabled=current
+                    on:click=move |_| on_page(target)
+                >
+                    {label}
+                </button>
+            }
+        }
+    };
+
+    (last > 0)
+        .then(|| {
+            let first_shown = (page - AROUND).max(0);
+            let last_shown = (page + AROUND).min(last);
+            view! {
+                <nav class="flex flex-wrap items-center gap-1" aria-label="Pages">
+                    {(first_shown > 0).then(|| button(0, "First".to_string()))}
+                    {(first_shown..=last_shown)
+                        .map(|x| button(x, (x + 1).to_string()))
+                        .collect_view()}
+                    {(last_shown < last).then(|| button(last, format!("Last ({})", last + 1)))}
+                    <form
+                        class="flex gap-1 px-2"
+                        on:submit=move |ev| {
+                            ev.prevent_default();
+                            if let Ok(target) = jump_to.get_untracked().trim().parse::<i64>() {
+                                on_page((target - 1).clamp(0, last));
+                            }
+                        }
+                    >
+                        <input
+                            type="number"
+                            min=1
+                            max=last + 1
+                            placeholder="Page"
+                            class="w-16 px-1 border rounded"
+                            bind:value=jump_to
+                        />
+                        <button
+                            type="submit"
+                            class="px-3 cursor-pointer hover:text-blue-500 border rounded-full bg-gray-100"
+                        >
+                            "Go"
+                        </button>
+                    </form>
+                    <span class="text-gray-500">{format!("Page {} of {}", page + 1, last + 1)}</span>
+                </nav>
+            }
+        })
+}
+
+/// At least one page, even when there is nothing to list.
+#[inline]
+pub fn page_count(total: i64, amount: i64) -> i64 {
+    if amount <= 0 {
+        return 1;
+    }
+    ((total + amount - 1) / amount).max(1)
+}
@@ -0,0 +1,129 @@
+use crate::models::Article;
+use leptos::prelude::*;
+use leptos_meta::{Link, Meta, Title};
+
+pub const SITE_NAME: &str = "Realworld Leptos";
+pub const SITE_DESCRIPTION: &str =
+    "Articles, tags and discussions from the community of the Realworld Leptos demo";
+
+/// The scheme and host the site is reached at, the crawlers need absolute urls.
+pub fn site_url() -> String {
+    #[cfg(feature = "ssr")]
+    {
+        crate::config::use_config().base_url.clone()
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        window().location().origin().unwrap_or_default()
+    }
+}
+
+/// Title, description, canonical url and the Open Graph and Twitter cards of a page.
+#[component]
+pub fn PageMeta(
+    #[prop(into)] title: Signal<String>,
+    #[prop(into)] description: Signal<String>,
+    /// The canonical path of the page, without the host
+    #[prop(into)]
+    path: Signal<String>,
+    /// `website` or `article` or `profile`
+    #[prop(default = "website")]
+    kind: &'static str,
+    /// A picture shown large in the cards
+    #[prop(default = None)]
+    image: Option<String>,
+    /// `noindex, nofollow` for the pages which are private to a user, or only forms
+    #[prop(default = "index, follow")]
+    robots: &'static str,
+) -> impl IntoView {
+    let base_url = site_url();
+    let image = image.map(|x| absolute_url(&base_url, x));
+    let url = Signal::derive(move || format!("{base_url}{}", path.get()));
+
+    view! {
+        <Title text=title />
+        <Meta name="description" content=description />
+        <Meta name="robots" content=robots />
+        {move || view! { <Link rel="canonical" href=url.get() /> }}
+        <Meta property="og:site_name" content=SITE_NAME />
+        <Meta property="og:type" content=kind />
+        <Meta property="og:title" content=title />
+        <Meta property="og:description" content=description />
+        <Meta property="og:url" content=url />
+        <Meta
+            name="twitter:card"
+            content=if image.is_some() { "summary_large_image" } else { "summary" }
+        />
+        <Meta name="twitter:title" content=title />
+        <Meta name="twitter:description" content=description />
+        {image
+            .map(|image| {
+                view! {
+                    <Meta property="og:image" content=image.clone() />
+                    <Meta name="twitter:image" content=image />
+                }
+            })}
+    }
+}
+
+fn absolute_url(base_url: &str, url: String) -> String {
+    if url.starts_with('/') {
+        format!("{base_url}{url}")
+    } else {
+        url
+    }
+}
+
+/// The metadata of an article: its authors, tags and the first image of its body.
+#[component]
+pub fn ArticleSeo(article: Article) -> impl IntoView {
+    use super::article_body::{body_blocks, BodyBlock};
+
+    let image = article.body.as_deref().and_then(|x| {
+        body_blocks(x).into_iter().find_map(|x| match x {
+            BodyBlock::Image { src, .. } => Some(src),
+            BodyBlock::Text(_) => None,
+        })
+    });
+    // Without a picture in the article, the small card shows the author
+    let avatar = image
+        .is_none()
+        .then(|| absolute_url(&site_url(), article.author.avatar()));
+    let authors = std::iter::once(article.author.username.clone())
+        .chain(article.co_authors.iter().cloned())
+        .collect::<Vec<_>>();
+    let tags = article
+        .tag_list
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let site_url = site_url();
+
+    view! {
+        <PageMeta
+            title=article.title.clone()
+            description=article.description.clone()
+            path=format!("/article/{}", article.slug)
+            kind="article"
+            image
+        />
+        {avatar.map(|x| view! { <Meta property="og:image" content=x /> })}
+        <Meta name="author" content=authors.join(", ") />
+        <Meta name="keywords" content=tags.join(", ") />
+        {authors
+            .iter()
+            .map(|x| {
+                let profile = format!("{site_url}/profile/{}", crate::urls::encode_path_segment(x));
+                view! { <Meta property="article:author" content=profile /> }
+            })
+            .collect_view()}
+        {tags
+            .iter()
+            .map(|x| view! { <Meta property="article:tag" content=x.clone() /> })
+            .collect_view()}
+        <Meta name="twitter:label1" content="Written by" />
+        <Meta name="twitter:data1" content=authors.join(", ") />
+        <Meta name="twitter:label2" content="Tags" />
+        <Meta name="twitter:data2" content=tags.join(", ") />
+    }
+}
@@ -0,0 +1,104 @@
+use super::article_preview::ArticleSignal;
+use crate::models::SeriesEntry;
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+#[server(SeriesEntriesAction, "/api", "GetJson")]
+#[tracing::instrument]
+pub async fn series_entries(id: i64) -> Result<Vec<SeriesEntry>, ServerFnError> {
+    crate::models::Series::entries(id).await.map_err(|x| {
+        tracing::error!("problem while fetching the series: {x:?}");
+        ServerFnError::new("Problem while fetching the series")
+    })
+}
+
+/// Previous and next parts of the series of the article, with its table of contents.
+#[component]
+pub fn SeriesNavigator(article: ArticleSignal) -> impl IntoView {
+    let Some(series) = article.with_untracked(|x| x.series.clone()) else {
+        return ().into_any();
+    };
+    let current = series.position;
+    let entries = Resource::new(move || series.id, series_entries);
+    let show_contents = RwSignal::new(false);
+
+    let part_link = move |entry: Option<SeriesEntry>, label: &'static str| {
+        entry.map(|x| {
+            view! {
+                <A href=format!("/article/{}", x.slug)>
+                    <span class="text-blue-600 hover:underline">{label}{x.title}</span>
+                </A>
+            }
+        })
+    };
+
+    view! {
+        <div class="mb-5 p-3 rounded-lg bg-gray-100">
+            <Transition fallback=move || view! { <p>"Loading the series"</p> }>
+                {move || {
+                    entries
+                        .get()
+                        .and_then(Result::ok)
+                        .map(|entries| {
+                            let previous = entries
+                                .iter()
+                                .find(|x| x.position == current - 1)
+                                .cloned();
+                            let next = entries.iter().find(|x| x.position == current + 1).cloned();
+                            let total = entries.len();
+                            view! {
+                                <div class="flex justify-between items-center gap-2">
+                                    <button
+                                        type="button"
+                                        class="font-bold hover:text-blue-600"
+                                        on:click=move |_| show_contents.update(|x| *x = !*x)
+                                    >
+                                        <i class="fa-solid fa-layer-group w-4 h-4"></i>
+                                        {format!(" {} (part {current} of {total})", series.title)}
+                                    </button>
+                                    <div class="flex gap-4">
+                                        {part_link(previous, "<< ")} {part_link(next, ">> ")}
+                                    </div>
+                                </div>
+                                <Show when=move || show_contents.get()>
+                                    <ol class="list-decimal ml-6 mt-2">
+                                        {entries
+                                            .clone()
+                                            .into_iter()
+                                            .map(|x| {
+                                                let is_current = x.position == current;
+                                                view! {
+                                                    <li class:font-bold=is_current>
+                                                        <A href=format!("/article/{}", x.slug)>{x.title}</A>
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ol>
+                                </Show>
+                            }
+                        })
+                }}
+            </Transition>
+        </div>
+    }
+    .into_any()
+}
+
+#[component]
+pub fn SeriesBadge(article: ArticleSignal) -> impl IntoView {
+    view! {
+        {move || {
+            article
+                .with(|x| x.series.clone())
+                .map(|series| {
+                    view! {
+                        <span class="bg-indigo-100 text-indigo-700 px-2 py-1 rounded text-xs">
+                            <i class="fa-solid fa-layer-group w-4 h-4"></i>
+                            {format!(" {} · part {}", series.title, series.position)}
+                        </span>
+                    }
+                })
+        }}
+    }
+}
@@ -0,0 +1,69 @@
+use crate::models::Tag;
+use leptos::{html::Input, prelude::*};
+
+/// The tags of an article as chips. Enter or a comma turns what was typed into a tag, spaces
+/// included, and Backspace in the empty field removes the last one.
+#[component]
+pub fn TagInput(tags: RwSignal<Vec<Tag>>) -> impl IntoView {
+    let input: NodeRef<Input> = NodeRef::new();
+
+    let add_typed = move || {
+        let Some(input) = input.get() else {
+            return;
+        };
+        for tag in input.value().split(',').filter_map(Tag::parse) {
+            tags.update(|x| {
+                if !x.contains(&tag) {
+                    x.push(tag);
+                }
+            });
+        }
+        input.set_value("");
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| match ev.key().as_str() {
+        "Enter" | "," => {
+            ev.prevent_default();
+            add_typed();
+        }
+        "Backspace" if input.get().is_some_and(|x| x.value().is_empty()) => {
+            tags.update(|x| {
+                x.pop();
+            });
+        }
+        _ => {}
+    };
+
+    view! {
+        <div class="input-field-common flex flex-wrap items-center gap-1">
+            <For
+                each=move || tags.get()
+                key=Clone::clone
+                children=move |tag: Tag| {
+                    let removed = tag.clone();
+                    view! {
+                        <span class="bg-gray-200 text-gray-700 px-2 py-1 rounded text-xs flex items-center gap-1">
+                            {tag.to_string()}
+                            <button
+                                type="button"
+                                class="hover:text-red-600"
+                                title="Remove the tag"
+                                on:click=move |_| tags.update(|x| x.retain(|t| *t != removed))
+                            >
+                                <i class="fa-solid fa-xmark"></i>
+                            </button>
+                        </span>
+                    }
+                }
+            />
+            <input
+                node_ref=input
+                type="text"
+                class="flex-1 min-w-40 outline-none"
+                placeholder="Add a tag, Enter or comma after each one"
+                on:keydown=on_keydown
+                on:blur=move |_| add_typed()
+            />
+        </div>
+    }
+}
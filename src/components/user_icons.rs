@@ -19,7 +19,7 @@ pub fn AuthorUserIcon(article_signal: ArticleSignal) -> impl IntoView {
                 <img
                     src=move || {
                         article_signal
-                            .with_untracked(|x| { x.author.image.clone().unwrap_or_default() })
+                            .with_untracked(|x| { x.author.avatar() })
                     }
                     class="w-10 h-10 rounded-full"
                 />
@@ -33,6 +33,27 @@ pub fn AuthorUserIcon(article_signal: ArticleSignal) -> impl IntoView {
                         }}
                     </span>
                 </A>
+                {move || {
+                    let co_authors = article_signal.with(|x| x.co_authors.clone());
+                    (!co_authors.is_empty())
+                        .then(|| {
+                            view! {
+                                <span class="px-1">" with "</span>
+                                {co_authors
+                                    .into_iter()
+                                    .enumerate()
+                                    .map(|(i, x)| {
+                                        view! {
+                                            {(i > 0).then_some(", ")}
+                                            <A href=format!("/profile/{x}")>
+                                                <span class="font-medium">{x.clone()}</span>
+                                            </A>
+                                        }
+                                    })
+                                    .collect_view()}
+                            }
+                        })
+                }}
             </div>
         </div>
     }
@@ -46,7 +67,9 @@ pub fn CommentUserIcon(comment: RwSignal<Comment>) -> impl IntoView {
             comment.with_untracked(|x| x.username.to_string())
         )
     };
-    let user_image = move || comment.with_untracked(|x| x.user_image.clone().unwrap_or_default());
+    let user_image = move || {
+        comment.with_untracked(|x| crate::avatars::avatar_url(&x.username, x.user_image.as_deref()))
+    };
 
     view! {
         <div class="flex gap-4 text-gray-600">
@@ -83,7 +106,7 @@ pub fn CurrentUserIcon(user_signal: RwSignal<Option<User>>) -> impl IntoView {
     let user_image = user_signal
         .get_untracked()
         .as_ref()
-        .map(|u| u.image().unwrap_or_default())
+        .map(|u| u.avatar())
         .unwrap_or_default();
 
     view! {
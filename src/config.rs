@@ -0,0 +1,394 @@
+use serde::Deserialize;
+use std::{fmt, path::PathBuf};
+
+static CONFIG: std::sync::OnceLock<AppConfig> = std::sync::OnceLock::new();
+
+/// The file read when `CONFIG_FILE` is not set, it may not exist.
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// The settings of the server, read from a TOML file, then overridden by the env vars.
+///
+/// Every section and field has a default, a file with only the secrets is enough.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    /// The scheme, host and optional path prefix the site is publicly reached at, every
+    /// absolute link (emails, feeds, sitemap, meta tags) starts with it.
+    /// `http://` and the address of the server when not set.
+    pub base_url: String,
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub mailer: Option<MailerConfig>,
+    pub uploads: UploadsConfig,
+    pub digest: DigestConfig,
+    pub backup: BackupConfig,
+    pub password: crate::password::PasswordPolicy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub url: String,
+    /// The connections for the reads, the writes go through a single one.
+    pub max_connections: u32,
+    /// `wal` lets the reads go on during a write.
+    pub journal_mode: String,
+    pub synchronous: String,
+    /// Milliseconds a connection waits for a lock before failing with `SQLITE_BUSY`.
+    pub busy_timeout: u64,
+    /// The `ON DELETE CASCADE` of the schema only run with it.
+    pub foreign_keys: bool,
+    pub create_if_missing: bool,
+    /// Any other pragma set on every connection, like `cache_size`.
+    pub pragmas: std::collections::BTreeMap<String, String>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub jwt_secret: String,
+    /// Seconds a login lasts.
+    pub session_lifetime: u64,
+    /// Seconds a password reset link can be used.
+    pub reset_token_lifetime: u64,
+    /// Seconds between two username changes of the same user.
+    pub username_change_cooldown: u64,
+    /// Usernames nobody can take, on top of the built-in ones.
+    pub reserved_usernames: Vec<String>,
+}
+
+impl AuthConfig {
+    pub fn is_reserved_username(&self, username: &str) -> bool {
+        self.reserved_usernames
+            .iter()
+            .any(|x| x.eq_ignore_ascii_case(username))
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MailerConfig {
+    pub email: String,
+    pub passwd: String,
+    pub smtp_server: String,
+    #[serde(default = "MailerConfig::default_port")]
+    pub smtp_port: u16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UploadsConfig {
+    pub dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DigestConfig {
+    /// Seconds between two looks for the due digests.
+    pub check_interval: u64,
+    /// Seconds the unsubscribe link of a digest keeps working.
+    pub unsubscribe_token_lifetime: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BackupConfig {
+    pub dir: PathBuf,
+    /// Seconds between two scheduled backups, 0 for none.
+    pub interval: u64,
+    /// How many of the latest backups are kept.
+    pub keep: usize,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            max_connections: 8,
+            journal_mode: "wal".into(),
+            synchronous: "normal".into(),
+            busy_timeout: 5_000,
+            foreign_keys: true,
+            create_if_missing: true,
+            pragmas: Default::default(),
+        }
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            jwt_secret: String::new(),
+            session_lifetime: 3_600_000,
+            reset_token_lifetime: 3_600,
+            username_change_cooldown: 30 * 24 * 3_600,
+            reserved_usernames: Vec::new(),
+        }
+    }
+}
+
+impl MailerConfig {
+    fn default_port() -> u16 {
+        587
+    }
+}
+
+// The secrets stay out of the logs
+impl fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthConfig")
+            .field("session_lifetime", &self.session_lifetime)
+            .field("reset_token_lifetime", &self.reset_token_lifetime)
+            .field("username_change_cooldown", &self.username_change_cooldown)
+            .field("reserved_usernames", &self.reserved_usernames)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for MailerConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MailerConfig")
+            .field("email", &self.email)
+            .field("smtp_server", &self.smtp_server)
+            .field("smtp_port", &self.smtp_port)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for UploadsConfig {
+    fn default() -> Self {
+        Self {
+            dir: "uploads".into(),
+        }
+    }
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: 60 * 60,
+            // Old digests keep working for a while after they were sent
+            unsubscribe_token_lifetime: 90 * 24 * 3_600,
+        }
+    }
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            dir: "backups".into(),
+            interval: 0,
+            keep: 7,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read(PathBuf, std::io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Env(&'static str, String),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(path, x) => write!(f, "could not read {}: {x}", path.display()),
+            Self::Parse(path, x) => write!(f, "could not parse {}: {x}", path.display()),
+            Self::Env(var, x) => write!(f, "invalid {var}: {x}"),
+            Self::Invalid(x) => write!(f, "invalid configuration: {x}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+fn env_var(name: &'static str) -> Option<String> {
+    std::env::var(name).ok().filter(|x| !x.is_empty())
+}
+
+fn parse_env<T: std::str::FromStr>(name: &'static str) -> Result<Option<T>, ConfigError>
+where
+    T::Err: fmt::Display,
+{
+    env_var(name)
+        .map(|x| {
+            x.parse()
+                .map_err(|e: T::Err| ConfigError::Env(name, e.to_string()))
+        })
+        .transpose()
+}
+
+impl AppConfig {
+    /// Reads `CONFIG_FILE`, or `config.toml` when it exists, applies the env vars over it and
+    /// checks the result. `site_addr` gives the base url when none is set.
+    pub fn load(site_addr: std::net::SocketAddr) -> Result<Self, ConfigError> {
+        let mut config = match env_var("CONFIG_FILE") {
+            Some(path) => Self::read(path.into())?,
+            None if std::path::Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                Self::read(DEFAULT_CONFIG_FILE.into())?
+            }
+            None => Self::default(),
+        };
+        config.apply_env()?;
+        if config.base_url.is_empty() {
+            config.base_url = format!("http://{site_addr}");
+            tracing::warn!("no base_url configured, the links use {}", config.base_url);
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn read(path: PathBuf) -> Result<Self, ConfigError> {
+        let text =
+            std::fs::read_to_string(&path).map_err(|x| ConfigError::Read(path.clone(), x))?;
+        toml::from_str(&text).map_err(|x| ConfigError::Parse(path, x))
+    }
+
+    /// The env vars win over the file, so the secrets can stay out of it.
+    fn apply_env(&mut self) -> Result<(), ConfigError> {
+        if let Some(x) = env_var("BASE_URL") {
+            self.base_url = x;
+        }
+        if let Some(x) = env_var("DATABASE_URL") {
+            self.database.url = x;
+        }
+        if let Some(x) = parse_env("DATABASE_MAX_CONNECTIONS")? {
+            self.database.max_connections = x;
+        }
+        if let Some(x) = env_var("JWT_SECRET") {
+            self.auth.jwt_secret = x;
+        }
+        if let Some(x) = env_var("UPLOAD_DIR") {
+            self.uploads.dir = x.into();
+        }
+
+        let email = env_var("MAILER_EMAIL");
+        let passwd = env_var("MAILER_PASSWD");
+        let smtp_server = env_var("MAILER_SMTP_SERVER");
+        let smtp_port = parse_env("MAILER_SMTP_PORT")?;
+        if let Some(mailer) = &mut self.mailer {
+            if let Some(x) = email {
+                mailer.email = x;
+            }
+            if let Some(x) = passwd {
+                mailer.passwd = x;
+            }
+            if let Some(x) = smtp_server {
+                mailer.smtp_server = x;
+            }
+            if let Some(x) = smtp_port {
+                mailer.smtp_port = x;
+            }
+        } else if email.is_some() || passwd.is_some() || smtp_server.is_some() {
+            let (Some(email), Some(passwd), Some(smtp_server)) = (email, passwd, smtp_server)
+            else {
+                return Err(ConfigError::Env(
+                    "MAILER_*",
+                    "MAILER_EMAIL, MAILER_PASSWD and MAILER_SMTP_SERVER go together".into(),
+                ));
+            };
+            self.mailer = Some(MailerConfig {
+                email,
+                passwd,
+                smtp_server,
+                smtp_port: smtp_port.unwrap_or(MailerConfig::default_port()),
+            });
+        }
+        Ok(())
+    }
+
+    fn validate(&mut self) -> Result<(), ConfigError> {
+        let invalid = |x: &str| Err(ConfigError::Invalid(x.to_string()));
+
+        // The links append their path to it
+        self.base_url = self.base_url.trim_end_matches('/').to_string();
+        if !self.base_url.starts_with("http://") && !self.base_url.starts_with("https://") {
+            return invalid("base_url has to start with http:// or https://");
+        }
+        if self.database.url.is_empty() {
+            return invalid("database.url (or DATABASE_URL) is required");
+        }
+        if self.database.max_connections == 0 {
+            return invalid("database.max_connections has to be at least 1");
+        }
+        if let Err(x) = crate::database::connect_options(&self.database) {
+            return invalid(&format!("database: {x}"));
+        }
+        if self.auth.jwt_secret.is_empty() {
+            return invalid("auth.jwt_secret (or JWT_SECRET) is required");
+        }
+        if self.auth.session_lifetime == 0 || self.auth.reset_token_lifetime == 0 {
+            return invalid("the token lifetimes have to be positive");
+        }
+        if let Some(mailer) = &self.mailer {
+            if mailer.email.is_empty() || mailer.passwd.is_empty() || mailer.smtp_server.is_empty()
+            {
+                return invalid("mailer needs email, passwd and smtp_server");
+            }
+        }
+        if self.uploads.dir.as_os_str().is_empty() {
+            return invalid("uploads.dir can't be empty");
+        }
+        if self.digest.check_interval == 0 || self.digest.unsubscribe_token_lifetime == 0 {
+            return invalid("the digest intervals have to be positive");
+        }
+        if self.backup.dir.as_os_str().is_empty() || self.backup.keep == 0 {
+            return invalid("backup needs a dir and to keep at least one backup");
+        }
+        let password = &self.password;
+        if password.min_length == 0 || password.max_length < password.min_length {
+            return invalid("password.max_length has to be at least password.min_length");
+        }
+        if password.min_classes > 4 || password.min_score > 4 {
+            return invalid("password.min_classes and password.min_score go up to 4");
+        }
+        if let Some(path) = &password.breached_list {
+            if !path.is_file() {
+                return invalid(&format!("password.breached_list {path:?} is not a file"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Loads the configuration into static, before anything reads it.
+pub fn init_config(site_addr: std::net::SocketAddr) -> Result<&'static AppConfig, ConfigError> {
+    let config = AppConfig::load(site_addr)?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// The configuration outside of the requests: the pools, the schedulers and the commands.
+pub fn get_config() -> &'static AppConfig {
+    CONFIG.get().expect("configuration loaded")
+}
+
+/// The configuration provided as a context to the server functions and the pages.
+pub fn use_config() -> &'static AppConfig {
+    leptos::prelude::expect_context::<&'static AppConfig>()
+}
+
+/// The state of the router, the handlers take the part they need with `State`.
+#[derive(Debug, Clone)]
+pub struct AppState {
+    pub leptos_options: leptos::config::LeptosOptions,
+    pub config: &'static AppConfig,
+}
+
+impl axum::extract::FromRef<AppState> for leptos::config::LeptosOptions {
+    fn from_ref(state: &AppState) -> Self {
+        state.leptos_options.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for &'static AppConfig {
+    fn from_ref(state: &AppState) -> Self {
+        state.config
+    }
+}
+
+/// Seconds from now as the `exp` of a token.
+pub(crate) fn expires_in(seconds: u64) -> usize {
+    (sqlx::types::chrono::Utc::now().timestamp() as usize) + seconds as usize
+}
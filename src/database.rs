@@ -1,25 +1,96 @@
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use std::str::FromStr;
+
 static DB: std::sync::OnceLock<sqlx::SqlitePool> = std::sync::OnceLock::new();
+static WRITER: std::sync::OnceLock<sqlx::SqlitePool> = std::sync::OnceLock::new();
+
+/// The migrations of this build, a restored snapshot can't be ahead of them.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// The migrations which were removed from `./migrations`. The databases which applied them keep
+/// their data, only the history is forgotten so the migrator doesn't miss them.
+/// `20250711062246` is the demo content, now loaded by `seed --demo`.
+pub const RETIRED_MIGRATIONS: [i64; 1] = [20250711062246];
+
+async fn forget_retired_migrations(pool: &sqlx::SqlitePool) -> Result<(), sqlx::Error> {
+    let history = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'",
+    )
+    .fetch_one(pool)
+    .await?;
+    if history == 0 {
+        return Ok(());
+    }
+    for version in RETIRED_MIGRATIONS {
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = $1")
+            .bind(version)
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+/// The options of every connection, from the `[database]` section of the configuration.
+pub fn connect_options(
+    config: &crate::config::DatabaseConfig,
+) -> Result<SqliteConnectOptions, sqlx::Error> {
+    let mut options = SqliteConnectOptions::from_str(&config.url)?
+        .journal_mode(config.journal_mode.parse()?)
+        .synchronous(config.synchronous.parse()?)
+        .busy_timeout(std::time::Duration::from_millis(config.busy_timeout))
+        .foreign_keys(config.foreign_keys)
+        .create_if_missing(config.create_if_missing)
+        .optimize_on_close(true, None);
+    for (key, value) in &config.pragmas {
+        options = options.pragma(key.clone(), value.clone());
+    }
+    Ok(options)
+}
 
-async fn create_pool() -> sqlx::SqlitePool {
-    let database_url = std::env::var("DATABASE_URL").expect("no database url found in ENV vars");
-    let pool = sqlx::sqlite::SqlitePoolOptions::new()
-        .max_connections(8)
-        .connect(database_url.as_str())
+/// The readers and the writer share the options, the writer is a pool of a single connection
+/// so the writes wait their turn in the pool instead of failing with `SQLITE_BUSY`.
+async fn create_pools() -> (sqlx::SqlitePool, sqlx::SqlitePool) {
+    let config = &crate::config::get_config().database;
+    let options = connect_options(config).expect("invalid database options");
+    // The writer first, it creates the file and switches it to the journal mode
+    let writer = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options.clone())
         .await
         .expect("could not connect to database");
 
-    sqlx::migrate!("./migrations")
-        .run(&pool)
+    forget_retired_migrations(&writer)
         .await
-        .expect("migrations failed");
+        .expect("could not update the migration history");
+    MIGRATOR.run(&writer).await.expect("migrations failed");
 
-    pool
+    let readers = SqlitePoolOptions::new()
+        .max_connections(config.max_connections)
+        .connect_with(options)
+        .await
+        .expect("could not connect to database");
+
+    (readers, writer)
 }
 
 pub async fn init_db() -> Result<(), ()> {
-    DB.set(create_pool().await).map_err(|_| ())
+    let (readers, writer) = create_pools().await;
+    WRITER.set(writer).map_err(|_| ())?;
+    DB.set(readers).map_err(|_| ())
 }
 
+/// The pool for the reads.
 pub fn get_db() -> &'static sqlx::SqlitePool {
     DB.get().expect("database initialized")
 }
+
+/// The single connection every write goes through.
+pub fn get_writer() -> &'static sqlx::SqlitePool {
+    WRITER.get().expect("database initialized")
+}
+
+/// A write transaction on the writer. It takes the write lock right away (`BEGIN IMMEDIATE`),
+/// so a transaction that reads before writing can't fail when it upgrades its lock.
+pub async fn begin_write() -> Result<sqlx::Transaction<'static, sqlx::Sqlite>, sqlx::Error> {
+    get_writer().begin_with("BEGIN IMMEDIATE").await
+}
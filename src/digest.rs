@@ -0,0 +1,217 @@
+use crate::feeds::escape;
+use crate::models::{DigestArticle, DigestFrequency, DigestSubscription};
+use axum::{
+    extract::Query,
+    http::StatusCode,
+    response::{Html, IntoResponse, Response},
+};
+use mail_send::mail_builder::{headers::raw::Raw, MessageBuilder};
+
+static UNSUBSCRIBE_SCOPE: &str = "digest-unsubscribe";
+
+/// Sends the due digests every `digest.check_interval`, forever. The links in the emails start
+/// with the configured `base_url`.
+pub async fn run_scheduler() {
+    let config = crate::config::get_config();
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(config.digest.check_interval));
+    loop {
+        interval.tick().await;
+        if let Err(x) = send_due_digests(&config.base_url).await {
+            tracing::error!("problem while sending the digests: {x:?}");
+        }
+    }
+}
+
+#[tracing::instrument]
+async fn send_due_digests(base_url: &str) -> Result<(), sqlx::Error> {
+    for subscription in DigestSubscription::due().await? {
+        let sent_at = sqlx::types::chrono::Utc::now().naive_utc();
+        let articles = match subscription.articles().await {
+            Ok(articles) => articles,
+            Err(x) => {
+                tracing::error!(
+                    "problem while reading the digest of {}: {x:?}",
+                    subscription.username
+                );
+                continue;
+            }
+        };
+        if !articles.is_empty() {
+            let Some(message) = build_message(base_url, &subscription, &articles) else {
+                continue;
+            };
+            if let Err(x) = crate::mailer::send(message).await {
+                // last_sent_at stays untouched, so it is retried on the next run
+                tracing::error!(
+                    "problem while sending the digest to {}: {x:?}",
+                    subscription.username
+                );
+                continue;
+            }
+        }
+        if let Err(x) = subscription.mark_sent(sent_at).await {
+            tracing::error!(
+                "problem while marking the digest of {} as sent: {x:?}",
+                subscription.username
+            );
+        }
+    }
+    Ok(())
+}
+
+fn unsubscribe_url(base_url: &str, username: &str) -> Option<String> {
+    let token = crate::auth::encode_scoped_token(
+        UNSUBSCRIBE_SCOPE,
+        crate::auth::TokenClaims {
+            sub: username.to_string(),
+            exp: crate::config::expires_in(
+                crate::config::get_config()
+                    .digest
+                    .unsubscribe_token_lifetime,
+            ),
+        },
+    )
+    .map_err(|x| tracing::error!("problem while signing the unsubscribe token: {x:?}"))
+    .ok()?;
+    Some(format!("{base_url}/digest/unsubscribe?token={token}"))
+}
+
+fn build_message<'x>(
+    base_url: &str,
+    subscription: &'x DigestSubscription,
+    articles: &[DigestArticle],
+) -> Option<MessageBuilder<'x>> {
+    let creds = crate::mailer::credentials()?;
+    let unsubscribe = unsubscribe_url(base_url, &subscription.username)?;
+
+    Some(
+        MessageBuilder::new()
+            .from(("Realworld Leptos", creds.email.as_str()))
+            .to(vec![(
+                subscription.username.as_str(),
+                subscription.email.as_str(),
+            )])
+            .subject("New articles from the authors and tags you follow")
+            .header("List-Unsubscribe", Raw::new(format!("<{unsubscribe}>")))
+            .header(
+                "List-Unsubscribe-Post",
+                Raw::new("List-Unsubscribe=One-Click"),
+            )
+            .text_body(render_text(
+                base_url,
+                &subscription.username,
+                articles,
+                &unsubscribe,
+            ))
+            .html_body(render_html(
+                base_url,
+                &subscription.username,
+                articles,
+                &unsubscribe,
+            )),
+    )
+}
+
+fn render_text(
+    base_url: &str,
+    username: &str,
+    articles: &[DigestArticle],
+    unsubscribe: &str,
+) -> String {
+    let mut text =
+        format!("Hi {username},\n\nHere is what is new from the authors and tags you follow:\n\n");
+    for article in articles {
+        text.push_str(&format!(
+            "* {} by {}\n  {}\n  {base_url}/article/{}\n\n",
+            article.title, article.author, article.description, article.slug
+        ));
+    }
+    text.push_str(&format!(
+        "You can stop receiving these emails here: {unsubscribe}\n"
+    ));
+    text
+}
+
+fn render_html(
+    base_url: &str,
+    username: &str,
+    articles: &[DigestArticle],
+    unsubscribe: &str,
+) -> String {
+    let mut html = format!(
+        "<p>Hi {},</p><p>Here is what is new from the authors and tags you follow:</p><ul>",
+        escape(username)
+    );
+    for article in articles {
+        html.push_str(&format!(
+            "<li><p><a href=\"{base_url}/article/{}\"><strong>{}</strong></a> by {}</p><p>{}</p></li>",
+            escape(&article.slug),
+            escape(&article.title),
+            escape(&article.author),
+            escape(&article.description),
+        ));
+    }
+    html.push_str(&format!(
+        "</ul><p><small><a href=\"{}\">Unsubscribe from this digest</a></small></p>",
+        escape(unsubscribe)
+    ));
+    html
+}
+
+#[derive(serde::Deserialize)]
+pub struct UnsubscribeQuery {
+    token: String,
+}
+
+fn invalid_unsubscribe_token() -> Response {
+    tracing::info!("Invalid unsubscribe token provided");
+    (
+        StatusCode::BAD_REQUEST,
+        Html("<p>This unsubscribe link is invalid or has expired.</p>"),
+    )
+        .into_response()
+}
+
+/// The page of the email link. It only asks for a confirmation, the link scanners and the
+/// previews of the mail clients open the links without anyone clicking.
+pub async fn unsubscribe_page(Query(query): Query<UnsubscribeQuery>) -> Response {
+    if crate::auth::decode_scoped_token(UNSUBSCRIBE_SCOPE, &query.token).is_err() {
+        return invalid_unsubscribe_token();
+    }
+    Html(format!(
+        "<form method=\"post\" action=\"?token={}\">\
+<p>Stop receiving the email digest?</p>\
+<input type=\"hidden\" name=\"List-Unsubscribe\" value=\"One-Click\">\
+<button type=\"submit\">Unsubscribe</button></form>",
+        escape(&query.token)
+    ))
+    .into_response()
+}
+
+/// Unsubscribes, by POST only: from the confirmation page or from the mail client through the
+/// List-Unsubscribe-Post header (RFC 8058 one-click).
+pub async fn unsubscribe(Query(query): Query<UnsubscribeQuery>) -> Response {
+    let Ok(claims) = crate::auth::decode_scoped_token(UNSUBSCRIBE_SCOPE, &query.token) else {
+        return invalid_unsubscribe_token();
+    };
+    // The token carries the username of when the email was sent
+    let username = match crate::models::User::renamed_to(claims.claims.sub.clone()).await {
+        Ok(Some(current)) => current,
+        _ => claims.claims.sub,
+    };
+    match DigestSubscription::set_frequency(username, DigestFrequency::Never).await {
+        Ok(_) => Html(
+            "<p>You will not receive the email digest anymore. You can subscribe again from the <a href=\"/settings\">settings</a>.</p>",
+        )
+        .into_response(),
+        Err(x) => {
+            tracing::error!("problem while unsubscribing: {x:?}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Html("<p>Something went wrong, please try again later.</p>"),
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::components::article_body::{body_blocks, BodyBlock};
+use crate::config::AppConfig;
+use crate::models::{Article, ArticleContent, ArticleFeed, ArticleQuery, PageStart};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use sqlx::types::chrono::{NaiveDateTime, TimeZone, Utc};
+use std::collections::HashMap;
+
+const FEED_SIZE: i64 = 20;
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct FeedQuery {
+    /// `atom`, anything else is RSS 2.0
+    format: Option<String>,
+}
+
+/// The latest articles of everyone.
+#[tracing::instrument]
+pub async fn global_feed(
+    State(config): State<&'static AppConfig>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let articles = ArticleQuery::new()
+        .fetch_page(PageStart::Offset(0), FEED_SIZE)
+        .await;
+    respond(
+        &config.base_url,
+        &query,
+        &headers,
+        "Latest articles",
+        "/",
+        articles,
+    )
+    .await
+}
+
+/// The latest articles with `tag`, aliases of the tag lead to the same feed.
+#[tracing::instrument]
+pub async fn tag_feed(
+    State(config): State<&'static AppConfig>,
+    Path(tag): Path<String>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let Some(tag) = crate::models::normalize_tag(&tag) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let tag = match crate::models::TagAlias::resolve(tag).await {
+        Ok(x) => x,
+        Err(x) => {
+            tracing::error!("problem while resolving the tag: {x:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let articles = ArticleQuery::new()
+        .tag(tag.clone())
+        .fetch_page(PageStart::Offset(0), FEED_SIZE)
+        .await;
+    respond(
+        &config.base_url,
+        &query,
+        &headers,
+        &format!("Articles tagged {tag}"),
+        &format!("/?tag={}", crate::models::tag_query_value(&tag)),
+        articles,
+    )
+    .await
+}
+
+/// The latest articles written by `user`, including the ones they co-authored.
+#[tracing::instrument]
+pub async fn author_feed(
+    State(config): State<&'static AppConfig>,
+    Path(user): Path<String>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> Response {
+    if crate::models::User::get(user.clone()).await.is_err() {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let articles = ArticleQuery::new()
+        .author(user.clone())
+        .fetch_page(PageStart::Offset(0), FEED_SIZE)
+        .await;
+    respond(
+        &config.base_url,
+        &query,
+        &headers,
+        &format!("Articles by {user}"),
+        &format!("/profile/{}", crate::urls::encode_path_segment(&user)),
+        articles,
+    )
+    .await
+}
+
+/// The links in the feeds have to be absolute, they start with `base_url`.
+async fn respond(
+    base_url: &str,
+    query: &FeedQuery,
+    headers: &HeaderMap,
+    title: &str,
+    link: &str,
+    articles: Result<ArticleFeed, sqlx::Error>,
+) -> Response {
+    let feed = match articles {
+        Ok(feed) => load_contents(feed.articles).await,
+        Err(x) => Err(x),
+    };
+    let entries = match feed {
+        Ok(x) => x,
+        Err(x) => {
+            tracing::error!("problem while building the feed {link}: {x:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let atom = query.format.as_deref() == Some("atom");
+    let document = if atom {
+        render_atom(base_url, title, link, &entries)
+    } else {
+        render_rss(base_url, title, link, &entries)
+    };
+
+    let etag = format!("\"{:x}\"", Sha256::digest(document.as_bytes()));
+    let last_modified = entries.iter().map(|(_, x)| x.updated_at).max();
+    if is_not_modified(headers, &etag, last_modified) {
+        return StatusCode::NOT_MODIFIED.into_response();
+    }
+
+    let content_type = if atom {
+        "application/atom+xml; charset=utf-8"
+    } else {
+        "application/rss+xml; charset=utf-8"
+    };
+    let mut response = (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CACHE_CONTROL, "public, max-age=300".to_string()),
+            (header::ETAG, etag),
+        ],
+        document,
+    )
+        .into_response();
+    if let Some(x) = last_modified.and_then(|x| HeaderValue::from_str(&http_date(x)).ok()) {
+        response.headers_mut().insert(header::LAST_MODIFIED, x);
+    }
+    response
+}
+
+/// Pairs the articles with their content, keeping the order of the listing.
+async fn load_contents(
+    articles: Vec<Article>,
+) -> Result<Vec<(Article, ArticleContent)>, sqlx::Error> {
+    let slugs = articles.iter().map(|x| x.slug.clone()).collect::<Vec<_>>();
+    let mut contents = Article::contents(&slugs)
+        .await?
+        .into_iter()
+        .map(|x| (x.slug.clone(), x))
+        .collect::<HashMap<_, _>>();
+    Ok(articles
+        .into_iter()
+        .filter_map(|x| contents.remove(&x.slug).map(|content| (x, content)))
+        .collect())
+}
+
+fn is_not_modified(headers: &HeaderMap, etag: &str, last_modified: Option<NaiveDateTime>) -> bool {
+    // The entity tag is more precise, the date is only checked without it
+    if let Some(x) = headers.get(header::IF_NONE_MATCH) {
+        return x
+            .to_str()
+            .map(|x| x.split(',').any(|x| x.trim() == etag || x.trim() == "*"))
+            .unwrap_or_default();
+    }
+    let since = headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| NaiveDateTime::parse_from_str(x, HTTP_DATE_FORMAT).ok());
+    match (since, last_modified) {
+        (Some(since), Some(last_modified)) => {
+            last_modified.and_utc().timestamp() <= since.and_utc().timestamp()
+        }
+        _ => false,
+    }
+}
+
+fn http_date(date: NaiveDateTime) -> String {
+    date.format(HTTP_DATE_FORMAT).to_string()
+}
+
+/// Escapes `text` for the XML of the feeds and the sitemap, and for the html of the emails.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// The body as html, it is escaped again when written in the feed.
+fn render_body(base_url: &str, body: &str) -> String {
+    body_blocks(body)
+        .into_iter()
+        .map(|x| match x {
+            BodyBlock::Text(text) => format!("<p>{}</p>", escape(&text)),
+            BodyBlock::Image { alt, src } => {
+                let src = if src.starts_with('/') {
+                    format!("{base_url}{src}")
+                } else {
+                    src
+                };
+                format!("<img src=\"{}\" alt=\"{}\"/>", escape(&src), escape(&alt))
+            }
+        })
+        .collect()
+}
+
+fn render_rss(
+    base_url: &str,
+    title: &str,
+    link: &str,
+    entries: &[(Article, ArticleContent)],
+) -> String {
+    let mut rss = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+<channel>
+<title>{}</title>
+<link>{}</link>
+<description>{}</description>
+"#,
+        escape(title),
+        escape(&format!("{base_url}{link}")),
+        escape(title),
+    );
+    if let Some(updated) = entries.iter().map(|(_, x)| x.updated_at).max() {
+        rss.push_str(&format!(
+            "<lastBuildDate>{}</lastBuildDate>\n",
+            Utc.from_utc_datetime(&updated).to_rfc2822()
+        ));
+    }
+    for (article, content) in entries {
+        let url = escape(&format!("{base_url}/article/{}", article.slug));
+        rss.push_str(&format!(
+            "<item>
+<title>{}</title>
+<link>{url}</link>
+<guid isPermaLink=\"true\">{url}</guid>
+<dc:creator>{}</dc:creator>
+<pubDate>{}</pubDate>
+{}<description>{}</description>
+<content:encoded>{}</content:encoded>
+</item>
+",
+            escape(&article.title),
+            escape(&article.author.username),
+            Utc.from_utc_datetime(&content.created_at).to_rfc2822(),
+            article
+                .tag_list
+                .iter()
+                .map(|x| format!("<category>{}</category>\n", escape(x)))
+                .collect::<String>(),
+            escape(&article.description),
+            escape(&render_body(base_url, &content.body)),
+        ));
+    }
+    rss.push_str("</channel>\n</rss>\n");
+    rss
+}
+
+fn render_atom(
+    base_url: &str,
+    title: &str,
+    link: &str,
+    entries: &[(Article, ArticleContent)],
+) -> String {
+    // A feed without entries still needs a date, the epoch keeps it stable
+    let updated = entries
+        .iter()
+        .map(|(_, x)| x.updated_at)
+        .max()
+        .unwrap_or_default();
+    let mut atom = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+<title>{}</title>
+<id>{}</id>
+<link rel="alternate" href="{}"/>
+<updated>{}</updated>
+"#,
+        escape(title),
+        escape(&format!("{base_url}{link}")),
+        escape(&format!("{base_url}{link}")),
+        Utc.from_utc_datetime(&updated).to_rfc3339(),
+    );
+    for (article, content) in entries {
+        let url = escape(&format!("{base_url}/article/{}", article.slug));
+        atom.push_str(&format!(
+            "<entry>
+<title>{}</title>
+<id>{url}</id>
+<link rel=\"alternate\" href=\"{url}\"/>
+<published>{}</published>
+<updated>{}</updated>
+<author><name>{}</name></author>
+{}{}<summary>{}</summary>
+<content type=\"html\">{}</content>
+</entry>
+",
+            escape(&article.title),
+            Utc.from_utc_datetime(&content.created_at).to_rfc3339(),
+            Utc.from_utc_datetime(&content.updated_at).to_rfc3339(),
+            escape(&article.author.username),
+            article
+                .co_authors
+                .iter()
+                .map(|x| format!("<contributor><name>{}</name></contributor>\n", escape(x)))
+                .collect::<String>(),
+            article
+                .tag_list
+                .iter()
+                .map(|x| format!("<category term=\"{}\"/>\n", escape(x)))
+                .collect::<String>(),
+            escape(&article.description),
+            escape(&render_body(base_url, &content.body)),
+        ));
+    }
+    atom.push_str("</feed>\n");
+    atom
+}
@@ -1,11 +1,37 @@
 #![recursion_limit = "256"]
+pub mod account;
 pub mod app;
 pub mod auth;
+pub mod avatars;
+#[cfg(feature = "ssr")]
+pub mod backup;
+#[cfg(feature = "ssr")]
+pub mod cli;
 pub mod components;
 #[cfg(feature = "ssr")]
+pub mod config;
+#[cfg(feature = "ssr")]
 pub mod database;
+#[cfg(feature = "ssr")]
+pub mod digest;
+#[cfg(feature = "ssr")]
+pub mod feeds;
+pub mod live;
+#[cfg(feature = "ssr")]
+pub mod mailer;
 pub mod models;
+pub mod password;
+#[cfg(feature = "ssr")]
+pub mod redirects;
 pub(crate) mod routes;
+#[cfg(feature = "ssr")]
+pub mod seed;
+#[cfg(feature = "ssr")]
+pub mod sitemap;
+#[cfg(feature = "ssr")]
+pub mod transfer;
+pub mod uploads;
+pub mod urls;
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn hydrate() {
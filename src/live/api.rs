@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum LiveEvent {
+    CommentsChanged {
+        article: String,
+    },
+    FavouritesChanged {
+        article: String,
+        count: i64,
+        by: String,
+    },
+    Notification {
+        message: String,
+        link: String,
+    },
+}
+
+/// Listens to the server sent events published at `url` until the current owner is cleaned up.
+/// On the server this is a no-op, the stream is only opened once the page is hydrated.
+pub fn use_live_events<F>(url: String, on_event: F)
+where
+    F: Fn(LiveEvent) + 'static,
+{
+    #[cfg(feature = "hydrate")]
+    {
+        use leptos::prelude::on_cleanup;
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        let source = match web_sys::EventSource::new(&url) {
+            Ok(source) => source,
+            Err(err) => {
+                tracing::error!("could not open the live events stream {url}: {err:?}");
+                return;
+            }
+        };
+        let on_message =
+            Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |ev: web_sys::MessageEvent| {
+                let Some(data) = ev.data().as_string() else {
+                    return;
+                };
+                match serde_json::from_str::<LiveEvent>(&data) {
+                    Ok(event) => on_event(event),
+                    Err(err) => tracing::error!("malformed live event {data}: {err:?}"),
+                }
+            });
+        source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        let handle = send_wrapper::SendWrapper::new((source, on_message));
+        on_cleanup(move || handle.0.close());
+    }
+    #[cfg(not(feature = "hydrate"))]
+    let _ = (url, on_event);
+}
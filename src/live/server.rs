@@ -0,0 +1,105 @@
+use axum::{
+    extract::Path,
+    http::{HeaderMap, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use std::collections::HashMap;
+use tokio::sync::broadcast;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+use super::LiveEvent;
+
+const CHANNEL_CAPACITY: usize = 64;
+
+type Topics = std::sync::Mutex<HashMap<Topic, broadcast::Sender<LiveEvent>>>;
+
+static HUB: std::sync::OnceLock<Topics> = std::sync::OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Topic {
+    Article(String),
+    User(String),
+}
+
+fn subscribe(topic: Topic) -> broadcast::Receiver<LiveEvent> {
+    let mut topics = HUB.get_or_init(Default::default).lock().unwrap();
+    // a closed page drops its receiver, and a topic nobody publishes to again would stay
+    topics.retain(|_, sender| sender.receiver_count() > 0);
+    topics
+        .entry(topic)
+        .or_insert_with(|| broadcast::channel(CHANNEL_CAPACITY).0)
+        .subscribe()
+}
+
+#[tracing::instrument]
+pub fn publish(topic: Topic, event: LiveEvent) {
+    let mut topics = HUB.get_or_init(Default::default).lock().unwrap();
+    if let Some(sender) = topics.get(&topic) {
+        if sender.send(event).is_err() {
+            // every subscriber is gone, no need to keep the channel around
+            topics.remove(&topic);
+        }
+    }
+}
+
+/// Sends a notification to the authors of `slug`, the owner and the co-authors who accepted,
+/// except the one acting on it.
+#[tracing::instrument]
+pub async fn notify_author(slug: String, actor: String, message: String) {
+    let authors = sqlx::query_scalar!(
+        "SELECT username FROM ArticleAuthors WHERE article=$1 and accepted_at IS NOT NULL and username!=$2",
+        slug,
+        actor
+    )
+    .fetch_all(crate::database::get_db())
+    .await;
+    match authors {
+        Ok(authors) => {
+            for author in authors {
+                publish(
+                    Topic::User(author),
+                    LiveEvent::Notification {
+                        message: message.clone(),
+                        link: format!("/article/{slug}"),
+                    },
+                )
+            }
+        }
+        Err(x) => tracing::error!("problem while getting the article authors: {x:?}"),
+    }
+}
+
+fn event_stream(
+    receiver: broadcast::Receiver<LiveEvent>,
+) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
+    let stream = BroadcastStream::new(receiver)
+        // a lagging client just misses the skipped events, the next one brings it up to date
+        .filter_map(Result::ok)
+        .map(|event| Event::default().json_data(event));
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+pub async fn article_events(
+    Path(slug): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, StatusCode> {
+    // a topic per made-up slug would only fill the hub
+    let found = sqlx::query_scalar!("SELECT COUNT(*) FROM Articles WHERE slug=$1", slug)
+        .fetch_one(crate::database::get_db())
+        .await
+        .map_err(|x| {
+            tracing::error!("problem while getting the article {slug}: {x:?}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    if found == 0 {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(event_stream(subscribe(Topic::Article(slug))))
+}
+
+pub async fn user_events(
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, StatusCode> {
+    let username =
+        crate::auth::get_username_from_headers(&headers).ok_or(StatusCode::UNAUTHORIZED)?;
+    Ok(event_stream(subscribe(Topic::User(username))))
+}
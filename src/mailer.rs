@@ -0,0 +1,21 @@
+use crate::config::MailerConfig;
+use mail_send::mail_builder::MessageBuilder;
+
+/// The mailer account from the configuration, `None` when it has none.
+pub fn credentials() -> Option<&'static MailerConfig> {
+    crate::config::get_config().mailer.as_ref()
+}
+
+#[tracing::instrument(skip(message))]
+pub async fn send(message: MessageBuilder<'_>) -> mail_send::Result<()> {
+    let Some(creds) = credentials() else {
+        return Err(mail_send::Error::MissingCredentials);
+    };
+    mail_send::SmtpClientBuilder::new(creds.smtp_server.as_str(), creds.smtp_port)
+        .implicit_tls(false)
+        .credentials((creds.email.as_str(), creds.passwd.as_str()))
+        .connect()
+        .await?
+        .send(message)
+        .await
+}
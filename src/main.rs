@@ -1,35 +1,116 @@
-#![recursion_limit = "256"]
+#![recursion_limit = "512"]
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() {
     use axum::Router;
+    use clap::Parser;
     use leptos::logging::log;
     use leptos::prelude::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
+    use realworld_app_leptos_axum_sqlite::account;
     use realworld_app_leptos_axum_sqlite::app::*;
+    use realworld_app_leptos_axum_sqlite::avatars;
+    use realworld_app_leptos_axum_sqlite::backup;
+    use realworld_app_leptos_axum_sqlite::cli;
+    use realworld_app_leptos_axum_sqlite::config;
     use realworld_app_leptos_axum_sqlite::database;
+    use realworld_app_leptos_axum_sqlite::digest;
+    use realworld_app_leptos_axum_sqlite::feeds;
+    use realworld_app_leptos_axum_sqlite::live;
+    use realworld_app_leptos_axum_sqlite::redirects;
+    use realworld_app_leptos_axum_sqlite::sitemap;
+    use realworld_app_leptos_axum_sqlite::uploads;
+
+    // Parsed first, the help needs neither the configuration nor the database
+    let cli = cli::Cli::parse();
 
     let conf = get_configuration(None).unwrap();
     let addr = conf.leptos_options.site_addr;
     let leptos_options = conf.leptos_options;
     // Generate the list of routes in your Leptos App
 
-    tracing_subscriber::fmt()
-        .with_level(true)
-        .with_max_level(tracing::Level::DEBUG)
-        .init();
+    let serve = matches!(cli.command, None | Some(cli::Command::Serve));
+    if serve {
+        tracing_subscriber::fmt()
+            .with_level(true)
+            .with_max_level(tracing::Level::DEBUG)
+            .init();
+    } else {
+        // The commands keep the standard output for their results, like the exports
+        tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::WARN)
+            .with_writer(std::io::stderr)
+            .init();
+    }
+    // Load and check the configuration before anything reads it
+    let config = match config::init_config(addr) {
+        Ok(x) => x,
+        Err(x) => panic!("problem with the configuration: {x}"),
+    };
+
+    if let Some(command) = cli.command.filter(|_| !serve) {
+        if let Err(x) = cli::run(command).await {
+            eprintln!("{x}");
+            std::process::exit(1);
+        }
+        return;
+    }
     // Init the pool into static
     database::init_db()
         .await
         .expect("problem during initialization of the database");
 
+    tokio::spawn(digest::run_scheduler());
+    tokio::spawn(backup::run_scheduler());
+
     let routes = generate_route_list(App);
+    let state = config::AppState {
+        leptos_options: leptos_options.clone(),
+        config,
+    };
     let app = Router::new()
-        .leptos_routes(&leptos_options, routes, {
+        .route(
+            "/live/article/{slug}",
+            axum::routing::get(live::article_events),
+        )
+        .route("/live/user", axum::routing::get(live::user_events))
+        .route(
+            "/digest/unsubscribe",
+            axum::routing::get(digest::unsubscribe_page).post(digest::unsubscribe),
+        )
+        .route("/admin/backup", axum::routing::post(backup::backup_now))
+        .route(
+            account::DOWNLOAD_PATH,
+            axum::routing::get(account::download),
+        )
+        .route("/sitemap.xml", axum::routing::get(sitemap::sitemap))
+        .route("/robots.txt", axum::routing::get(sitemap::robots))
+        .route("/feed.xml", axum::routing::get(feeds::global_feed))
+        .route("/tag/{tag}/feed.xml", axum::routing::get(feeds::tag_feed))
+        .route(
+            "/profile/{user}/feed.xml",
+            axum::routing::get(feeds::author_feed),
+        )
+        .route(
+            uploads::UPLOAD_URL,
+            axum::routing::post(uploads::upload).layer(axum::extract::DefaultBodyLimit::max(
+                // Room for the multipart boundaries and headers
+                uploads::MAX_UPLOAD_SIZE + 64 * 1024,
+            )),
+        )
+        .nest_service(uploads::UPLOADS_PATH, uploads::serve_uploads())
+        .route(
+            &format!("{}/{{file}}", avatars::AVATARS_PATH),
+            axum::routing::get(avatars::avatar),
+        )
+        .leptos_routes_with_context(&state, routes, move || provide_context(config), {
             let leptos_options = leptos_options.clone();
             move || shell(leptos_options.clone())
         })
-        .fallback(leptos_axum::file_and_error_handler(shell))
+        .fallback(leptos_axum::file_and_error_handler_with_context::<
+            config::AppState,
+            _,
+        >(move || provide_context(config), shell))
         .layer(
             tower_http::trace::TraceLayer::new_for_http()
                 .make_span_with(
@@ -46,7 +127,8 @@ async fn main() {
         .layer(axum::middleware::from_fn(
             realworld_app_leptos_axum_sqlite::auth::auth_middleware,
         ))
-        .with_state(leptos_options);
+        .layer(axum::middleware::from_fn(redirects::redirect_middleware))
+        .with_state(state);
 
     // run our app with hyper
     // `axum::Server` is a re-export of `hyper::Server`
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ArticleRole {
+    /// Wrote the article, the only one who can delete it and manage its co-authors
+    Owner,
+    /// Can edit the content and the tags of the article
+    Editor,
+}
+
+impl ArticleRole {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Owner => "owner",
+            Self::Editor => "editor",
+        }
+    }
+}
+
+impl std::str::FromStr for ArticleRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "owner" => Ok(Self::Owner),
+            "editor" => Ok(Self::Editor),
+            _ => Err(format!("unknown article role {s}")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ArticleAuthor {
+    pub username: String,
+    pub image: Option<String>,
+    pub role: ArticleRole,
+    /// `false` while the invitation is pending
+    pub accepted: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl ArticleAuthor {
+    /// The owner first, then the co-authors in the order they were invited.
+    pub async fn for_article(slug: String) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query!(
+            r#"
+SELECT aa.username, u.image, aa.role, aa.accepted_at IS NOT NULL as "accepted!: bool"
+FROM ArticleAuthors as aa
+    JOIN Users as u ON u.username = aa.username
+WHERE aa.article=$1
+ORDER BY aa.role = 'editor', aa.invited_at"#,
+            slug
+        )
+        .map(|x| Self {
+            username: x.username,
+            image: x.image,
+            role: x.role.parse().unwrap_or(ArticleRole::Editor),
+            accepted: x.accepted,
+        })
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    /// The role of `username` on the article, only once the invitation is accepted.
+    pub async fn role(slug: String, username: String) -> Result<Option<ArticleRole>, sqlx::Error> {
+        sqlx::query_scalar!(
+            "SELECT role FROM ArticleAuthors WHERE article=$1 and username=$2 and accepted_at IS NOT NULL",
+            slug,
+            username
+        )
+        .fetch_optional(crate::database::get_db())
+        .await
+        .map(|x| x.and_then(|x| x.parse().ok()))
+    }
+
+    pub async fn invite(
+        slug: String,
+        username: String,
+    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO ArticleAuthors(article, username, role) VALUES ($1, $2, 'editor')",
+            slug,
+            username
+        )
+        .execute(crate::database::get_writer())
+        .await
+    }
+
+    pub async fn accept(
+        slug: String,
+        username: String,
+    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        sqlx::query!(
+            "UPDATE ArticleAuthors SET accepted_at=CURRENT_TIMESTAMP
+WHERE article=$1 and username=$2 and accepted_at IS NULL",
+            slug,
+            username
+        )
+        .execute(crate::database::get_writer())
+        .await
+    }
+
+    /// Declines an invitation or removes a co-author, the owner always stays.
+    pub async fn remove(
+        slug: String,
+        username: String,
+    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM ArticleAuthors WHERE article=$1 and username=$2 and role='editor'",
+            slug,
+            username
+        )
+        .execute(crate::database::get_writer())
+        .await
+    }
+}
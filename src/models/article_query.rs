@@ -0,0 +1,349 @@
+use super::{Article, ArticleFeed, Cursor, PageStart, UserPreview};
+use sqlx::types::chrono::NaiveDateTime;
+
+/// The order of a listing.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ArticleSort {
+    /// The latest first, the order the cursors follow
+    #[default]
+    Newest,
+    /// The unread bookmarks first, then the latest bookmarked, only with `bookmarked_by`
+    ReadingList,
+}
+
+/// The articles of a listing, every listing goes through it: the filters choose the articles,
+/// the sort and the page which of them come back, and all the rows map the same way.
+///
+/// The page is selected first, the counters, tags, co-authors and the state for the logged
+/// user are only joined to the rows of that page.
+#[derive(Debug, Clone, Default)]
+pub struct ArticleQuery {
+    viewer: Option<String>,
+    author: Option<String>,
+    favourited_by: Option<String>,
+    bookmarked_by: Option<String>,
+    tag: Option<String>,
+    followed_by: Option<(String, bool)>,
+    slug: Option<String>,
+    sort: ArticleSort,
+}
+
+#[derive(sqlx::FromRow)]
+struct ArticleRow {
+    slug: String,
+    title: String,
+    description: String,
+    body: Option<String>,
+    created_at: NaiveDateTime,
+    cursor_created_at: String,
+    favorites_count: i64,
+    comments_count: i64,
+    username: String,
+    image: Option<String>,
+    fav: bool,
+    following: bool,
+    bookmarked: bool,
+    bookmark_read: bool,
+    co_authors: String,
+    series_id: Option<i64>,
+    series_title: Option<String>,
+    series_position: Option<i64>,
+    tag_list: String,
+}
+
+impl ArticleRow {
+    fn into_entry(self) -> (Cursor, Article) {
+        let cursor = Cursor {
+            created_at: self.cursor_created_at,
+            slug: self.slug.clone(),
+        };
+        let article = Article {
+            slug: self.slug,
+            title: self.title,
+            body: self.body,
+            description: self.description,
+            created_at: self.created_at.format(super::DATE_FORMAT).to_string(),
+            favorites_count: self.favorites_count,
+            tag_list: serde_json::from_str::<Vec<String>>(&self.tag_list)
+                .unwrap_or_default()
+                .into_iter()
+                .map(super::Tag::from_stored)
+                .collect(),
+            author: UserPreview {
+                username: self.username,
+                image: self.image,
+                following: self.following,
+            },
+            fav: self.fav,
+            comments_count: self.comments_count,
+            bookmarked: self.bookmarked,
+            bookmark_read: self.bookmark_read,
+            series: super::SeriesPart::from_columns(
+                self.series_id,
+                self.series_title,
+                self.series_position,
+            ),
+            co_authors: serde_json::from_str(&self.co_authors).unwrap_or_default(),
+        };
+        (cursor, article)
+    }
+}
+
+impl ArticleQuery {
+    /// All the articles, as seen by the logged user.
+    pub fn new() -> Self {
+        Self {
+            viewer: crate::auth::get_username(),
+            ..Default::default()
+        }
+    }
+
+    /// Written by `username`, including the articles they co-authored.
+    pub fn author(mut self, username: String) -> Self {
+        self.author = Some(username);
+        self
+    }
+
+    pub fn favourited_by(mut self, username: String) -> Self {
+        self.favourited_by = Some(username);
+        self
+    }
+
+    /// On the reading list of `username`.
+    pub fn bookmarked_by(mut self, username: String) -> Self {
+        self.bookmarked_by = Some(username);
+        self
+    }
+
+    /// An empty tag does not filter anything.
+    pub fn tag(mut self, tag: String) -> Self {
+        self.tag = Some(tag).filter(|x| !x.is_empty());
+        self
+    }
+
+    /// The feed of `username`: the authors they follow, and their followed tags with `tags`.
+    pub fn followed_by(mut self, username: String, tags: bool) -> Self {
+        self.followed_by = Some((username, tags));
+        self
+    }
+
+    pub fn slug(mut self, slug: String) -> Self {
+        self.slug = Some(slug);
+        self
+    }
+
+    pub fn sort(mut self, sort: ArticleSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    fn reading_list(&self) -> bool {
+        self.sort == ArticleSort::ReadingList && self.bookmarked_by.is_some()
+    }
+
+    fn push_filters(&self, qb: &mut sqlx::QueryBuilder<'_, sqlx::Sqlite>) {
+        qb.push(" FROM Articles as a");
+        if let Some(username) = &self.bookmarked_by {
+            qb.push(" JOIN Bookmarks as bm ON bm.article = a.slug and bm.username = ")
+                .push_bind(username.clone());
+        }
+        qb.push(" WHERE 1=1");
+        if let Some(username) = &self.author {
+            qb.push(" AND a.slug IN (SELECT article FROM ArticleAuthors WHERE username = ")
+                .push_bind(username.clone())
+                .push(" and accepted_at IS NOT NULL)");
+        }
+        if let Some(username) = &self.favourited_by {
+            qb.push(" AND a.slug IN (SELECT article FROM FavArticles WHERE username = ")
+                .push_bind(username.clone())
+                .push(")");
+        }
+        if let Some(tag) = &self.tag {
+            qb.push(" AND a.slug IN (SELECT article FROM ArticleTags WHERE tag = ")
+                .push_bind(tag.clone())
+                .push(")");
+        }
+        if let Some((username, tags)) = &self.followed_by {
+            qb.push(" AND (a.author IN (SELECT influencer FROM Follows WHERE follower = ")
+                .push_bind(username.clone())
+                .push(")");
+            if *tags {
+                qb.push(
+                    " OR a.slug IN (SELECT at.article FROM ArticleTags as at
+    JOIN TagFollows as tf ON tf.tag = at.tag WHERE tf.username = ",
+                )
+                .push_bind(username.clone())
+                .push(")");
+            }
+            qb.push(")");
+        }
+        if let Some(slug) = &self.slug {
+            qb.push(" AND a.slug = ").push_bind(slug.clone());
+        }
+    }
+
+    /// The order inside the page, over `Articles as a`, and of the rows, over `page as p`.
+    fn order(&self, start: &PageStart) -> (&'static str, &'static str) {
+        if self.reading_list() {
+            return (
+                "bm.read_at IS NOT NULL, bm.created_at desc, a.slug",
+                "p.is_read, p.bookmarked_at desc, p.slug",
+            );
+        }
+        match start {
+            // Going backwards, the rows come in ascending order
+            PageStart::Before(_) => ("a.created_at, a.slug", "p.created_at, p.slug"),
+            _ => (
+                "a.created_at desc, a.slug desc",
+                "p.created_at desc, p.slug desc",
+            ),
+        }
+    }
+
+    /// `limit` is the number of rows, negative for all of them.
+    fn build(
+        &self,
+        start: &PageStart,
+        limit: i64,
+        with_body: bool,
+    ) -> sqlx::QueryBuilder<'static, sqlx::Sqlite> {
+        let (offset, after, before) = start.bounds();
+        let (page_order, order) = self.order(start);
+        let mut qb =
+            sqlx::QueryBuilder::new("WITH page AS MATERIALIZED (SELECT a.slug, a.created_at");
+        if self.reading_list() {
+            qb.push(", bm.read_at IS NOT NULL as is_read, bm.created_at as bookmarked_at");
+        }
+        self.push_filters(&mut qb);
+        // The cursors follow the date order, the reading list keeps the page numbers
+        if !self.reading_list() {
+            if let Some(after) = after {
+                qb.push(" AND (a.created_at, a.slug) < (")
+                    .push_bind(after.created_at.clone())
+                    .push(", ")
+                    .push_bind(after.slug.clone())
+                    .push(")");
+            }
+            if let Some(before) = before {
+                qb.push(" AND (a.created_at, a.slug) > (")
+                    .push_bind(before.created_at.clone())
+                    .push(", ")
+                    .push_bind(before.slug.clone())
+                    .push(")");
+            }
+        }
+        qb.push(" ORDER BY ")
+            .push(page_order)
+            .push(" LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+        qb.push(
+            ")
+SELECT
+    a.slug,
+    a.title,
+    a.description,
+    ",
+        )
+        .push(if with_body { "a.body" } else { "NULL" })
+        .push(
+            " as body,
+    a.created_at,
+    CAST(a.created_at AS TEXT) as cursor_created_at,
+    a.favorites_count,
+    a.comments_count,
+    u.username,
+    u.image,
+    f.username IS NOT NULL as fav,
+    fo.influencer IS NOT NULL as following,
+    b.username IS NOT NULL as bookmarked,
+    b.read_at IS NOT NULL as bookmark_read,
+    COALESCE(ca.co_authors, '[]') as co_authors,
+    s.id as series_id,
+    s.title as series_title,
+    sa.position as series_position,
+    COALESCE(t.tag_list, '[]') as tag_list
+FROM page as p
+    JOIN Articles as a ON a.slug = p.slug
+    JOIN Users as u ON u.username = a.author
+    LEFT JOIN FavArticles as f ON f.article = a.slug and f.username = ",
+        )
+        .push_bind(self.viewer.clone())
+        .push(" LEFT JOIN Follows as fo ON fo.influencer = a.author and fo.follower = ")
+        .push_bind(self.viewer.clone())
+        .push(" LEFT JOIN Bookmarks as b ON b.article = a.slug and b.username = ")
+        .push_bind(self.viewer.clone())
+        .push(
+            "
+    LEFT JOIN SeriesArticles as sa ON sa.article = a.slug
+    LEFT JOIN Series as s ON s.id = sa.series
+    LEFT JOIN (
+        SELECT article, json_group_array(tag) as tag_list FROM (
+            SELECT article, tag FROM ArticleTags
+            WHERE article IN (SELECT slug FROM page)
+            ORDER BY article, tag
+        )
+        GROUP BY article
+    ) as t ON t.article = a.slug
+    LEFT JOIN (
+        SELECT article, json_group_array(username) as co_authors FROM (
+            SELECT article, username FROM ArticleAuthors
+            WHERE article IN (SELECT slug FROM page) and role='editor' and accepted_at IS NOT NULL
+            ORDER BY article, accepted_at
+        )
+        GROUP BY article
+    ) as ca ON ca.article = a.slug
+ORDER BY ",
+        )
+        .push(order);
+        qb
+    }
+
+    /// `amount` articles from `start`, with the cursors around them.
+    #[tracing::instrument(level = tracing::Level::DEBUG)]
+    pub async fn fetch_page(
+        &self,
+        start: PageStart,
+        amount: i64,
+    ) -> Result<ArticleFeed, sqlx::Error> {
+        // One more than asked, to know if there is a next page
+        let rows = self
+            .build(&start, amount + 1, false)
+            .build_query_as::<ArticleRow>()
+            .fetch_all(crate::database::get_db())
+            .await?;
+        let rows = rows.into_iter().map(ArticleRow::into_entry).collect();
+        Ok(ArticleFeed::from_rows(rows, &start, amount))
+    }
+
+    #[tracing::instrument(level = tracing::Level::DEBUG)]
+    pub async fn fetch_all(&self) -> Result<Vec<Article>, sqlx::Error> {
+        let rows = self
+            .build(&PageStart::Offset(0), -1, false)
+            .build_query_as::<ArticleRow>()
+            .fetch_all(crate::database::get_db())
+            .await?;
+        Ok(rows.into_iter().map(|x| x.into_entry().1).collect())
+    }
+
+    /// The first article with its body, `RowNotFound` without any.
+    #[tracing::instrument(level = tracing::Level::DEBUG)]
+    pub async fn fetch_one(&self) -> Result<Article, sqlx::Error> {
+        self.build(&PageStart::Offset(0), 1, true)
+            .build_query_as::<ArticleRow>()
+            .fetch_one(crate::database::get_db())
+            .await
+            .map(|x| x.into_entry().1)
+    }
+
+    /// How many articles there are on all the pages.
+    #[tracing::instrument(level = tracing::Level::DEBUG)]
+    pub async fn count(&self) -> Result<i64, sqlx::Error> {
+        let mut qb = sqlx::QueryBuilder::new("SELECT COUNT(*)");
+        self.push_filters(&mut qb);
+        qb.build_query_scalar::<i64>()
+            .fetch_one(crate::database::get_db())
+            .await
+    }
+}
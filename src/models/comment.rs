@@ -21,7 +21,7 @@ impl Comment {
             username,
             body
         )
-        .execute(crate::database::get_db())
+        .execute(crate::database::get_writer())
         .await
     }
 
@@ -29,7 +29,8 @@ impl Comment {
     pub async fn get_all(article: String) -> Result<Vec<Self>, sqlx::Error> {
         sqlx::query!(
             "
-        SELECT c.*, u.image FROM Comments as c
+        SELECT c.id as \"id!\", c.article, c.username, c.body, c.created_at, u.image
+            FROM Comments as c
             JOIN Users as u ON u.username=c.username
         WHERE c.article=$1
         ORDER BY c.created_at",
@@ -47,13 +48,15 @@ impl Comment {
         .await
     }
 
+    /// The article of the deleted comment, `None` when there was no such comment of `user`.
     #[cfg(feature = "ssr")]
-    pub async fn delete(
-        id: i32,
-        user: String,
-    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
-        sqlx::query!("DELETE FROM Comments WHERE id=$1 and username=$2", id, user)
-            .execute(crate::database::get_db())
-            .await
+    pub async fn delete(id: i32, user: String) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar!(
+            "DELETE FROM Comments WHERE id=$1 and username=$2 RETURNING article",
+            id,
+            user
+        )
+        .fetch_optional(crate::database::get_writer())
+        .await
     }
 }
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DigestFrequency {
+    #[default]
+    Never,
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Never => "never",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+}
+
+impl std::str::FromStr for DigestFrequency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" => Ok(Self::Never),
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            _ => Err(format!("unknown digest frequency {s}")),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone)]
+pub struct DigestSubscription {
+    pub username: String,
+    pub email: String,
+    pub last_sent_at: sqlx::types::chrono::NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DigestArticle {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub author: String,
+}
+
+#[cfg(feature = "ssr")]
+impl DigestSubscription {
+    pub async fn get_frequency(username: String) -> Result<DigestFrequency, sqlx::Error> {
+        sqlx::query_scalar!(
+            "SELECT frequency FROM DigestSubscriptions WHERE username=$1",
+            username
+        )
+        .fetch_optional(crate::database::get_db())
+        .await
+        .map(|frequency| frequency.and_then(|x| x.parse().ok()).unwrap_or_default())
+    }
+
+    pub async fn set_frequency(
+        username: String,
+        frequency: DigestFrequency,
+    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        if frequency == DigestFrequency::Never {
+            return sqlx::query!(
+                "DELETE FROM DigestSubscriptions WHERE username=$1",
+                username
+            )
+            .execute(crate::database::get_writer())
+            .await;
+        }
+        let frequency = frequency.as_str();
+        // Changing the frequency keeps last_sent_at, so nothing is sent twice
+        sqlx::query!(
+            "
+INSERT INTO DigestSubscriptions(username, frequency) VALUES ($1, $2)
+ON CONFLICT(username) DO UPDATE SET frequency=excluded.frequency",
+            username,
+            frequency,
+        )
+        .execute(crate::database::get_writer())
+        .await
+    }
+
+    /// Subscriptions whose last digest is older than their frequency.
+    pub async fn due() -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query!(
+            "
+SELECT d.username, u.email, d.last_sent_at
+FROM DigestSubscriptions as d
+    JOIN Users as u ON u.username = d.username
+WHERE d.last_sent_at <= datetime('now', CASE d.frequency WHEN 'daily' THEN '-1 day' ELSE '-7 days' END)"
+        )
+        .map(|x| Self {
+            username: x.username,
+            email: x.email,
+            last_sent_at: x.last_sent_at,
+        })
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    /// New articles since the last digest, from followed authors and followed tags.
+    pub async fn articles(&self) -> Result<Vec<DigestArticle>, sqlx::Error> {
+        sqlx::query_as!(
+            DigestArticle,
+            "
+SELECT a.slug, a.title, a.description, a.author
+FROM Articles as a
+WHERE
+    a.created_at > $2
+    AND a.author != $1
+    AND (
+        a.author IN (SELECT influencer FROM Follows WHERE follower=$1)
+        OR a.slug IN (
+            SELECT at.article FROM ArticleTags as at
+                JOIN TagFollows as tf ON tf.tag = at.tag
+            WHERE tf.username=$1
+        )
+    )
+ORDER BY a.created_at desc
+LIMIT 50",
+            self.username,
+            self.last_sent_at,
+        )
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    pub async fn mark_sent(
+        &self,
+        sent_at: sqlx::types::chrono::NaiveDateTime,
+    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        sqlx::query!(
+            "UPDATE DigestSubscriptions SET last_sent_at=$2 WHERE username=$1",
+            self.username,
+            sent_at,
+        )
+        .execute(crate::database::get_writer())
+        .await
+    }
+}
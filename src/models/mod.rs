@@ -1,13 +1,44 @@
 mod user;
-pub use user::{User, UserPreview};
+#[cfg(feature = "ssr")]
+pub use user::Rename;
+pub use user::{
+    validate_username, User, UserPreview, DELETED_USER, RESERVED_USERNAMES, USERNAME_MAX_LENGTH,
+    USERNAME_MIN_LENGTH,
+};
 mod pagination;
-pub use pagination::Pagination;
+pub use pagination::{ArticleFeed, Pagination};
+#[cfg(feature = "ssr")]
+pub use pagination::{Cursor, PageStart};
 mod article;
 pub use article::Article;
+#[cfg(feature = "ssr")]
+pub use article::ArticleContent;
+#[cfg(feature = "ssr")]
+mod article_query;
+#[cfg(feature = "ssr")]
+pub use article_query::{ArticleQuery, ArticleSort};
+mod article_author;
+pub use article_author::{ArticleAuthor, ArticleRole};
 mod comment;
 pub use comment::Comment;
 mod search;
 pub use search::MatchedArticles;
+mod digest;
+#[cfg(feature = "ssr")]
+pub use digest::DigestSubscription;
+pub use digest::{DigestArticle, DigestFrequency};
+mod series;
+pub use series::{Series, SeriesChoice, SeriesEntry, SeriesPart};
+#[cfg(feature = "ssr")]
+mod sitemap;
+#[cfg(feature = "ssr")]
+pub use sitemap::SitemapEntry;
+mod tag;
+#[cfg(feature = "ssr")]
+pub use tag::normalize_stored_tags;
+pub use tag::{normalize_tag, tag_query_value, Tag, TagAlias, TagCount, TAG_MAX_LENGTH};
+mod upload;
+pub use upload::Upload;
 
 #[cfg(feature = "ssr")]
 const DATE_FORMAT: &str = "%d/%m/%Y %H:%M";
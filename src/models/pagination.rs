@@ -1,12 +1,18 @@
 use leptos::prelude::*;
 use leptos_router::params::Params;
+use serde::{Deserialize, Serialize};
 
+/// `page` is kept for the links shared before the cursors, and to show where the reader is.
+/// When there is a cursor, it decides where the page starts instead.
 #[derive(Debug, Params, PartialEq, Clone)]
 pub struct Pagination {
     tag: Option<String>,
     my_feed: Option<bool>,
+    feed_tags: Option<bool>,
     page: Option<u32>,
     amount: Option<u32>,
+    after: Option<String>,
+    before: Option<String>,
 }
 
 impl Pagination {
@@ -18,11 +24,24 @@ impl Pagination {
     pub fn get_my_feed(&self) -> bool {
         self.my_feed.unwrap_or_default()
     }
+    /// Whether "Your Feed" includes the followed tags along with the followed authors.
+    #[inline]
+    pub fn get_feed_tags(&self) -> bool {
+        self.feed_tags.unwrap_or(true)
+    }
     #[inline]
     pub fn get_page(&self) -> u32 {
         self.page.unwrap_or_default()
     }
     #[inline]
+    pub fn get_after(&self) -> Option<String> {
+        self.after.clone()
+    }
+    #[inline]
+    pub fn get_before(&self) -> Option<String> {
+        self.before.clone()
+    }
+    #[inline]
     pub fn get_amount(&self) -> u32 {
         let per_page = expect_context::<RwSignal<Option<u32>>>();
         self.amount.unwrap_or(per_page.get_untracked().unwrap())
@@ -46,21 +65,44 @@ impl Pagination {
         self
     }
 
+    #[inline]
+    pub fn set_feed_tags(mut self, feed_tags: bool) -> Self {
+        self.feed_tags = Some(feed_tags);
+        self
+    }
+
+    /// Jumps to `page`, counted from the start of the listing.
+    #[inline]
+    pub fn set_page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self.after = None;
+        self.before = None;
+        self
+    }
+
     #[inline]
     pub fn reset_page(mut self) -> Self {
         self.page = Some(0);
+        self.after = None;
+        self.before = None;
         self
     }
 
+    /// Moves to the page after `cursor`, the page number is only used without a cursor.
     #[inline]
-    pub fn next_page(mut self) -> Self {
+    pub fn next_page(mut self, cursor: Option<String>) -> Self {
         self.page = Some(self.page.unwrap_or_default().saturating_add(1));
+        self.after = cursor;
+        self.before = None;
         self
     }
 
+    /// Moves to the page before `cursor`, the first page never needs one.
     #[inline]
-    pub fn previous_page(mut self) -> Self {
+    pub fn previous_page(mut self, cursor: Option<String>) -> Self {
         self.page = Some(self.page.unwrap_or_default().saturating_sub(1));
+        self.after = None;
+        self.before = cursor.filter(|_| self.page != Some(0));
         self
     }
 }
@@ -71,8 +113,11 @@ impl Default for Pagination {
         Self {
             tag: Some(String::new()),
             my_feed: Some(false),
+            feed_tags: Some(true),
             page: Some(0),
             amount: Some(per_page.get_untracked().unwrap_or(10)),
+            after: None,
+            before: None,
         }
     }
 }
@@ -81,11 +126,244 @@ impl std::fmt::Display for Pagination {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "/?tag={}&my_feed={}&page={}&amount={}",
-            self.get_tag(),
+            "/?tag={}&my_feed={}&feed_tags={}&page={}&amount={}",
+            super::tag_query_value(self.get_tag()),
             self.get_my_feed(),
+            self.get_feed_tags(),
             self.get_page(),
             self.get_amount(),
-        )
+        )?;
+        if let Some(after) = &self.after {
+            write!(f, "&after={after}")?;
+        }
+        if let Some(before) = &self.before {
+            write!(f, "&before={before}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A page of articles with the cursors of its neighbours, to be passed back as `after` or
+/// `before`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ArticleFeed {
+    pub articles: Vec<super::Article>,
+    /// `None` on the last page
+    pub next: Option<String>,
+    /// `None` on the first page
+    pub prev: Option<String>,
+    /// The number of articles on all the pages
+    pub total: i64,
+}
+
+/// The position of an article in the listings, sorted by creation date and then slug.
+/// It is given to the client encoded, so nothing relies on its shape.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    pub created_at: String,
+    pub slug: String,
+}
+
+#[cfg(feature = "ssr")]
+impl Cursor {
+    pub fn encode(&self) -> String {
+        use base64::Engine;
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(format!("{}|{}", self.created_at, self.slug))
+    }
+
+    pub fn decode(cursor: &str) -> Option<Self> {
+        use base64::Engine;
+        let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()?;
+        let (created_at, slug) = std::str::from_utf8(&decoded).ok()?.split_once('|')?;
+        Some(Self {
+            created_at: created_at.to_string(),
+            slug: slug.to_string(),
+        })
+    }
+}
+
+/// Where a page of articles starts.
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageStart {
+    /// Skips that many articles, for the links without a cursor
+    Offset(i64),
+    After(Cursor),
+    Before(Cursor),
+}
+
+#[cfg(feature = "ssr")]
+impl PageStart {
+    /// `after` wins over `before`, the page number only counts without any of them.
+    pub fn new(
+        page: u32,
+        amount: u32,
+        after: Option<String>,
+        before: Option<String>,
+    ) -> Result<Self, String> {
+        let decode = |x: String| Cursor::decode(&x).ok_or_else(|| format!("Invalid cursor {x}"));
+        match (after, before) {
+            (Some(after), _) => decode(after).map(Self::After),
+            (None, Some(before)) => decode(before).map(Self::Before),
+            (None, None) => Ok(Self::Offset(i64::from(page) * i64::from(amount))),
+        }
+    }
+
+    /// The arguments of the queries: the offset, and the bounds as `(created_at, slug)`.
+    pub(crate) fn bounds(&self) -> (i64, Option<&Cursor>, Option<&Cursor>) {
+        match self {
+            Self::Offset(offset) => (*offset, None, None),
+            Self::After(cursor) => (0, Some(cursor), None),
+            Self::Before(cursor) => (0, None, Some(cursor)),
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl ArticleFeed {
+    /// Builds the page from the rows of a query which fetched one more than `amount`, to know
+    /// if there is something beyond. Going backwards, the rows come in ascending order.
+    /// The total is counted apart, the listings used by the feeds do not need it.
+    pub(crate) fn from_rows(
+        mut rows: Vec<(Cursor, super::Article)>,
+        start: &PageStart,
+        amount: i64,
+    ) -> Self {
+        let has_more = rows.len() as i64 > amount;
+        rows.truncate(amount.max(0) as usize);
+        if matches!(start, PageStart::Before(_)) {
+            rows.reverse();
+        }
+        let first = rows.first().map(|(x, _)| x.encode());
+        let last = rows.last().map(|(x, _)| x.encode());
+        let (next, prev) = match start {
+            PageStart::Offset(offset) => (last.filter(|_| has_more), first.filter(|_| *offset > 0)),
+            PageStart::After(_) => (last.filter(|_| has_more), first),
+            PageStart::Before(_) => (last, first.filter(|_| has_more)),
+        };
+        Self {
+            articles: rows.into_iter().map(|(_, x)| x).collect(),
+            next,
+            prev,
+            total: 0,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    fn cursor(i: usize) -> Cursor {
+        Cursor {
+            created_at: format!("2025-01-{i:02} 10:00:00"),
+            slug: format!("article-{i}"),
+        }
+    }
+
+    /// The rows as a query returns them: newest first, or oldest first going backwards.
+    fn rows(range: impl Iterator<Item = usize>) -> Vec<(Cursor, super::super::Article)> {
+        range
+            .map(|i| {
+                let article = super::super::Article {
+                    slug: format!("article-{i}"),
+                    ..Default::default()
+                };
+                (cursor(i), article)
+            })
+            .collect()
+    }
+
+    fn slugs(feed: &ArticleFeed) -> Vec<&str> {
+        feed.articles.iter().map(|x| x.slug.as_str()).collect()
+    }
+
+    #[test]
+    fn cursor_round_trip() {
+        let cursor = Cursor {
+            created_at: "2025-01-02 03:04:05.678".to_string(),
+            slug: "what|is-a-slug".to_string(),
+        };
+        let encoded = cursor.encode();
+        assert!(encoded
+            .bytes()
+            .all(|x| x.is_ascii_alphanumeric() || x == b'-' || x == b'_'));
+        assert_eq!(Cursor::decode(&encoded), Some(cursor));
+    }
+
+    #[test]
+    fn cursor_rejects_garbage() {
+        use base64::Engine;
+        assert_eq!(Cursor::decode(""), None);
+        assert_eq!(Cursor::decode("not a cursor!"), None);
+        let no_separator = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode("slug");
+        assert_eq!(Cursor::decode(&no_separator), None);
+    }
+
+    #[test]
+    fn page_start() {
+        assert_eq!(PageStart::new(3, 10, None, None), Ok(PageStart::Offset(30)));
+        assert_eq!(
+            PageStart::new(3, 10, Some(cursor(1).encode()), Some(cursor(2).encode())),
+            Ok(PageStart::After(cursor(1)))
+        );
+        assert_eq!(
+            PageStart::new(0, 10, None, Some(cursor(2).encode())),
+            Ok(PageStart::Before(cursor(2)))
+        );
+        assert!(PageStart::new(0, 10, Some("!".to_string()), None).is_err());
+    }
+
+    #[test]
+    fn first_page() {
+        let feed = ArticleFeed::from_rows(rows((1..=3).rev()), &PageStart::Offset(0), 2);
+        assert_eq!(slugs(&feed), ["article-3", "article-2"]);
+        assert_eq!(feed.next, Some(cursor(2).encode()));
+        assert_eq!(feed.prev, None);
+    }
+
+    #[test]
+    fn last_page_by_offset() {
+        let feed = ArticleFeed::from_rows(rows((1..=2).rev()), &PageStart::Offset(2), 2);
+        assert_eq!(feed.next, None);
+        assert_eq!(feed.prev, Some(cursor(2).encode()));
+    }
+
+    #[test]
+    fn after_a_cursor() {
+        let start = PageStart::After(cursor(9));
+        let feed = ArticleFeed::from_rows(rows((6..=8).rev()), &start, 2);
+        assert_eq!(slugs(&feed), ["article-8", "article-7"]);
+        assert_eq!(feed.next, Some(cursor(7).encode()));
+        assert_eq!(feed.prev, Some(cursor(8).encode()));
+
+        let feed = ArticleFeed::from_rows(rows((7..=8).rev()), &start, 2);
+        assert_eq!(feed.next, None);
+    }
+
+    #[test]
+    fn before_a_cursor() {
+        let start = PageStart::Before(cursor(1));
+        // Ascending, the closest to the cursor first
+        let feed = ArticleFeed::from_rows(rows(2..=4), &start, 2);
+        assert_eq!(slugs(&feed), ["article-3", "article-2"]);
+        assert_eq!(feed.next, Some(cursor(2).encode()));
+        assert_eq!(feed.prev, Some(cursor(3).encode()));
+
+        // Back to the first page
+        let feed = ArticleFeed::from_rows(rows(2..=3), &start, 2);
+        assert_eq!(slugs(&feed), ["article-3", "article-2"]);
+        assert_eq!(feed.prev, None);
+    }
+
+    #[test]
+    fn empty_page() {
+        let feed = ArticleFeed::from_rows(vec![], &PageStart::Offset(0), 10);
+        assert!(feed.articles.is_empty());
+        assert_eq!((feed.next, feed.prev), (None, None));
     }
 }
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Series {
+    pub id: i64,
+    pub title: String,
+}
+
+/// Where an article sits in its series.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SeriesPart {
+    pub id: i64,
+    pub title: String,
+    pub position: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl SeriesPart {
+    /// Built from the columns of a `LEFT JOIN` on the series, which are all null when the
+    /// article is not part of one.
+    pub(crate) fn from_columns(
+        id: Option<i64>,
+        title: Option<String>,
+        position: Option<i64>,
+    ) -> Option<Self> {
+        Some(Self {
+            id: id?,
+            title: title?,
+            position: position?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SeriesEntry {
+    pub slug: String,
+    pub title: String,
+    pub position: i64,
+}
+
+/// The series chosen in the editor for an article.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeriesChoice {
+    None,
+    Existing(i64),
+    New(String),
+}
+
+#[cfg(feature = "ssr")]
+impl Series {
+    pub async fn for_author(author: String) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Self,
+            r#"SELECT id as "id!", title FROM Series WHERE author=$1 ORDER BY created_at desc"#,
+            author
+        )
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    /// The parts of the series, in order.
+    pub async fn entries(id: i64) -> Result<Vec<SeriesEntry>, sqlx::Error> {
+        sqlx::query_as!(
+            SeriesEntry,
+            "
+SELECT a.slug, a.title, sa.position
+FROM SeriesArticles as sa
+    JOIN Articles as a ON a.slug = sa.article
+WHERE sa.series=$1
+ORDER BY sa.position",
+            id
+        )
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    /// Puts the article of `author` in the chosen series at `position`, counted from 1, or at
+    /// the end when there is no position. The other parts are moved to make room.
+    pub async fn set_article(
+        conn: &mut sqlx::SqliteConnection,
+        author: &str,
+        slug: &str,
+        choice: SeriesChoice,
+        position: Option<i64>,
+    ) -> Result<(), sqlx::Error> {
+        // The article leaves its current place first, so moving it within the same series
+        // is the same as adding it
+        let current = sqlx::query!(
+            "SELECT series, position FROM SeriesArticles WHERE article=$1",
+            slug
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+        sqlx::query!("DELETE FROM SeriesArticles WHERE article=$1", slug)
+            .execute(&mut *conn)
+            .await?;
+        if let Some(current) = &current {
+            Self::renumber(conn, current.series).await?;
+        }
+
+        let series = match choice {
+            SeriesChoice::None => None,
+            SeriesChoice::Existing(id) => Some(
+                // Only the author can add articles to a series
+                sqlx::query_scalar!(
+                    "SELECT id FROM Series WHERE id=$1 and author=$2",
+                    id,
+                    author
+                )
+                .fetch_one(&mut *conn)
+                .await?,
+            ),
+            SeriesChoice::New(title) => Some(
+                sqlx::query_scalar!(
+                    "INSERT INTO Series(author, title) VALUES ($1, $2)
+ON CONFLICT(author, title) DO UPDATE SET title=excluded.title
+RETURNING id",
+                    author,
+                    title
+                )
+                .fetch_one(&mut *conn)
+                .await?,
+            ),
+        };
+
+        if let Some(series) = series {
+            let position = match position {
+                Some(position) => position.max(1),
+                None => match current.as_ref().filter(|x| x.series == series) {
+                    Some(current) => current.position,
+                    None => {
+                        sqlx::query_scalar!(
+                        "SELECT COALESCE(MAX(position), 0) + 1 FROM SeriesArticles WHERE series=$1",
+                        series
+                    )
+                        .fetch_one(&mut *conn)
+                        .await?
+                    }
+                },
+            };
+            sqlx::query!(
+                "UPDATE SeriesArticles SET position=position+1 WHERE series=$1 and position>=$2",
+                series,
+                position,
+            )
+            .execute(&mut *conn)
+            .await?;
+            sqlx::query!(
+                "INSERT INTO SeriesArticles(article, series, position) VALUES ($1, $2, $3)",
+                slug,
+                series,
+                position
+            )
+            .execute(&mut *conn)
+            .await?;
+            // A position past the end is brought back to the end
+            Self::renumber(conn, series).await?;
+        }
+
+        if let Some(previous) = current.map(|x| x.series).filter(|x| Some(*x) != series) {
+            sqlx::query!(
+                "DELETE FROM Series WHERE id=$1 and NOT EXISTS(SELECT 1 FROM SeriesArticles WHERE series=$1)",
+                previous
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Numbers the parts 1, 2, 3... without gaps, keeping their order.
+    async fn renumber(conn: &mut sqlx::SqliteConnection, series: i64) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "
+UPDATE SeriesArticles SET position=ordered.part
+FROM (
+    SELECT article, row_number() OVER (ORDER BY position, article) as part
+    FROM SeriesArticles WHERE series=$1
+) as ordered
+WHERE SeriesArticles.article = ordered.article",
+            series
+        )
+        .execute(&mut *conn)
+        .await
+        .map(|_| ())
+    }
+}
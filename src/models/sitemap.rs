@@ -0,0 +1,40 @@
+/// A page listed in the sitemap, `updated_at` is unknown for the profiles without articles.
+#[derive(Debug, Clone)]
+pub struct SitemapEntry {
+    pub path: String,
+    pub updated_at: Option<sqlx::types::chrono::NaiveDateTime>,
+}
+
+impl SitemapEntry {
+    /// The articles, then the profiles, each dated by their latest change.
+    pub async fn all() -> Result<Vec<Self>, sqlx::Error> {
+        let articles = sqlx::query!(
+            r#"SELECT slug, updated_at as "updated_at?" FROM Articles ORDER BY updated_at desc"#
+        )
+        .map(|x| Self {
+            path: format!("/article/{}", crate::urls::encode_path_segment(&x.slug)),
+            updated_at: x.updated_at,
+        })
+        .fetch_all(crate::database::get_db());
+        let profiles = sqlx::query!(
+            r#"
+SELECT u.username as "username!", MAX(a.updated_at) as "updated_at?: sqlx::types::chrono::NaiveDateTime"
+FROM Users as u
+    LEFT JOIN ArticleAuthors as aa ON aa.username = u.username and aa.accepted_at IS NOT NULL
+    LEFT JOIN Articles as a ON a.slug = aa.article
+GROUP BY u.username
+ORDER BY u.username"#
+        )
+        .map(|x| Self {
+            path: format!(
+                "/profile/{}",
+                crate::urls::encode_path_segment(&x.username)
+            ),
+            updated_at: x.updated_at,
+        })
+        .fetch_all(crate::database::get_db());
+        let (mut articles, profiles) = tokio::try_join!(articles, profiles)?;
+        articles.extend(profiles);
+        Ok(articles)
+    }
+}
@@ -0,0 +1,363 @@
+use serde::{Deserialize, Serialize};
+
+pub const TAG_MAX_LENGTH: usize = 32;
+
+/// Folds the different spellings of a tag into one: lowercase, without punctuation, one space
+/// between its words and at most [`TAG_MAX_LENGTH`] characters. `-`, `_` and `.` are kept
+/// inside a tag (`node.js`), `+` and `#` after its first character so `c++` and `c#` stay
+/// apart from `c` while `#rust` is `rust`. Returns `None` when nothing is left.
+pub fn normalize_tag(raw: &str) -> Option<String> {
+    let tag = raw
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.' | '+' | '#'))
+        .collect::<String>();
+    let tag = tag
+        .split(' ')
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let tag = tag
+        .trim_start_matches([' ', '-', '_', '.', '+', '#'])
+        .chars()
+        .take(TAG_MAX_LENGTH)
+        .collect::<String>();
+    // Trimmed after the cut as well, normalising a tag again gives the same tag
+    let tag = tag.trim_end_matches([' ', '-', '_', '.']);
+    (!tag.is_empty()).then(|| tag.to_string())
+}
+
+/// `tag` in the query string of a link, a tag can have `#` and `+`.
+pub fn tag_query_value(tag: &str) -> String {
+    crate::urls::encode_query_value(tag)
+}
+
+/// A tag once normalized by [`normalize_tag`]. The typed tags go through [`Tag::parse`], the
+/// serialised ones were built on the server and are read back as they are.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(transparent)]
+pub struct Tag(String);
+
+impl Tag {
+    pub fn parse(raw: &str) -> Option<Self> {
+        normalize_tag(raw).map(Self)
+    }
+
+    /// A tag read from the database, normalised when it was stored or by
+    /// [`normalize_stored_tags`], kept as it is so it links to its articles.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn from_stored(tag: String) -> Self {
+        Self(tag)
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Tag {
+    type Error = String;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        Self::parse(&raw).ok_or_else(|| format!("invalid tag {raw:?}"))
+    }
+}
+
+impl From<Tag> for String {
+    fn from(tag: Tag) -> Self {
+        tag.0
+    }
+}
+
+impl std::ops::Deref for Tag {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TagCount {
+    pub tag: String,
+    pub articles: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TagAlias {
+    pub alias: String,
+    pub tag: String,
+}
+
+/// Folds the tags stored before [`normalize_tag`], or under older rules, into their normalised
+/// spelling. Run once by the `normalize-tags` command on a database from before the tags
+/// migration, the newer tags are stored normalised already.
+#[cfg(feature = "ssr")]
+pub async fn normalize_stored_tags(
+    conn: &mut sqlx::SqliteConnection,
+) -> Result<usize, sqlx::Error> {
+    let stored = sqlx::query_scalar!(
+        "SELECT tag as \"tag!\" FROM ArticleTags UNION SELECT tag FROM TagFollows"
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    let mut normalized = 0;
+    for tag in stored {
+        let normal = normalize_tag(&tag);
+        if normal.as_deref() == Some(tag.as_str()) {
+            continue;
+        }
+        // The rows which would become duplicates are skipped by the update, then removed
+        if let Some(normal) = normal {
+            sqlx::query!(
+                "UPDATE OR IGNORE ArticleTags SET tag=$2 WHERE tag=$1",
+                tag,
+                normal
+            )
+            .execute(&mut *conn)
+            .await?;
+            sqlx::query!(
+                "UPDATE OR IGNORE TagFollows SET tag=$2 WHERE tag=$1",
+                tag,
+                normal
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+        sqlx::query!("DELETE FROM ArticleTags WHERE tag=$1", tag)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query!("DELETE FROM TagFollows WHERE tag=$1", tag)
+            .execute(&mut *conn)
+            .await?;
+        normalized += 1;
+    }
+    Ok(normalized)
+}
+
+#[cfg(feature = "ssr")]
+impl TagCount {
+    /// Every tag containing `search` with its number of articles, the most used first.
+    pub async fn directory(
+        search: String,
+        page: i64,
+        amount: i64,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        let offset = page * amount;
+        sqlx::query_as!(
+            Self,
+            "
+SELECT tag, COUNT(*) as articles
+FROM ArticleTags
+WHERE $1 = '' OR instr(tag, $1) > 0
+GROUP BY tag
+ORDER BY articles desc, tag
+LIMIT $2 OFFSET $3",
+            search,
+            amount,
+            offset,
+        )
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    pub async fn count(search: String) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar!(
+            "SELECT COUNT(DISTINCT tag) FROM ArticleTags WHERE $1 = '' OR instr(tag, $1) > 0",
+            search
+        )
+        .fetch_one(crate::database::get_db())
+        .await
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl TagAlias {
+    pub async fn all() -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Self,
+            "SELECT alias, tag FROM TagAliases ORDER BY tag, alias"
+        )
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    /// The tag `tag` stands for, itself when it is not an alias.
+    pub async fn resolve(tag: String) -> Result<String, sqlx::Error> {
+        sqlx::query_scalar!("SELECT tag FROM TagAliases WHERE alias=$1", tag)
+            .fetch_optional(crate::database::get_db())
+            .await
+            .map(|x| x.unwrap_or(tag))
+    }
+
+    /// Replaces the aliases among the tags of an article by the tags they stand for.
+    pub async fn resolve_article_tags(
+        conn: &mut sqlx::SqliteConnection,
+        slug: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "
+UPDATE OR IGNORE ArticleTags SET tag=(SELECT tag FROM TagAliases WHERE alias=ArticleTags.tag)
+WHERE article=$1 and tag IN (SELECT alias FROM TagAliases)",
+            slug
+        )
+        .execute(&mut *conn)
+        .await?;
+        // What is left was already tagged with the target as well
+        sqlx::query!(
+            "DELETE FROM ArticleTags WHERE article=$1 and tag IN (SELECT alias FROM TagAliases)",
+            slug
+        )
+        .execute(&mut *conn)
+        .await?;
+        Ok(())
+    }
+
+    /// Moves every article and follower of `from` to `into`, and keeps `from` as an alias so
+    /// it is folded into `into` from now on. `into` must not be an alias itself, see [`Self::resolve`].
+    pub async fn merge(from: String, into: String) -> Result<(), sqlx::Error> {
+        let mut transaction = crate::database::begin_write().await?;
+        Self::merge_with(transaction.as_mut(), &from, &into).await?;
+        transaction.commit().await
+    }
+
+    /// [`Self::merge`] inside a transaction of the caller.
+    pub async fn merge_with(
+        conn: &mut sqlx::SqliteConnection,
+        from: &str,
+        into: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE OR IGNORE ArticleTags SET tag=$2 WHERE tag=$1",
+            from,
+            into
+        )
+        .execute(&mut *conn)
+        .await?;
+        sqlx::query!("DELETE FROM ArticleTags WHERE tag=$1", from)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query!(
+            "UPDATE OR IGNORE TagFollows SET tag=$2 WHERE tag=$1",
+            from,
+            into
+        )
+        .execute(&mut *conn)
+        .await?;
+        sqlx::query!("DELETE FROM TagFollows WHERE tag=$1", from)
+            .execute(&mut *conn)
+            .await?;
+        // The aliases of `from` now point to `into` as well
+        sqlx::query!("UPDATE TagAliases SET tag=$2 WHERE tag=$1", from, into)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query!(
+            "INSERT INTO TagAliases(alias, tag) VALUES ($1, $2)
+ON CONFLICT(alias) DO UPDATE SET tag=excluded.tag",
+            from,
+            into
+        )
+        .execute(&mut *conn)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn remove(alias: String) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        sqlx::query!("DELETE FROM TagAliases WHERE alias=$1", alias)
+            .execute(crate::database::get_writer())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_tag_folds_spellings() {
+        assert_eq!(normalize_tag("Rust").as_deref(), Some("rust"));
+        assert_eq!(normalize_tag("#rust").as_deref(), Some("rust"));
+        assert_eq!(
+            normalize_tag("  Hello,\tWorld!! ").as_deref(),
+            Some("hello world")
+        );
+        assert_eq!(normalize_tag("Node.js").as_deref(), Some("node.js"));
+        assert_eq!(normalize_tag("-web_dev-").as_deref(), Some("web_dev"));
+        assert_eq!(normalize_tag("Ñandú").as_deref(), Some("ñandú"));
+    }
+
+    #[test]
+    fn normalize_tag_keeps_plus_and_hash_inside() {
+        assert_eq!(normalize_tag("C++").as_deref(), Some("c++"));
+        assert_eq!(normalize_tag("C#").as_deref(), Some("c#"));
+        assert_eq!(normalize_tag("++c").as_deref(), Some("c"));
+        assert_ne!(normalize_tag("c++"), normalize_tag("c"));
+    }
+
+    #[test]
+    fn normalize_tag_rejects_empty() {
+        for raw in ["", "   ", "!!!", "++", "#", "-_."] {
+            assert_eq!(normalize_tag(raw), None, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn normalize_tag_truncates() {
+        let long = "a".repeat(TAG_MAX_LENGTH + 10);
+        assert_eq!(normalize_tag(&long), Some("a".repeat(TAG_MAX_LENGTH)));
+        // The cut doesn't leave a separator at the end
+        let cut = format!("{}-bc", "a".repeat(TAG_MAX_LENGTH - 1));
+        assert_eq!(normalize_tag(&cut), Some("a".repeat(TAG_MAX_LENGTH - 1)));
+    }
+
+    #[test]
+    fn normalize_tag_is_idempotent() {
+        for raw in [
+            "C++",
+            "c#",
+            "#rust",
+            "Ñandú",
+            "  Hello,  World!! ",
+            "node.js",
+            "-x-",
+            &format!("{} b", "a".repeat(TAG_MAX_LENGTH - 1)),
+        ] {
+            let tag = normalize_tag(raw).expect("a tag");
+            assert_eq!(normalize_tag(&tag).as_ref(), Some(&tag), "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn tag_parse() {
+        assert_eq!(Tag::parse(" Rust "), Tag::parse("rust"));
+        assert_eq!(
+            Tag::parse("#Rust").map(String::from).as_deref(),
+            Some("rust")
+        );
+        assert_eq!(Tag::parse("!!!"), None);
+        assert!(Tag::try_from("?".to_string()).is_err());
+    }
+
+    #[test]
+    fn tag_is_serialized_as_it_is() {
+        let tag = Tag::parse("#Rust").unwrap();
+        assert_eq!(serde_json::to_string(&tag).unwrap(), "\"rust\"");
+        // Read back without normalising it again, like the tags stored under older rules
+        let tag: Tag = serde_json::from_str("\"Node.JS\"").unwrap();
+        assert_eq!(tag.as_str(), "Node.JS");
+    }
+
+    #[test]
+    fn tag_query_value_escapes() {
+        assert_eq!(tag_query_value("c# and c++"), "c%23%20and%20c%2B%2B");
+        assert_eq!(tag_query_value("node.js"), "node.js");
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// A file uploaded by a user, stored on disk under the sha256 of its content.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Upload {
+    pub hash: String,
+    pub username: String,
+    pub mime: String,
+    pub extension: String,
+    pub size: i64,
+}
+
+#[cfg(feature = "ssr")]
+impl Upload {
+    /// Records who uploaded the file, uploading the same content twice is not an error.
+    pub async fn insert(&self) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        sqlx::query!(
+            "INSERT OR IGNORE INTO Uploads(hash, username, mime, extension, size) VALUES ($1, $2, $3, $4, $5)",
+            self.hash,
+            self.username,
+            self.mime,
+            self.extension,
+            self.size
+        )
+        .execute(crate::database::get_writer())
+        .await
+    }
+
+    /// The file names of the uploads of `username` nobody else uploaded, gone from the disk
+    /// with the user.
+    pub async fn only_of(
+        conn: &mut sqlx::SqliteConnection,
+        username: &str,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar!(
+            "SELECT hash || '.' || extension as \"name!: String\" FROM Uploads u
+WHERE username=$1 and NOT EXISTS(SELECT 1 FROM Uploads o WHERE o.hash=u.hash and o.username!=u.username)",
+            username
+        )
+        .fetch_all(conn)
+        .await
+    }
+}
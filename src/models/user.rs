@@ -12,6 +12,14 @@ pub struct UserPreview {
     pub following: bool,
 }
 
+impl UserPreview {
+    /// The image to show, generated from the username when the user has not chosen one.
+    #[inline]
+    pub fn avatar(&self) -> String {
+        crate::avatars::avatar_url(&self.username, self.image.as_deref())
+    }
+}
+
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct User {
     username: String,
@@ -25,6 +33,78 @@ pub struct User {
 
 static EMAIL_REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
 
+/// The author shown for the content of the anonymised accounts, nobody can sign up with it.
+pub const DELETED_USER: &str = "deleted_user";
+
+pub const USERNAME_MIN_LENGTH: usize = 4;
+pub const USERNAME_MAX_LENGTH: usize = 32;
+
+/// Names which would pass for the app or its staff, compared without the case. On the server
+/// `validate_username` also refuses the ones of `auth.reserved_usernames`.
+pub const RESERVED_USERNAMES: [&str; 16] = [
+    DELETED_USER,
+    "admin",
+    "administrator",
+    "anonymous",
+    "moderator",
+    "profile",
+    "realworld",
+    "root",
+    "settings",
+    "signup",
+    "staff",
+    "support",
+    "system",
+    "login",
+    "logout",
+    "editor",
+];
+
+/// The rules of a new username, at the signup or when it changes. The existing ones are
+/// left as they are.
+pub fn validate_username(username: &str) -> Result<(), String> {
+    let length = username.chars().count();
+    if length < USERNAME_MIN_LENGTH {
+        return Err(format!(
+            "Username {username} is too short, at least {USERNAME_MIN_LENGTH} characters"
+        ));
+    }
+    if length > USERNAME_MAX_LENGTH {
+        return Err(format!(
+            "Username is too long, at most {USERNAME_MAX_LENGTH} characters"
+        ));
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    {
+        return Err("Username can only have letters, digits, _ and -".into());
+    }
+    let reserved = RESERVED_USERNAMES
+        .iter()
+        .any(|x| x.eq_ignore_ascii_case(username));
+    #[cfg(feature = "ssr")]
+    let reserved = reserved
+        || crate::config::get_config()
+            .auth
+            .is_reserved_username(username);
+    if reserved {
+        return Err(format!("Username {username} is reserved"));
+    }
+    Ok(())
+}
+
+/// What came of a username change.
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+pub enum Rename {
+    Renamed,
+    /// Another user has it, or had it
+    Taken,
+    /// The last change is too recent, the next one is possible from then
+    TooSoon(sqlx::types::chrono::NaiveDateTime),
+}
+
 impl User {
     #[inline]
     pub fn username(&self) -> String {
@@ -42,21 +122,27 @@ impl User {
     pub fn image(&self) -> Option<String> {
         self.image.clone()
     }
+    /// The password hash of a user read from the database, for the exports.
+    #[cfg(feature = "ssr")]
+    #[inline]
+    pub fn password_hash(&self) -> Option<String> {
+        self.password.clone()
+    }
+    #[inline]
+    pub fn avatar(&self) -> String {
+        crate::avatars::avatar_url(&self.username, self.image.as_deref())
+    }
 
+    /// Checks the password against the policy, with the username and email set before.
+    #[cfg(feature = "ssr")]
     pub fn set_password(mut self, password: String) -> Result<Self, String> {
-        if password.len() < 4 {
-            return Err("You need to provide a stronger password".into());
-        }
+        crate::password::validate_password(&password, &[&self.username, &self.email])?;
         self.password = Some(password);
         Ok(self)
     }
 
     pub fn set_username(mut self, username: String) -> Result<Self, String> {
-        if username.len() < 4 {
-            return Err(format!(
-                "Username {username} is too short, at least 4 characters"
-            ));
-        }
+        validate_username(&username)?;
         self.username = username;
         Ok(self)
     }
@@ -93,8 +179,7 @@ impl User {
     pub fn set_image(mut self, image: String) -> Result<Self, String> {
         if image.is_empty() {
             self.image = None;
-            // TODO: This is incorrect! changeme in the future for a proper validation
-        } else if !image.starts_with("http") {
+        } else if !crate::uploads::is_image_url(&image) {
             return Err("Invalid image url!".into());
         } else {
             self.image = Some(image);
@@ -113,6 +198,17 @@ impl User {
         .await
     }
 
+    #[cfg(feature = "ssr")]
+    pub async fn is_admin(username: String) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar!(
+            "SELECT role = 'admin' as \"admin!: bool\" FROM users WHERE username=$1",
+            username
+        )
+        .fetch_optional(crate::database::get_db())
+        .await
+        .map(|x| x.unwrap_or_default())
+    }
+
     #[cfg(feature = "ssr")]
     pub async fn get_email(email: String) -> Result<Self, sqlx::Error> {
         sqlx::query_as!(
@@ -144,7 +240,7 @@ impl User {
             self.email,
             hashed_password,
         )
-        .execute(crate::database::get_db())
+        .execute(crate::database::get_writer())
         .await
     }
 
@@ -179,7 +275,199 @@ WHERE username=$1",
             password_is_some,
             hashed_password,
         )
-        .execute(crate::database::get_db())
+        .execute(crate::database::get_writer())
+        .await
+    }
+
+    /// Every user, for the exports.
+    #[cfg(feature = "ssr")]
+    pub async fn all() -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Self,
+            "SELECT username, email, bio, image, password FROM users ORDER BY username"
+        )
+        .fetch_all(crate::database::get_db())
+        .await
+    }
+
+    /// `role` is `user` or `admin`.
+    #[cfg(feature = "ssr")]
+    pub async fn set_role(
+        username: String,
+        role: &str,
+    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        sqlx::query!("UPDATE Users SET role=$2 WHERE username=$1", username, role)
+            .execute(crate::database::get_writer())
+            .await
+    }
+
+    /// Changes the username, the rows of the user follow through the `ON UPDATE CASCADE` of
+    /// the schema. The former username is kept for the redirects and can't be taken by anyone
+    /// else. At most one change every `cooldown` seconds.
+    #[cfg(feature = "ssr")]
+    pub async fn rename(
+        username: String,
+        new_username: String,
+        cooldown: u64,
+    ) -> Result<Rename, sqlx::Error> {
+        let mut transaction = crate::database::begin_write().await?;
+        let taken = sqlx::query_scalar!(
+            "SELECT EXISTS(SELECT 1 FROM Users WHERE username=$1)
+    OR EXISTS(SELECT 1 FROM UsernameChanges WHERE old_username=$1 and username!=$2) as \"taken!: bool\"",
+            new_username,
+            username
+        )
+        .fetch_one(transaction.as_mut())
+        .await?;
+        if taken {
+            return Ok(Rename::Taken);
+        }
+        let last_change = sqlx::query_scalar!(
+            "SELECT MAX(changed_at) as \"changed_at?: sqlx::types::chrono::NaiveDateTime\" FROM UsernameChanges WHERE username=$1",
+            username
+        )
+        .fetch_one(transaction.as_mut())
+        .await?;
+        if let Some(last_change) = last_change {
+            let next_change = last_change + std::time::Duration::from_secs(cooldown);
+            if next_change > sqlx::types::chrono::Utc::now().naive_utc() {
+                return Ok(Rename::TooSoon(next_change));
+            }
+        }
+        sqlx::query!(
+            "UPDATE Users SET username=$2 WHERE username=$1",
+            username,
+            new_username
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        // Back to a former username of theirs, it is the current one again
+        sqlx::query!(
+            "DELETE FROM UsernameChanges WHERE old_username=$1",
+            new_username
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        sqlx::query!(
+            "INSERT INTO UsernameChanges(old_username, username) VALUES ($1, $2)",
+            username,
+            new_username
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        transaction.commit().await?;
+        Ok(Rename::Renamed)
+    }
+
+    /// The current username of the user who had `username` before, when nobody has it now.
+    #[cfg(feature = "ssr")]
+    pub async fn renamed_to(username: String) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar!(
+            "SELECT username FROM UsernameChanges
+WHERE old_username=$1 and NOT EXISTS(SELECT 1 FROM Users WHERE username=$1)",
+            username
+        )
+        .fetch_optional(crate::database::get_db())
+        .await
+    }
+
+    /// Whether `username` belongs to a user, now or before a change.
+    #[cfg(feature = "ssr")]
+    pub async fn username_taken(username: String) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar!(
+            "SELECT EXISTS(SELECT 1 FROM Users WHERE username=$1)
+    OR EXISTS(SELECT 1 FROM UsernameChanges WHERE old_username=$1) as \"taken!: bool\"",
+            username
+        )
+        .fetch_one(crate::database::get_db())
         .await
     }
+
+    /// Whether `password` is the one of the user, read with [`Self::get`].
+    #[cfg(feature = "ssr")]
+    pub fn verify_password(&self, password: &str) -> bool {
+        use argon2::password_hash::{PasswordHash, PasswordVerifier};
+        self.password
+            .as_deref()
+            .and_then(|x| PasswordHash::new(x).ok())
+            .is_some_and(|hash| {
+                Argon2::default()
+                    .verify_password(password.as_bytes(), &hash)
+                    .is_ok()
+            })
+    }
+
+    /// Removes the user but keeps their articles, comments and series under [`DELETED_USER`].
+    /// The rest, follows, favourites, bookmarks and the co-authorships of other articles, goes
+    /// with the user through the cascades.
+    #[cfg(feature = "ssr")]
+    pub async fn anonymise(
+        username: String,
+    ) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        let mut transaction = crate::database::begin_write().await?;
+        // Without a password, nobody can log in as the placeholder
+        sqlx::query!(
+            "INSERT OR IGNORE INTO Users(username, email, password) VALUES ($1, $2, '')",
+            DELETED_USER,
+            "deleted_user@localhost"
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        sqlx::query!(
+            "UPDATE Articles SET author=$2 WHERE author=$1",
+            username,
+            DELETED_USER
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        sqlx::query!(
+            "UPDATE ArticleAuthors SET username=$2 WHERE username=$1 and role='owner'",
+            username,
+            DELETED_USER
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        sqlx::query!(
+            "UPDATE Comments SET username=$2 WHERE username=$1",
+            username,
+            DELETED_USER
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        // A series whose title the placeholder already has is dropped, its articles stay
+        sqlx::query!(
+            "UPDATE OR IGNORE Series SET author=$2 WHERE author=$1",
+            username,
+            DELETED_USER
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        // The uploaded images may be in the kept articles
+        sqlx::query!(
+            "UPDATE OR IGNORE Uploads SET username=$2 WHERE username=$1",
+            username,
+            DELETED_USER
+        )
+        .execute(transaction.as_mut())
+        .await?;
+        let result = sqlx::query!("DELETE FROM Users WHERE username=$1", username)
+            .execute(transaction.as_mut())
+            .await?;
+        transaction.commit().await?;
+        Ok(result)
+    }
+
+    /// Removes the user, their articles, comments, follows and everything else of theirs go
+    /// with them through the cascades. The files only they uploaded are removed from the disk.
+    #[cfg(feature = "ssr")]
+    pub async fn delete(username: String) -> Result<sqlx::sqlite::SqliteQueryResult, sqlx::Error> {
+        let mut transaction = crate::database::begin_write().await?;
+        let files = super::Upload::only_of(transaction.as_mut(), &username).await?;
+        let result = sqlx::query!("DELETE FROM Users WHERE username=$1", username)
+            .execute(transaction.as_mut())
+            .await?;
+        transaction.commit().await?;
+        crate::uploads::remove_files(&files).await;
+        Ok(result)
+    }
 }
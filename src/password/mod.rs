@@ -0,0 +1,242 @@
+//! The password policy, checked the same way in the forms as the user types and on the server.
+//!
+//! The breached passwords are in a file of SHA-1 hashes, like the Pwned Passwords ranges: the
+//! browser only sends the first 5 hex digits of the hash and looks for the rest in the hashes
+//! sharing them, the password never leaves it before the form is sent.
+
+#[cfg(feature = "ssr")]
+mod server;
+#[cfg(feature = "ssr")]
+pub use server::*;
+mod strength;
+pub use strength::{estimate, Strength, Weakness};
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+/// The hex digits of the hash sent to look for a breached password.
+pub const PREFIX_LENGTH: usize = 5;
+
+/// The `[password]` section of the configuration, sent to the browser for the live checks.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    /// Out of lowercase, uppercase, digits and symbols.
+    pub min_classes: usize,
+    /// The strength estimate from 0 to 4, see [`estimate`].
+    pub min_score: u8,
+    /// Rejects the passwords of the list of breached passwords.
+    pub check_breached: bool,
+    /// A list used instead of the bundled one, same format: one uppercase SHA-1 per line,
+    /// optionally followed by `:count`. Stays on the server.
+    #[serde(skip_serializing)]
+    pub breached_list: Option<std::path::PathBuf>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 10,
+            max_length: 128,
+            min_classes: 2,
+            min_score: 2,
+            check_breached: true,
+            breached_list: None,
+        }
+    }
+}
+
+/// What is wrong with a password, empty when it can be used.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct PasswordFeedback {
+    /// The strength estimate, from 0 to 4.
+    pub score: u8,
+    pub problems: Vec<String>,
+    pub suggestions: Vec<String>,
+}
+
+impl PasswordFeedback {
+    pub fn is_acceptable(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// All the problems in one message, for the server responses.
+    pub fn message(&self) -> String {
+        self.problems.join(". ")
+    }
+}
+
+impl PasswordPolicy {
+    /// Checks `password` against the policy. `user_inputs` are the username and email, which
+    /// make a password weak, and `breached` tells whether it was found in the breached list.
+    pub fn check(&self, password: &str, user_inputs: &[&str], breached: bool) -> PasswordFeedback {
+        let mut feedback = PasswordFeedback::default();
+        let length = password.chars().count();
+        if length < self.min_length {
+            feedback.problems.push(format!(
+                "The password needs at least {} characters",
+                self.min_length
+            ));
+        }
+        if length > self.max_length {
+            feedback.problems.push(format!(
+                "The password can have at most {} characters",
+                self.max_length
+            ));
+            // Not worth estimating
+            return feedback;
+        }
+        let classes = [
+            password.chars().any(|c| c.is_lowercase()),
+            password.chars().any(|c| c.is_uppercase()),
+            password.chars().any(|c| c.is_ascii_digit()),
+            password.chars().any(|c| !c.is_alphanumeric()),
+        ];
+        if classes.iter().filter(|x| **x).count() < self.min_classes {
+            feedback.problems.push(format!(
+                "The password needs {} of lowercase letters, uppercase letters, digits and symbols",
+                self.min_classes
+            ));
+        }
+        if breached {
+            feedback
+                .problems
+                .push("This password is in a list of breached passwords".to_string());
+        }
+        let strength = estimate(password, user_inputs);
+        feedback.score = strength.score;
+        if strength.score < self.min_score {
+            feedback
+                .problems
+                .push("The password is too easy to guess".to_string());
+            if let Some(weakness) = strength.weakness {
+                feedback.suggestions.push(weakness.warning().to_string());
+            }
+            feedback
+                .suggestions
+                .push("A few uncommon words together are strong and easy to remember".to_string());
+        }
+        feedback
+    }
+}
+
+/// The uppercase hex SHA-1 of `password`, as in the breached list.
+pub fn sha1_hex(password: &str) -> String {
+    Sha1::digest(password.as_bytes())
+        .iter()
+        .map(|x| format!("{x:02X}"))
+        .collect()
+}
+
+#[tracing::instrument]
+#[server(PasswordPolicyAction, "/api", "GetJson")]
+pub async fn password_policy() -> Result<PasswordPolicy, ServerFnError> {
+    Ok(crate::config::use_config().password.clone())
+}
+
+/// The rest of the hashes of the breached passwords starting with `prefix`.
+#[tracing::instrument]
+#[server(BreachedRangeAction, "/api", "GetJson")]
+pub async fn breached_range(prefix: String) -> Result<Vec<String>, ServerFnError> {
+    if prefix.len() != PREFIX_LENGTH || !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ServerFnError::new(format!(
+            "The prefix needs {PREFIX_LENGTH} hex digits"
+        )));
+    }
+    Ok(breached_list().range(&prefix.to_ascii_uppercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STRONG: &str = "Velvet-orbit-cactus-71";
+
+    #[test]
+    fn accepts_a_strong_password() {
+        let feedback = PasswordPolicy::default().check(STRONG, &["someone"], false);
+        assert!(feedback.is_acceptable(), "{feedback:?}");
+        assert!(feedback.score >= 2);
+        assert!(feedback.suggestions.is_empty());
+    }
+
+    #[test]
+    fn length() {
+        let policy = PasswordPolicy::default();
+        let feedback = policy.check("Ab1!", &[], false);
+        assert!(feedback
+            .problems
+            .contains(&"The password needs at least 10 characters".to_string()));
+
+        let feedback = policy.check(&"Ab1!".repeat(40), &[], false);
+        assert_eq!(
+            feedback.problems,
+            ["The password can have at most 128 characters"]
+        );
+        assert_eq!(feedback.score, 0);
+    }
+
+    #[test]
+    fn character_classes() {
+        let policy = PasswordPolicy {
+            min_score: 0,
+            ..Default::default()
+        };
+        assert!(!policy
+            .check("velvetorbitcactus", &[], false)
+            .is_acceptable());
+        assert!(policy
+            .check("velvetorbitcactus71", &[], false)
+            .is_acceptable());
+        let policy = PasswordPolicy {
+            min_classes: 4,
+            ..policy
+        };
+        assert!(policy.check(STRONG, &[], false).is_acceptable());
+        assert!(!policy
+            .check("Velvetorbitcactus71", &[], false)
+            .is_acceptable());
+    }
+
+    #[test]
+    fn breached() {
+        let feedback = PasswordPolicy::default().check(STRONG, &[], true);
+        assert_eq!(
+            feedback.message(),
+            "This password is in a list of breached passwords"
+        );
+    }
+
+    #[test]
+    fn easy_to_guess() {
+        let policy = PasswordPolicy::default();
+        for (password, user_inputs) in [
+            ("Password123", &[][..]),
+            ("Qwertyuiop1", &[]),
+            (
+                "Margaret.Hamilton1",
+                &["margaret.hamilton", "margaret@example.com"][..],
+            ),
+        ] {
+            let feedback = policy.check(password, user_inputs, false);
+            assert!(
+                feedback
+                    .problems
+                    .contains(&"The password is too easy to guess".to_string()),
+                "{password}: {feedback:?}"
+            );
+            assert!(!feedback.suggestions.is_empty());
+        }
+    }
+
+    #[test]
+    fn sha1_hex_is_uppercase() {
+        assert_eq!(
+            sha1_hex("password"),
+            "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8"
+        );
+    }
+}
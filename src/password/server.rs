@@ -0,0 +1,189 @@
+use super::{sha1_hex, PREFIX_LENGTH};
+
+/// Common and breached passwords with their usual variants, the `breached_list` of the
+/// configuration replaces it with a bigger one.
+const BUNDLED_LIST: &str = include_str!("breached-passwords.txt");
+
+static BREACHED_LIST: std::sync::OnceLock<BreachedList> = std::sync::OnceLock::new();
+
+/// The SHA-1 of the breached passwords, sorted for the lookups by prefix.
+pub struct BreachedList(Vec<[u8; 20]>);
+
+impl BreachedList {
+    /// Reads lines of 40 hex digits, anything after them (the `:count` of the Pwned Passwords
+    /// files) is ignored like the empty lines and the `#` comments.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut hashes = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let hash = line
+                .get(..40)
+                .and_then(decode_hex)
+                .ok_or_else(|| format!("line {}: not a SHA-1 hash", number + 1))?;
+            hashes.push(hash);
+        }
+        hashes.sort_unstable();
+        hashes.dedup();
+        Ok(Self(hashes))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, password: &str) -> bool {
+        decode_hex(&sha1_hex(password)).is_some_and(|x| self.0.binary_search(&x).is_ok())
+    }
+
+    /// The last 35 hex digits of the hashes starting with the uppercase `prefix`.
+    pub fn range(&self, prefix: &str) -> Vec<String> {
+        let (Some(first), Some(last)) = (
+            decode_hex(&format!("{prefix:0<40}")),
+            decode_hex(&format!("{prefix:F<40}")),
+        ) else {
+            return Vec::new();
+        };
+        let start = self.0.partition_point(|x| *x < first);
+        let end = self.0.partition_point(|x| *x <= last);
+        self.0[start..end]
+            .iter()
+            .map(|x| {
+                x.iter().map(|b| format!("{b:02X}")).collect::<String>()[PREFIX_LENGTH..]
+                    .to_string()
+            })
+            .collect()
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<[u8; 20]> {
+    let mut bytes = [0; 20];
+    if hex.len() != 40 {
+        return None;
+    }
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// The list of the configuration, read on the first use, or the bundled one.
+pub fn breached_list() -> &'static BreachedList {
+    BREACHED_LIST.get_or_init(|| {
+        let config = &crate::config::get_config().password;
+        if let Some(path) = &config.breached_list {
+            match std::fs::read_to_string(path)
+                .map_err(|x| x.to_string())
+                .and_then(|x| BreachedList::parse(&x))
+            {
+                Ok(list) => {
+                    tracing::info!("{} breached passwords read from {path:?}", list.len());
+                    return list;
+                }
+                Err(x) => {
+                    tracing::error!("problem with the breached passwords of {path:?}, the bundled ones are used: {x}");
+                }
+            }
+        }
+        BreachedList::parse(BUNDLED_LIST).expect("the bundled breached passwords to be valid")
+    })
+}
+
+/// Checks a new password against the configured policy and the breached passwords.
+pub fn validate_password(password: &str, user_inputs: &[&str]) -> Result<(), String> {
+    let policy = &crate::config::get_config().password;
+    let breached = policy.check_breached && breached_list().contains(password);
+    let feedback = policy.check(password, user_inputs, breached);
+    if feedback.is_acceptable() {
+        Ok(())
+    } else {
+        Err(feedback.message())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(passwords: &[&str]) -> BreachedList {
+        let text = passwords
+            .iter()
+            .map(|x| format!("{}:12", sha1_hex(x)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        BreachedList::parse(&text).unwrap()
+    }
+
+    #[test]
+    fn parse_skips_comments_and_counts() {
+        let text = format!(
+            "# a comment\n\n{}\n{}:3\n{}\n",
+            sha1_hex("password"),
+            sha1_hex("123456"),
+            sha1_hex("password").to_lowercase()
+        );
+        let list = BreachedList::parse(&text).unwrap();
+        // The same hash in lowercase is the same hash
+        assert_eq!(list.len(), 2);
+        assert!(list.contains("password"));
+        assert!(list.contains("123456"));
+    }
+
+    #[test]
+    fn parse_rejects_bad_lines() {
+        let text = format!("{}\nnot a hash\n", sha1_hex("password"));
+        assert_eq!(
+            BreachedList::parse(&text).err().as_deref(),
+            Some("line 2: not a SHA-1 hash")
+        );
+        assert!(BreachedList::parse("5BAA6").is_err());
+    }
+
+    #[test]
+    fn contains_is_exact() {
+        let list = list(&["password", "letmein"]);
+        assert!(list.contains("password"));
+        assert!(list.contains("letmein"));
+        assert!(!list.contains("Password"));
+        assert!(!list.contains(""));
+    }
+
+    #[test]
+    fn range_returns_the_suffixes_of_the_prefix() {
+        let list = list(&["password", "letmein", "dragon"]);
+        let hash = sha1_hex("password");
+        let (prefix, suffix) = hash.split_at(PREFIX_LENGTH);
+        assert_eq!(list.range(prefix), [suffix.to_string()]);
+        assert!(list.range("00000").is_empty());
+        assert!(list.range("XYZ12").is_empty());
+    }
+
+    #[test]
+    fn range_includes_the_bounds() {
+        let text = ["ABCDE", "ABCDF", "ABCDD"]
+            .iter()
+            .flat_map(|prefix| {
+                [
+                    format!("{prefix}{}", "0".repeat(35)),
+                    format!("{prefix}{}", "F".repeat(35)),
+                ]
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let list = BreachedList::parse(&text).unwrap();
+        assert_eq!(list.range("ABCDE"), ["0".repeat(35), "F".repeat(35)]);
+    }
+
+    #[test]
+    fn bundled_list_parses() {
+        let list = BreachedList::parse(BUNDLED_LIST).unwrap();
+        assert!(!list.is_empty());
+        assert!(list.contains("password"));
+    }
+}
@@ -0,0 +1,485 @@
+//! A strength estimate in the spirit of zxcvbn, small enough for the WASM bundle: the password
+//! is cut into the cheapest run of patterns (common words, the user's own details, keyboard
+//! rows, sequences, repeats, years, and brute force for the rest) and the guesses an attacker
+//! trying those patterns first would need give the score.
+
+/// Common passwords and the words they are made of, the most used first. The rank is the
+/// number of guesses, the full list of breached passwords is only on the server.
+const COMMON_WORDS: &[&str] = &[
+    "password",
+    "qwerty",
+    "dragon",
+    "monkey",
+    "letmein",
+    "football",
+    "iloveyou",
+    "admin",
+    "welcome",
+    "login",
+    "master",
+    "hello",
+    "sunshine",
+    "princess",
+    "abc",
+    "shadow",
+    "superman",
+    "trustno",
+    "baseball",
+    "starwars",
+    "freedom",
+    "whatever",
+    "love",
+    "secret",
+    "michael",
+    "jordan",
+    "hunter",
+    "ranger",
+    "buster",
+    "soccer",
+    "harley",
+    "batman",
+    "andrew",
+    "tigger",
+    "charlie",
+    "robert",
+    "thomas",
+    "hockey",
+    "daniel",
+    "george",
+    "jessica",
+    "ashley",
+    "pepper",
+    "summer",
+    "winter",
+    "spring",
+    "autumn",
+    "computer",
+    "internet",
+    "cookie",
+    "flower",
+    "cheese",
+    "orange",
+    "banana",
+    "apple",
+    "chocolate",
+    "matrix",
+    "maggie",
+    "ginger",
+    "hannah",
+    "nicole",
+    "michelle",
+    "amanda",
+    "samsung",
+    "google",
+    "test",
+    "guest",
+    "user",
+    "root",
+    "changeme",
+    "default",
+    "mustang",
+    "access",
+    "liverpool",
+    "chelsea",
+    "arsenal",
+    "pokemon",
+    "naruto",
+    "minecraft",
+    "family",
+    "friends",
+    "forever",
+    "angel",
+    "lovely",
+    "baby",
+    "money",
+    "dolphin",
+    "butterfly",
+    "purple",
+    "yellow",
+    "silver",
+    "golden",
+    "diamond",
+    "tiger",
+    "eagle",
+    "jesus",
+    "heaven",
+    "killer",
+    "pass",
+    "word",
+    "secure",
+    "realworld",
+    "leptos",
+    "qazwsx",
+    "zaq",
+    "asdf",
+    "zxcv",
+    "super",
+    "star",
+    "sun",
+    "moon",
+    "blue",
+    "red",
+    "green",
+    "black",
+    "white",
+    "cat",
+    "dog",
+    "house",
+    "home",
+    "party",
+    "happy",
+    "smile",
+    "magic",
+    "music",
+    "rock",
+    "game",
+    "player",
+    "gamer",
+    "ninja",
+    "pirate",
+    "knight",
+    "wizard",
+    "dream",
+    "heart",
+    "queen",
+    "king",
+    "prince",
+    "boss",
+    "lucky",
+    "cool",
+    "sweet",
+    "honey",
+    "sugar",
+    "candy",
+    "coffee",
+    "pizza",
+];
+
+/// The rows of a qwerty keyboard, with the digits and their shifted symbols.
+const KEYBOARD_ROWS: &[&str] = &[
+    "`1234567890-=",
+    "~!@#$%^&*()_+",
+    "qwertyuiop[]\\",
+    "asdfghjkl;'",
+    "zxcvbnm,./",
+    "1qaz2wsx3edc4rfv5tgb6yhn7ujm8ik9ol0p",
+];
+
+/// What made a password weak, the feedback names the longest one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Weakness {
+    Common,
+    Personal,
+    Keyboard,
+    Sequence,
+    Repeat,
+    Year,
+}
+
+impl Weakness {
+    pub fn warning(self) -> &'static str {
+        match self {
+            Self::Common => "Common words and passwords are easy to guess",
+            Self::Personal => "Your username or email in the password is easy to guess",
+            Self::Keyboard => "Rows of keys like qwerty are easy to guess",
+            Self::Sequence => "Sequences like abc or 6543 are easy to guess",
+            Self::Repeat => "Repeats like aaa or abcabc are easy to guess",
+            Self::Year => "Years are easy to guess",
+        }
+    }
+}
+
+/// The estimate of a password.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Strength {
+    /// From 0, guessed in a thousand tries, to 4, beyond ten billion.
+    pub score: u8,
+    /// log10 of the guesses.
+    pub guesses: f64,
+    pub weakness: Option<Weakness>,
+}
+
+/// A part of the password matching a pattern, `log` is log10 of its guesses.
+#[derive(Clone, Copy)]
+struct Match {
+    start: usize,
+    end: usize,
+    log: f64,
+    weakness: Option<Weakness>,
+}
+
+/// Estimates `password`, `user_inputs` are the details of the user (username, email) an
+/// attacker would try first.
+pub fn estimate(password: &str, user_inputs: &[&str]) -> Strength {
+    let chars: Vec<char> = password.chars().collect();
+    if chars.is_empty() {
+        return Strength {
+            score: 0,
+            guesses: 0.0,
+            weakness: None,
+        };
+    }
+    let mut matches = Vec::new();
+    dictionary_matches(&chars, user_inputs, &mut matches);
+    keyboard_matches(&chars, &mut matches);
+    sequence_matches(&chars, &mut matches);
+    repeat_matches(&chars, &mut matches);
+    year_matches(&chars, &mut matches);
+
+    let (guesses, weakness) = cheapest(&chars, &matches);
+    let score = match guesses {
+        x if x < 3.0 => 0,
+        x if x < 6.0 => 1,
+        x if x < 8.0 => 2,
+        x if x < 10.0 => 3,
+        _ => 4,
+    };
+    Strength {
+        score,
+        guesses,
+        weakness,
+    }
+}
+
+/// Undoes the usual substitutions, `p@ssw0rd` is tried right after `password`.
+fn unleet(c: char) -> char {
+    match c {
+        '4' | '@' => 'a',
+        '8' => 'b',
+        '(' => 'c',
+        '3' => 'e',
+        '6' | '9' => 'g',
+        '1' | '!' | '|' => 'i',
+        '0' => 'o',
+        '$' | '5' => 's',
+        '7' | '+' => 't',
+        '2' => 'z',
+        c => c.to_ascii_lowercase(),
+    }
+}
+
+/// The guesses of the variants of a word: the capitals and the substitutions.
+fn variations(part: &[char]) -> f64 {
+    let mut log = 0.0;
+    let upper = part.iter().filter(|c| c.is_uppercase()).count();
+    if upper == part.len() || (upper == 1 && part[0].is_uppercase()) {
+        log += 2f64.log10();
+    } else if upper > 0 {
+        log += (part.len() as f64).log10() * upper.min(3) as f64;
+    }
+    if part.iter().any(|c| !c.is_alphabetic()) {
+        log += 2f64.log10();
+    }
+    log
+}
+
+fn dictionary_matches(chars: &[char], user_inputs: &[&str], matches: &mut Vec<Match>) {
+    let plain: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    let unleeted: Vec<char> = chars.iter().map(|&c| unleet(c)).collect();
+    // The local part of an email is what gets reused
+    let personal = user_inputs
+        .iter()
+        .map(|x| x.split('@').next().unwrap_or_default().to_lowercase())
+        .filter(|x| x.chars().count() >= 3);
+    let words = COMMON_WORDS
+        .iter()
+        .enumerate()
+        .map(|(rank, word)| (word.to_string(), (rank + 1) as f64, Weakness::Common))
+        .chain(personal.map(|x| (x, 1.0, Weakness::Personal)));
+    for (word, rank, weakness) in words {
+        let word: Vec<char> = word.chars().collect();
+        for start in 0..chars.len() {
+            let end = start + word.len();
+            if end > chars.len() {
+                break;
+            }
+            let found = plain[start..end] == word[..] || unleeted[start..end] == word[..];
+            let reversed = !found && plain[start..end].iter().rev().eq(word.iter());
+            if found || reversed {
+                let mut log = rank.log10() + variations(&chars[start..end]);
+                if reversed {
+                    log += 2f64.log10();
+                }
+                matches.push(Match {
+                    start,
+                    end,
+                    log,
+                    weakness: Some(weakness),
+                });
+            }
+        }
+    }
+}
+
+fn keyboard_matches(chars: &[char], matches: &mut Vec<Match>) {
+    let lower: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+    for row in KEYBOARD_ROWS {
+        let row: Vec<char> = row.chars().collect();
+        for start in 0..lower.len() {
+            let mut end = start;
+            // Along the row, one way or the other
+            while let Some(position) = row.iter().position(|&x| x == lower[end]) {
+                end += 1;
+                if end == lower.len() {
+                    break;
+                }
+                let next = lower[end];
+                let forward = row.get(position + 1) == Some(&next);
+                let backward = position > 0 && row[position - 1] == next;
+                if !forward && !backward {
+                    break;
+                }
+            }
+            if end - start >= 4 {
+                // The starting key and the length, both ways
+                let log = (2.0 * row.len() as f64 * (end - start) as f64).log10()
+                    + variations(&chars[start..end]).min(2f64.log10());
+                matches.push(Match {
+                    start,
+                    end,
+                    log,
+                    weakness: Some(Weakness::Keyboard),
+                });
+            }
+        }
+    }
+}
+
+fn sequence_matches(chars: &[char], matches: &mut Vec<Match>) {
+    let mut start = 0;
+    while start + 2 < chars.len() {
+        let delta = chars[start + 1] as i64 - chars[start] as i64;
+        let mut end = start + 1;
+        while end < chars.len() && chars[end] as i64 - chars[end - 1] as i64 == delta {
+            end += 1;
+        }
+        if end - start >= 3 && (delta == 1 || delta == -1) {
+            let base: f64 = match chars[start] {
+                'a' | 'z' | 'A' | 'Z' | '0' | '1' | '9' => 4.0,
+                c if c.is_ascii_digit() => 10.0,
+                c if c.is_ascii_alphabetic() => 26.0,
+                _ => 95.0,
+            };
+            matches.push(Match {
+                start,
+                end,
+                log: (base * 2.0 * (end - start) as f64).log10(),
+                weakness: Some(Weakness::Sequence),
+            });
+            start = end - 1;
+        } else {
+            start += 1;
+        }
+    }
+}
+
+fn repeat_matches(chars: &[char], matches: &mut Vec<Match>) {
+    for start in 0..chars.len() {
+        // The shortest unit repeated from here, `aaa` or `abcabc`
+        for unit in 1..=(chars.len() - start) / 2 {
+            let mut end = start + unit;
+            while end + unit <= chars.len() && chars[end..end + unit] == chars[start..start + unit]
+            {
+                end += unit;
+            }
+            let count = (end - start) / unit;
+            if count >= 2 && (unit > 1 || count >= 3) {
+                let (unit_log, _) = cheapest(&chars[start..start + unit], &[]);
+                matches.push(Match {
+                    start,
+                    end,
+                    log: unit_log + (count as f64).log10(),
+                    weakness: Some(Weakness::Repeat),
+                });
+                break;
+            }
+        }
+    }
+}
+
+fn year_matches(chars: &[char], matches: &mut Vec<Match>) {
+    for start in 0..chars.len().saturating_sub(3) {
+        let part: String = chars[start..start + 4].iter().collect();
+        if let Ok(year) = part.parse::<u32>() {
+            if (1900..=2099).contains(&year) {
+                matches.push(Match {
+                    start,
+                    end: start + 4,
+                    log: 200f64.log10(),
+                    weakness: Some(Weakness::Year),
+                });
+            }
+        }
+    }
+}
+
+/// The characters an attacker tries for each position in brute force.
+fn cardinality(chars: &[char]) -> f64 {
+    let mut cardinality = 0.0;
+    if chars.iter().any(|c| c.is_ascii_lowercase()) {
+        cardinality += 26.0;
+    }
+    if chars.iter().any(|c| c.is_ascii_uppercase()) {
+        cardinality += 26.0;
+    }
+    if chars.iter().any(|c| c.is_ascii_digit()) {
+        cardinality += 10.0;
+    }
+    if chars.iter().any(|c| c.is_ascii_punctuation() || *c == ' ') {
+        cardinality += 33.0;
+    }
+    if chars.iter().any(|c| !c.is_ascii()) {
+        cardinality += 100.0;
+    }
+    cardinality
+}
+
+/// The log10 guesses of a cut of the start of the password, with the length and weakness of
+/// its longest pattern.
+type Cut = (f64, Option<(usize, Weakness)>);
+
+/// The fewest guesses over the ways to cut the password into matches, brute force filling
+/// the gaps. Like zxcvbn, a cut in `k` parts costs `k!` more, the attacker doesn't know
+/// which patterns are used in which order.
+fn cheapest(chars: &[char], matches: &[Match]) -> (f64, Option<Weakness>) {
+    let length = chars.len();
+    let brute_force = cardinality(chars).log10();
+    // best[end][parts] = (log10 guesses, the longest pattern)
+    let mut best: Vec<Vec<Option<Cut>>> = vec![vec![None; length + 1]; length + 1];
+    best[0][0] = Some((0.0, None));
+    for end in 1..=length {
+        for parts in 1..=end {
+            let mut candidates: Vec<(usize, f64, Option<Weakness>)> = (0..end)
+                .map(|start| (start, brute_force * (end - start) as f64, None))
+                .collect();
+            candidates.extend(
+                matches
+                    .iter()
+                    .filter(|x| x.end == end)
+                    .map(|x| (x.start, x.log, x.weakness)),
+            );
+            for (start, log, weakness) in candidates {
+                let Some((before, longest)) = best[start][parts - 1] else {
+                    continue;
+                };
+                let total = before + log;
+                let longest = match (longest, weakness) {
+                    (Some((x, _)), Some(w)) if end - start > x => Some((end - start, w)),
+                    (None, Some(w)) => Some((end - start, w)),
+                    (x, _) => x,
+                };
+                if best[end][parts].is_none_or(|(x, _)| total < x) {
+                    best[end][parts] = Some((total, longest));
+                }
+            }
+        }
+    }
+    (1..=length)
+        .filter_map(|parts| {
+            best[length][parts].map(|(log, longest)| {
+                let factorial: f64 = (1..=parts).map(|x| (x as f64).log10()).sum();
+                (log + factorial, longest.map(|(_, w)| w))
+            })
+        })
+        .fold((f64::INFINITY, None), |a, b| if b.0 < a.0 { b } else { a })
+}
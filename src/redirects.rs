@@ -0,0 +1,39 @@
+//! Permanent redirects of the URLs which moved, like the profile of a renamed user.
+use axum::{
+    http::{header, Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+const PROFILE_PREFIX: &str = "/profile/";
+
+/// Sends `/profile/{old}` and everything below it, the feed for instance, to the current
+/// username. Links shared before the change keep working until someone else takes the name.
+pub async fn redirect_middleware(
+    req: Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> Response {
+    let Some(rest) = req.uri().path().strip_prefix(PROFILE_PREFIX) else {
+        return next.run(req).await;
+    };
+    let (username, tail) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    // The new username passed `validate_username`, it needs no encoding
+    match crate::models::User::renamed_to(username.to_string()).await {
+        Ok(Some(current)) => {
+            let mut location = format!("{PROFILE_PREFIX}{current}{tail}");
+            if let Some(query) = req.uri().query() {
+                location.push('?');
+                location.push_str(query);
+            }
+            (
+                StatusCode::MOVED_PERMANENTLY,
+                [(header::LOCATION, location)],
+            )
+                .into_response()
+        }
+        Ok(None) => next.run(req).await,
+        Err(x) => {
+            tracing::error!("problem while looking for a renamed user: {x:?}");
+            next.run(req).await
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use crate::app::{GlobalState, GlobalStateStoreFields};
+use crate::components::article_body::ArticleBody;
 use crate::components::article_preview::{ArticleMeta, ArticleSignal};
+use crate::components::co_authors::CoAuthors;
+use crate::components::seo::ArticleSeo;
+use crate::components::series::SeriesNavigator;
 use crate::components::user_icons::{AuthorUserIcon, CommentUserIcon, CurrentUserIcon};
 use crate::models::{Comment, User};
 use leptos::html::Textarea;
 use leptos::prelude::*;
-use leptos_meta::Title;
 use leptos_router::hooks::use_params_map;
 use reactive_stores::Store;
 
@@ -21,7 +24,9 @@ pub struct FollowUser(pub bool);
 #[tracing::instrument]
 pub async fn get_article(slug: String) -> Result<ArticleResult, ServerFnError> {
     Ok(ArticleResult {
-        article: crate::models::Article::for_article(slug)
+        article: crate::models::ArticleQuery::new()
+            .slug(slug)
+            .fetch_one()
             .await
             .map_err(|x| {
                 let err = format!("Error while getting user_profile articles: {x:?}");
@@ -41,11 +46,7 @@ pub fn Article(username: crate::auth::UsernameSignal) -> impl IntoView {
         |slug| async { get_article(slug).await },
     );
 
-    let title = RwSignal::new(String::from("Loading"));
-
     view! {
-        <Title text=move || title() />
-
         <Suspense fallback=move || view! { <p>"Loading Article"</p> }>
             <ErrorBoundary fallback=|_| {
                 view! {
@@ -59,8 +60,10 @@ pub fn Article(username: crate::auth::UsernameSignal) -> impl IntoView {
                         .get()
                         .map(move |x| {
                             x.map(move |article_result| {
-                                title.set(article_result.article.slug.to_string());
-                                view! { <ArticlePage username result=article_result /> }
+                                view! {
+                                    <ArticleSeo article=article_result.article.clone() />
+                                    <ArticlePage username result=article_result />
+                                }
                             })
                         })
                 }}
@@ -127,8 +130,10 @@ where
                             <BackToButton on_back_event is_top=true />
                         </div>
                     </div>
+                    <SeriesNavigator article=article_signal />
+                    <CoAuthors username article=article_signal />
                     <div class="mb-5">
-                        <p>{article_signal.get_untracked().body}</p>
+                        <ArticleBody body=article_signal.get_untracked().body.unwrap_or_default() />
                     </div>
                 </div>
                 <div class="mb-5 px-1 py-1">
@@ -173,14 +178,27 @@ pub async fn post_comment(slug: String, body: String) -> Result<(), ServerFnErro
         return Err(ServerFnError::ServerError("you must be logged in".into()));
     };
 
-    crate::models::Comment::insert(slug, logged_user, body)
+    crate::models::Comment::insert(slug.clone(), logged_user.clone(), body)
         .await
-        .map(|_| ())
         .map_err(|x| {
             let err = format!("Error while posting a comment: {x:?}");
             tracing::error!("{err}");
-            ServerFnError::ServerError("Could not post a comment, try again later".into())
-        })
+            ServerFnError::new("Could not post a comment, try again later")
+        })?;
+
+    crate::live::publish(
+        crate::live::Topic::Article(slug.clone()),
+        crate::live::LiveEvent::CommentsChanged {
+            article: slug.clone(),
+        },
+    );
+    crate::live::notify_author(
+        slug,
+        logged_user.clone(),
+        format!("{logged_user} commented on your article"),
+    )
+    .await;
+    Ok(())
 }
 
 #[server(GetCommentsAction, "/api", "GetJson")]
@@ -202,11 +220,18 @@ pub async fn delete_comment(id: i32) -> Result<(), ServerFnError> {
 
     crate::models::Comment::delete(id, logged_user)
         .await
-        .map(|_| ())
+        .map(|slug| {
+            if let Some(slug) = slug {
+                crate::live::publish(
+                    crate::live::Topic::Article(slug.clone()),
+                    crate::live::LiveEvent::CommentsChanged { article: slug },
+                );
+            }
+        })
         .map_err(|x| {
-            let err = format!("Error while posting a comment: {x:?}");
+            let err = format!("Error while deleting a comment: {x:?}");
             tracing::error!("{err}");
-            ServerFnError::ServerError("Could not post a comment, try again later".into())
+            ServerFnError::ServerError("Could not delete the comment, try again later".into())
         })
 }
 
@@ -241,6 +266,23 @@ pub fn CommentSection(
         },
     );
 
+    crate::live::use_live_events(
+        format!(
+            "/live/article/{}",
+            article.with_untracked(|a| a.slug.to_string())
+        ),
+        move |event| match event {
+            crate::live::LiveEvent::CommentsChanged { .. } => comments.refetch(),
+            crate::live::LiveEvent::FavouritesChanged { count, by, .. } => {
+                // our own favourites are already counted by ButtonFav
+                if username.get_untracked().as_deref() != Some(by.as_str()) {
+                    article.update(|a| a.favorites_count = count);
+                }
+            }
+            crate::live::LiveEvent::Notification { .. } => (),
+        },
+    );
+
     let post_button_disable =
         move || comment_value.get().is_empty() || comment_value.get().len() < 3;
 
@@ -1,7 +1,10 @@
 use crate::app::{GlobalState, GlobalStateStoreFields};
+use crate::components::image_upload::ImageUpload;
+use crate::components::seo::PageMeta;
+use crate::components::tag_input::TagInput;
 use crate::routes::article_modal::ArticleResult;
+use crate::uploads::UploadedFile;
 use leptos::prelude::*;
-use leptos_meta::*;
 use leptos_router::hooks::use_params_map;
 use reactive_stores::Store;
 
@@ -18,7 +21,20 @@ struct ArticleUpdate {
     title: String,
     description: String,
     body: String,
-    tag_list: std::collections::HashSet<String>,
+    tag_list: std::collections::HashSet<crate::models::Tag>,
+    series: crate::models::SeriesChoice,
+    series_position: Option<i64>,
+}
+
+/// The series fields of the editor, as typed.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, Default)]
+pub struct SeriesInput {
+    /// Id of an existing series of the author, empty for none
+    series: String,
+    /// Title of a new series, used instead of `series` when not empty
+    new_series: String,
+    /// Part of the series, empty to keep the current one or to add it at the end
+    position: String,
 }
 
 const TITLE_MIN_LENGTH: usize = 4;
@@ -31,7 +47,8 @@ fn validate_article(
     title: String,
     description: String,
     body: String,
-    tag_list: String,
+    tag_list: Vec<String>,
+    series: SeriesInput,
 ) -> Result<ArticleUpdate, String> {
     if title.len() < TITLE_MIN_LENGTH {
         return Err("You need to provide a title with at least 4 characters".into());
@@ -46,16 +63,44 @@ fn validate_article(
     }
 
     let tag_list = tag_list
-        .trim()
-        .split_ascii_whitespace()
-        .filter(|x| !x.is_empty())
-        .map(str::to_string)
-        .collect::<std::collections::HashSet<String>>();
+        .iter()
+        .filter_map(|x| crate::models::Tag::parse(x))
+        .collect::<std::collections::HashSet<_>>();
+
+    let new_series = series.new_series.trim();
+    let series_position = series.position;
+    let series = series.series;
+    let series = if !new_series.is_empty() {
+        if new_series.len() < TITLE_MIN_LENGTH {
+            return Err("You need to provide a series title with at least 4 characters".into());
+        }
+        crate::models::SeriesChoice::New(new_series.to_string())
+    } else if series.is_empty() {
+        crate::models::SeriesChoice::None
+    } else {
+        crate::models::SeriesChoice::Existing(
+            series
+                .parse()
+                .map_err(|_| "The selected series is invalid".to_string())?,
+        )
+    };
+    let series_position = match series_position.trim() {
+        "" => None,
+        x => Some(
+            x.parse::<i64>()
+                .ok()
+                .filter(|x| *x > 0)
+                .ok_or("The part of the series must be a positive number")?,
+        ),
+    };
+
     Ok(ArticleUpdate {
         title,
         description,
         body,
         tag_list,
+        series,
+        series_position,
     })
 }
 
@@ -67,11 +112,14 @@ async fn update_article(
     article: ArticleUpdate,
 ) -> Result<String, sqlx::Error> {
     static BIND_LIMIT: usize = 65535;
-    let mut transaction = crate::database::get_db().begin().await?;
+    let mut transaction = crate::database::begin_write().await?;
     let (rows_affected, slug) = if !slug.is_empty() {
         (
             sqlx::query!(
-                "UPDATE Articles SET title=$1, description=$2, body=$3 WHERE slug=$4 and author=$5",
+                "UPDATE Articles SET title=$1, description=$2, body=$3, updated_at=CURRENT_TIMESTAMP
+WHERE slug=$4 and EXISTS(
+    SELECT 1 FROM ArticleAuthors WHERE article=$4 and username=$5 and accepted_at IS NOT NULL
+)",
                 article.title,
                 article.description,
                 article.body,
@@ -110,23 +158,53 @@ async fn update_article(
         qb.push_values(
             article.tag_list.clone().into_iter().take(BIND_LIMIT / 2),
             |mut b, tag| {
-                b.push_bind(slug.clone()).push_bind(tag);
+                b.push_bind(slug.clone()).push_bind(String::from(tag));
             },
         );
         qb.build().execute(transaction.as_mut()).await?;
+        crate::models::TagAlias::resolve_article_tags(transaction.as_mut(), &slug).await?;
+    }
+    // The series belong to the owner, the co-authors cannot move the article
+    let owner = sqlx::query_scalar!("SELECT author FROM Articles WHERE slug=$1", slug)
+        .fetch_one(transaction.as_mut())
+        .await?;
+    if owner == author {
+        crate::models::Series::set_article(
+            transaction.as_mut(),
+            &author,
+            &slug,
+            article.series,
+            article.series_position,
+        )
+        .await?;
     }
 
     transaction.commit().await?;
     Ok(slug)
 }
 
+#[server(AuthorSeriesAction, "/api", "GetJson")]
+#[tracing::instrument]
+pub async fn author_series() -> Result<Vec<crate::models::Series>, ServerFnError> {
+    let Some(author) = crate::auth::get_username() else {
+        return Ok(vec![]);
+    };
+    crate::models::Series::for_author(author)
+        .await
+        .map_err(|x| {
+            tracing::error!("problem while fetching the series: {x:?}");
+            ServerFnError::new("Problem while fetching the series")
+        })
+}
+
 #[server(EditorAction, "/api")]
 #[tracing::instrument]
 pub async fn editor_action(
     title: String,
     description: String,
     body: String,
-    tag_list: String,
+    tag_list: Vec<String>,
+    series: SeriesInput,
     slug: String,
 ) -> Result<EditorResponse, ServerFnError> {
     let Some(author) = crate::auth::get_username() else {
@@ -135,7 +213,7 @@ pub async fn editor_action(
             "you should be authenticated".to_string(),
         ));
     };
-    let article = match validate_article(title, description, body, tag_list) {
+    let article = match validate_article(title, description, body, tag_list, series) {
         Ok(x) => x,
         Err(x) => return Ok(EditorResponse::ValidationError(x)),
     };
@@ -234,26 +312,77 @@ where
     let editor_title: NodeRef<leptos::html::Input> = NodeRef::new();
     let editor_desc: NodeRef<leptos::html::Input> = NodeRef::new();
     let editor_body: NodeRef<leptos::html::Textarea> = NodeRef::new();
-    let editor_tags: NodeRef<leptos::html::Input> = NodeRef::new();
+    let tags = RwSignal::new(Vec::<crate::models::Tag>::new());
     let editor_slug: NodeRef<leptos::html::Input> = NodeRef::new();
+    let editor_series: NodeRef<leptos::html::Select> = NodeRef::new();
+    let editor_new_series: NodeRef<leptos::html::Input> = NodeRef::new();
+    let editor_series_position: NodeRef<leptos::html::Input> = NodeRef::new();
+    let insert_image = move |image: UploadedFile| {
+        let body = editor_body.get().expect("<textarea> to exist");
+        let text = body.value();
+        let separator = if text.is_empty() || text.ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        body.set_value(&format!("{text}{separator}![image]({})\n", image.url));
+    };
+    let author_series = OnceResource::new(author_series());
+    // Only the owner can manage the series, the co-authors keep the current one
+    let owns_article = move || {
+        article_res
+            .get()
+            .and_then(Result::ok)
+            .map(|a| {
+                a.article.slug.is_empty()
+                    || a.logged_user.map(|u| u.username()) == Some(a.article.author.username)
+            })
+            .unwrap_or(true)
+    };
+    Effect::new(move || {
+        if let Some(Ok(a)) = article_res.get() {
+            tags.set(a.article.tag_list);
+        }
+    });
+    let article_series = move || {
+        article_res
+            .get()
+            .and_then(Result::ok)
+            .and_then(|a| a.article.series)
+    };
 
     let on_in_event = move |_| {
         let title = editor_title.get().expect("<input> to exist").value();
         let desc = editor_desc.get().expect("<input> to exist").value();
         let body = editor_body.get().expect("<textarea> to exist").value();
-        let tags = editor_tags.get().expect("<input> to exist").value();
         let slug = editor_slug.get().expect("<input> to exist").value();
+        let series = editor_series.get().expect("<select> to exist").value();
+        let new_series = editor_new_series.get().expect("<input> to exist").value();
+        let series_position = editor_series_position
+            .get()
+            .expect("<input> to exist")
+            .value();
 
         on_in(EditorAction {
             title: title.clone(),
             description: desc,
             body,
-            tag_list: tags,
+            tag_list: tags.get_untracked().into_iter().map(String::from).collect(),
+            series: SeriesInput {
+                series,
+                new_series,
+                position: series_position,
+            },
             slug,
         })
     };
     view! {
-        <Title text="Editor" />
+        <PageMeta
+            title="Editor".to_string()
+            description="Write or edit an article".to_string()
+            path="/editor".to_string()
+            robots="noindex, nofollow"
+        />
         <div class="fixed inset-0 z-50 flex items-center justify-center bg-gray-900 bg-opacity-60">
             <div class="block rounded-lg bg-white w-2/5 p-4 shadow-[0_2px_15px_-3px_rgba(0,0,0,0.07),0_10px_20px_-2px_rgba(0,0,0,0.04)] z-70">
                 <div>
@@ -338,22 +467,52 @@ where
                                                         .unwrap_or_default()
                                                 }
                                             ></textarea>
+                                            <ImageUpload label=" Insert an image" on_upload=insert_image />
                                         </div>
                                         <div class="mb-5">
+                                            <TagInput tags />
+                                        </div>
+                                        <div class="flex gap-2 mb-5" class:hidden=move || !owns_article()>
+                                            <select node_ref=editor_series class="input-field-common">
+                                                <option value="">"Not part of a series"</option>
+                                                {move || {
+                                                    let selected = article_series().map(|x| x.id);
+                                                    author_series
+                                                        .get()
+                                                        .and_then(Result::ok)
+                                                        .unwrap_or_default()
+                                                        .into_iter()
+                                                        .map(|x| {
+                                                            view! {
+                                                                <option
+                                                                    value=x.id.to_string()
+                                                                    selected=selected == Some(x.id)
+                                                                >
+                                                                    {x.title}
+                                                                </option>
+                                                            }
+                                                        })
+                                                        .collect_view()
+                                                }}
+                                            </select>
                                             <input
-                                                node_ref=editor_tags
-                                                name="tag_list"
+                                                node_ref=editor_new_series
+                                                name="new_series"
                                                 type="text"
                                                 class="input-field-common"
-                                                placeholder="Enter tags(space separated)"
+                                                placeholder="or start a new series"
+                                            />
+                                            <input
+                                                node_ref=editor_series_position
+                                                name="series_position"
+                                                type="number"
+                                                min="1"
+                                                class="input-field-common w-24"
+                                                placeholder="Part"
                                                 value=move || {
-                                                    article_res
-                                                        .get()
-                                                        .map(move |x| {
-                                                            x.map(move |a| { a.article.tag_list }).unwrap_or(Vec::new())
-                                                        })
+                                                    article_series()
+                                                        .map(|x| x.position.to_string())
                                                         .unwrap_or_default()
-                                                        .join(" ")
                                                 }
                                             />
                                         </div>
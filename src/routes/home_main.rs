@@ -1,7 +1,6 @@
 use crate::routes::profile_home::Profile;
 use crate::routes::user_home::HomePage;
 use leptos::prelude::*;
-use leptos_meta::*;
 
 /// Renders the home page of your application.
 #[component]
@@ -21,7 +20,6 @@ pub fn HomeMain(username: crate::auth::UsernameSignal, user_profile: bool) -> im
                 }
             >
                 <Suspense fallback=move || view! { <p>"Loading..."</p> }>
-                    <Title text="Home" />
                     <HomePage username />
                 </Suspense>
             </Show>
@@ -1,6 +1,6 @@
 use crate::auth::LoginAction;
+use crate::components::seo::PageMeta;
 use leptos::{html::Input, prelude::*};
-use leptos_meta::*;
 
 #[component]
 pub fn LoginForm(login: ServerAction<LoginAction>) -> impl IntoView {
@@ -80,7 +80,12 @@ where
     let (passwd_visible, set_passwd_visible) = signal(false);
 
     view! {
-        <Title text="Login" />
+        <PageMeta
+            title="Login".to_string()
+            description="Login to your account".to_string()
+            path="/login".to_string()
+            robots="noindex, nofollow"
+        />
         <div class="fixed inset-0 z-50 flex items-center justify-center bg-gray-900 bg-opacity-60">
             <div class="block rounded-lg bg-white w-2/5 p-4 shadow-[0_2px_15px_-3px_rgba(0,0,0,0.07),0_10px_20px_-2px_rgba(0,0,0,0.04)] z-70">
                 <h5 class="mb-5 text-xl font-medium leading-tight text-neutral-800">
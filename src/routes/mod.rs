@@ -6,4 +6,5 @@ pub mod profile_home;
 pub mod reset_password_modal;
 pub mod settings_modal;
 pub mod signup_modal;
+pub mod tags_home;
 pub mod user_home;
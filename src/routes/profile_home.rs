@@ -1,11 +1,10 @@
 use crate::app::{GlobalState, GlobalStateStoreFields};
 use crate::components::{
     article_preview::ArticlePreviewList, items_per_page::ItemsPerPage,
-    prev_next_buttons::PreviousNextButton,
+    prev_next_buttons::PreviousNextButton, seo::PageMeta,
 };
 use crate::models::Pagination;
 use leptos::prelude::*;
-use leptos_meta::*;
 use leptos_router::{
     hooks::{use_params_map, use_query, use_query_map},
     params::ParamsError,
@@ -17,24 +16,59 @@ use reactive_stores::Store;
 pub async fn profile_articles(
     username: String,
     favourites: Option<bool>,
+    bookmarks: Option<bool>,
     page: u32,
     amount: u32,
-) -> Result<Vec<crate::models::Article>, ServerFnError> {
+    after: Option<String>,
+    before: Option<String>,
+) -> Result<crate::models::ArticleFeed, ServerFnError> {
+    let start = crate::models::PageStart::new(page, amount, after, before).map_err(|x| {
+        tracing::debug!("{x}");
+        ServerFnError::new("The page is not valid anymore, go back to the first one")
+    })?;
     let page = i64::from(page);
     let amount = i64::from(amount);
 
-    crate::models::Article::for_user_profile_home(
-        username,
-        favourites.unwrap_or_default(),
-        page,
-        amount,
-    )
-    .await
-    .map_err(|x| {
-        let err = format!("Error while getting user_profile articles: {x:?}");
-        tracing::error!("{err}");
-        ServerFnError::ServerError("Could not retrieve articles, try again later".into())
-    })
+    if bookmarks.unwrap_or_default() {
+        // The reading list is private
+        if crate::auth::get_username().as_ref() != Some(&username) {
+            return Err(ServerFnError::ServerError(
+                "You can only see your own reading list".into(),
+            ));
+        }
+        // The reading list is sorted by its reading state, it keeps the page numbers
+        let query = crate::models::ArticleQuery::new()
+            .bookmarked_by(username)
+            .sort(crate::models::ArticleSort::ReadingList);
+        return tokio::try_join!(
+            query.fetch_page(crate::models::PageStart::Offset(page * amount), amount),
+            query.count(),
+        )
+        .map(|(feed, total)| crate::models::ArticleFeed {
+            articles: feed.articles,
+            total,
+            ..Default::default()
+        })
+        .map_err(|x| {
+            tracing::error!("Error while getting the reading list: {x:?}");
+            ServerFnError::ServerError("Could not retrieve articles, try again later".into())
+        });
+    }
+
+    let favourites = favourites.unwrap_or_default();
+    let query = if favourites {
+        crate::models::ArticleQuery::new().favourited_by(username)
+    } else {
+        crate::models::ArticleQuery::new().author(username)
+    };
+    let (mut feed, total) = tokio::try_join!(query.fetch_page(start, amount), query.count())
+        .map_err(|x| {
+            let err = format!("Error while getting user_profile articles: {x:?}");
+            tracing::error!("{err}");
+            ServerFnError::new("Could not retrieve articles, try again later")
+        })?;
+    feed.total = total;
+    Ok(feed)
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
@@ -97,7 +131,12 @@ pub fn Profile(username: crate::auth::UsernameSignal) -> impl IntoView {
     };
 
     view! {
-        <Title text=move || format!("{}'s profile", route_user()) />
+        <PageMeta
+            title=Signal::derive(move || format!("{}'s profile", route_user()))
+            description=Signal::derive(move || format!("Articles written by {}", route_user()))
+            path=Signal::derive(move || format!("/profile/{}", route_user()))
+            kind="profile"
+        />
         <div>
             <ProfileHome on_back_event username route_user />
         </div>
@@ -122,17 +161,30 @@ where
     // let route_user = move || params.with_untracked(|x| x.get("user").unwrap_or_default());
     let query = use_query_map();
     let favourite = move || query.with(|x| x.get("favourites").map(|_| true));
+    let bookmarks = move || query.with(|x| x.get("bookmarks").map(|_| true));
 
     let articles = Resource::new(
         move || {
             (
                 favourite(),
+                bookmarks(),
                 route_user(),
-                pagination.get().unwrap_or_default().get_page(),
+                pagination.get().unwrap_or_default(),
                 per_page.get().unwrap(),
             )
         },
-        move |(fav, user, page, amount)| async move { profile_articles(user, fav, page, amount).await },
+        move |(fav, bookmarks, user, pagination, amount)| async move {
+            profile_articles(
+                user,
+                fav,
+                bookmarks,
+                pagination.get_page(),
+                amount,
+                pagination.get_after(),
+                pagination.get_before(),
+            )
+            .await
+        },
     );
 
     let global_state = expect_context::<Store<GlobalState>>();
@@ -144,8 +196,11 @@ where
             <div class="mb-5">
                 <div class="flex justify-between px-2 bg-gray-200">
                     <div class="flex">
-                        <UserArticlesTab favourite route_user pagination />
+                        <UserArticlesTab favourite bookmarks route_user pagination />
                         <FavouritedArticlesTab favourite route_user pagination />
+                        <Show when=move || username.get().as_ref() == Some(&route_user())>
+                            <BookmarksTab bookmarks route_user pagination />
+                        </Show>
                     </div>
                     <ItemsPerPage />
                 </div>
@@ -165,14 +220,16 @@ where
 }
 
 #[component]
-fn UserArticlesTab<A, B>(
+fn UserArticlesTab<A, B, C>(
     favourite: A,
+    bookmarks: C,
     route_user: B,
     pagination: Memo<Result<Pagination, ParamsError>>,
 ) -> impl IntoView
 where
     A: Fn() -> Option<bool> + 'static + Send,
     B: Fn() -> String + 'static + Send + Copy,
+    C: Fn() -> Option<bool> + 'static + Send,
 {
     let per_page: RwSignal<Option<u32>> =
         use_context().expect("per_page context should be available");
@@ -185,7 +242,7 @@ where
                 class=move || {
                     format!(
                         "font-bold {}",
-                        if !favourite().unwrap_or_default() {
+                        if !favourite().unwrap_or_default() && !bookmarks().unwrap_or_default() {
                             "border-b-8"
                         } else {
                             "cursor-pointer"
@@ -266,6 +323,57 @@ where
     }
 }
 
+#[component]
+fn BookmarksTab<A, B>(
+    bookmarks: A,
+    route_user: B,
+    pagination: Memo<Result<Pagination, ParamsError>>,
+) -> impl IntoView
+where
+    A: Fn() -> Option<bool> + 'static + Send,
+    B: Fn() -> String + 'static + Send + Copy,
+{
+    let per_page: RwSignal<Option<u32>> =
+        use_context().expect("per_page context should be available");
+    let global_state = expect_context::<Store<GlobalState>>();
+
+    view! {
+        <div class="mb-5 px-2">
+            <button
+                type="button"
+                class=move || {
+                    format!(
+                        "font-bold {}",
+                        if bookmarks().unwrap_or_default() {
+                            "border-b-8"
+                        } else {
+                            "cursor-pointer"
+                        },
+                    )
+                }
+                on:click=move |_| {
+                    let navigate = leptos_router::hooks::use_navigate();
+                    let bookmarks_url = format!(
+                        "/profile/{}{}{}",
+                        route_user(),
+                        pagination
+                            .get()
+                            .unwrap_or_default()
+                            .reset_page()
+                            .set_amount(per_page.get().unwrap()),
+                        "&bookmarks=true",
+                    );
+                    global_state.back_url().set(bookmarks_url.clone());
+                    navigate(&bookmarks_url, Default::default())
+                }
+            >
+                <i class="fa-solid fa-lock w-4 h-4"></i>
+                " Read Later"
+            </button>
+        </div>
+    }
+}
+
 #[component]
 pub fn UserInfo<C>(on_back_event: C) -> impl IntoView
 where
@@ -292,7 +400,7 @@ where
                             .get()
                             .map(move |x| {
                                 x.map(move |u| {
-                                    let image = u.user.image();
+                                    let image = u.user.avatar();
                                     let username = u.user.username();
                                     let bio = u.user.bio();
                                     let email = format!(
@@ -1,22 +1,11 @@
 use crate::app::{GlobalState, GlobalStateStoreFields};
 use crate::auth::LogoutAction;
+use crate::components::password_strength::PasswordStrength;
+use crate::components::seo::PageMeta;
 use leptos::html::Input;
 use leptos::prelude::*;
-use leptos_meta::*;
 use leptos_router::{hooks::use_query, params::Params};
 use reactive_stores::Store;
-use std::env;
-
-#[allow(dead_code)]
-#[cfg(feature = "ssr")]
-struct EmailCredentials {
-    email: String,
-    passwd: String,
-    smtp_server: String,
-}
-
-#[cfg(feature = "ssr")]
-static EMAIL_CREDS: std::sync::OnceLock<EmailCredentials> = std::sync::OnceLock::new();
 
 #[tracing::instrument]
 #[server(ResetPasswordAction1, "/api")]
@@ -26,23 +15,19 @@ pub async fn reset_password_1(email: String) -> Result<String, ServerFnError> {
         tracing::error!("{err} {x:?} ");
         return Err(ServerFnError::new(err));
     } else {
-        let creds = EMAIL_CREDS.get_or_init(|| EmailCredentials {
-            email: env::var("MAILER_EMAIL").unwrap(),
-            passwd: env::var("MAILER_PASSWD").unwrap(),
-            smtp_server: env::var("MAILER_SMTP_SERVER").unwrap(),
-        });
-        let host = leptos_axum::extract::<axum_extra::extract::Host>().await?.0;
-        let schema = if cfg!(debug_assertions) {
-            "http"
-        } else {
-            "https"
+        let Some(creds) = crate::mailer::credentials() else {
+            tracing::error!("the mailer is not configured");
+            return Err(ServerFnError::new(
+                "Password reset emails are not available",
+            ));
         };
+        let config = crate::config::use_config();
         let token = crate::auth::encode_token(crate::auth::TokenClaims {
             sub: email.clone(),
-            exp: (sqlx::types::chrono::Utc::now().timestamp() as usize) + 3_600,
+            exp: crate::config::expires_in(config.auth.reset_token_lifetime),
         })
         .unwrap();
-        let uri = format!("{}://{}/reset_password?token={}", schema, host, token);
+        let uri = format!("{}/reset_password?token={}", config.base_url, token);
         // Build a simple multipart message
         let message = mail_send::mail_builder::MessageBuilder::new()
             .from(("Realworld Leptos", creds.email.as_str()))
@@ -60,13 +45,7 @@ pub async fn reset_password_1(email: String) -> Result<String, ServerFnError> {
         // if smtp available, then uncomment below mail send part. Else use above logging to get a reset link to test
         // Incorrect smtp may cause the thread to panic after multiple attempts
 
-        // mail_send::SmtpClientBuilder::new(creds.smtp_server.as_str(), 587)
-        //     .implicit_tls(false)
-        //     .credentials((creds.email.as_str(), creds.passwd.as_str()))
-        //     .connect()
-        //     .await?
-        //     .send(message)
-        //     .await?
+        // crate::mailer::send(message).await?
     }
     return Ok(String::from(
         "Email sent. Check email and click the reset url link inside.",
@@ -314,7 +293,12 @@ where
     let (passwd_visible, set_passwd_visible) = signal(false);
 
     view! {
-        <Title text="Reset Password" />
+        <PageMeta
+            title="Reset Password".to_string()
+            description="Reset the password of your account".to_string()
+            path="/reset_password".to_string()
+            robots="noindex, nofollow"
+        />
         <div class="fixed inset-0 z-50 flex items-center justify-center bg-gray-900 bg-opacity-60">
 
             <div class="block rounded-lg bg-white w-2/5 p-4 shadow-[0_2px_15px_-3px_rgba(0,0,0,0.07),0_10px_20px_-2px_rgba(0,0,0,0.04)] z-70">
@@ -366,6 +350,7 @@ where
                                         }></i>
                                     </span>
                                 </div>
+                                <PasswordStrength password=new_password_value />
                                 <div class="mb-5 relative">
                                     <input
                                         node_ref=user_confirm_password
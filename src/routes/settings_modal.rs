@@ -3,12 +3,15 @@ use leptos::{
     html::{Input, Textarea},
     prelude::*,
 };
-use leptos_meta::*;
 use reactive_stores::Store;
 
 use serde::{Deserialize, Serialize};
 
 use crate::auth::LogoutAction;
+use crate::components::image_upload::ImageUpload;
+use crate::components::password_strength::PasswordStrength;
+use crate::components::seo::PageMeta;
+use crate::uploads::UploadedFile;
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub enum SettingsUpdateError {
@@ -54,21 +57,24 @@ fn update_user_validation(
     password: String,
     confirm_password: &str,
 ) -> Result<crate::models::User, SettingsUpdateError> {
-    if !password.is_empty() {
-        if password != confirm_password {
-            return Err(SettingsUpdateError::PasswordsNotMatch);
-        }
-        user = user
-            .set_password(password)
-            .map_err(SettingsUpdateError::ValidationError)?;
+    if !password.is_empty() && password != confirm_password {
+        return Err(SettingsUpdateError::PasswordsNotMatch);
     }
 
-    user.set_email(email)
+    user = user
+        .set_email(email)
         .map_err(SettingsUpdateError::ValidationError)?
         .set_bio(bio)
         .map_err(SettingsUpdateError::ValidationError)?
         .set_image(image)
-        .map_err(SettingsUpdateError::ValidationError)
+        .map_err(SettingsUpdateError::ValidationError)?;
+    // Checked with the new email
+    if !password.is_empty() {
+        user = user
+            .set_password(password)
+            .map_err(SettingsUpdateError::ValidationError)?;
+    }
+    Ok(user)
 }
 
 #[cfg(feature = "ssr")]
@@ -93,6 +99,119 @@ pub async fn settings_get() -> Result<crate::models::User, ServerFnError> {
     get_user().await
 }
 
+#[tracing::instrument]
+#[server(DigestGetAction, "/api", "GetJson")]
+pub async fn digest_get() -> Result<crate::models::DigestFrequency, ServerFnError> {
+    let user = get_user().await?;
+    crate::models::DigestSubscription::get_frequency(user.username())
+        .await
+        .map_err(|x| {
+            tracing::error!("problem while getting the digest frequency: {x:?}");
+            ServerFnError::ServerError("Problem while getting the digest settings".into())
+        })
+}
+
+#[tracing::instrument]
+#[server(DigestUpdateAction, "/api")]
+pub async fn digest_update(frequency: crate::models::DigestFrequency) -> Result<(), ServerFnError> {
+    let user = get_user().await?;
+    crate::models::DigestSubscription::set_frequency(user.username(), frequency)
+        .await
+        .map(|_| ())
+        .map_err(|x| {
+            tracing::error!("problem while updating the digest frequency: {x:?}");
+            ServerFnError::ServerError("Problem while updating the digest settings".into())
+        })
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub enum UsernameChangeResponse {
+    Changed(String),
+    ValidationError(String),
+    Taken,
+    /// The date of the next possible change
+    TooSoon(String),
+}
+
+#[tracing::instrument]
+#[server(UsernameChangeAction, "/api")]
+pub async fn change_username(username: String) -> Result<UsernameChangeResponse, ServerFnError> {
+    let user = get_user().await?;
+    let username = username.trim().to_string();
+    if username == user.username() {
+        return Ok(UsernameChangeResponse::Changed(username));
+    }
+    if let Err(x) = crate::models::validate_username(&username) {
+        return Ok(UsernameChangeResponse::ValidationError(x));
+    }
+    let auth = &crate::config::use_config().auth;
+    let rename = crate::models::User::rename(
+        user.username(),
+        username.clone(),
+        auth.username_change_cooldown,
+    )
+    .await
+    .map_err(|x| {
+        tracing::error!("problem while changing the username: {x:?}");
+        ServerFnError::new("Problem while changing the username")
+    })?;
+    Ok(match rename {
+        crate::models::Rename::Renamed => {
+            // The token is issued for the username, the current one points to nobody now
+            crate::auth::set_username(username.clone()).await;
+            UsernameChangeResponse::Changed(username)
+        }
+        crate::models::Rename::Taken => UsernameChangeResponse::Taken,
+        crate::models::Rename::TooSoon(x) => {
+            UsernameChangeResponse::TooSoon(x.format("%Y-%m-%d %H:%M UTC").to_string())
+        }
+    })
+}
+
+/// What becomes of the articles and comments of a deleted account.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeletedContent {
+    /// Kept under the "deleted user" placeholder
+    #[default]
+    Anonymise,
+    Delete,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub enum DeleteAccountResponse {
+    WrongPassword,
+    Deleted,
+}
+
+#[tracing::instrument(skip(password))]
+#[server(DeleteAccountAction, "/api")]
+pub async fn delete_account(
+    password: String,
+    content: DeletedContent,
+) -> Result<DeleteAccountResponse, ServerFnError> {
+    let user = get_user().await?;
+    if !user.verify_password(&password) {
+        return Ok(DeleteAccountResponse::WrongPassword);
+    }
+    let username = user.username();
+    let result = match content {
+        DeletedContent::Anonymise => crate::models::User::anonymise(username.clone()).await,
+        DeletedContent::Delete => crate::models::User::delete(username.clone()).await,
+    };
+    result.map_err(|x| {
+        tracing::error!("problem while deleting the account {username}: {x:?}");
+        ServerFnError::new("Problem while deleting the account")
+    })?;
+    let response_options = use_context::<leptos_axum::ResponseOptions>().unwrap();
+    response_options.insert_header(
+        axum::http::header::SET_COOKIE,
+        axum::http::HeaderValue::from_str(crate::auth::REMOVE_COOKIE)
+            .expect("header value couldn't be set"),
+    );
+    Ok(DeleteAccountResponse::Deleted)
+}
+
 // #[derive(Debug, Default, Deserialize, Serialize, Clone)]
 // pub struct UserGet {
 //     username: String,
@@ -102,7 +221,10 @@ pub async fn settings_get() -> Result<crate::models::User, ServerFnError> {
 // }
 
 #[component]
-pub fn Settings(logout: ServerAction<LogoutAction>) -> impl IntoView {
+pub fn Settings(
+    logout: ServerAction<LogoutAction>,
+    username: crate::auth::UsernameSignal,
+) -> impl IntoView {
     let show_modal: RwSignal<bool> = use_context().expect("show_modal context should be available");
     show_modal.set(true);
 
@@ -174,6 +296,8 @@ pub fn Settings(logout: ServerAction<LogoutAction>) -> impl IntoView {
                                 x.map(move |user| {
                                     view! {
                                         <SettingsModal
+                                            logout
+                                            username
                                             on_in=settings_update_event
                                             on_in_logout=settings_update_logout_event
                                             on_cancel=on_cancel_event
@@ -192,6 +316,8 @@ pub fn Settings(logout: ServerAction<LogoutAction>) -> impl IntoView {
 
 #[component]
 fn SettingsModal<A, B, C>(
+    logout: ServerAction<LogoutAction>,
+    username: crate::auth::UsernameSignal,
     on_in: A,
     on_in_logout: B,
     on_cancel: C,
@@ -204,7 +330,6 @@ where
     C: Fn() + 'static + Send,
 {
     let user_profile_pic_url: NodeRef<Input> = NodeRef::new();
-    let user_name: NodeRef<Input> = NodeRef::new();
     let user_bio: NodeRef<Textarea> = NodeRef::new();
     let user_email: NodeRef<Input> = NodeRef::new();
     let user_new_password: NodeRef<Input> = NodeRef::new();
@@ -225,6 +350,15 @@ where
         set_profile_pic_url_value(event_target_value(&ev));
         no_profile_url_input_yet.set(false);
     };
+    // Avatars are small, the thumbnail is enough
+    let on_profile_pic_upload = move |image: UploadedFile| {
+        user_profile_pic_url
+            .get()
+            .expect("profile pic url <input> to exist")
+            .set_value(&image.thumbnail);
+        set_profile_pic_url_value(image.thumbnail);
+        no_profile_url_input_yet.set(false);
+    };
     let on_bio_input = move |ev| {
         set_bio_value(event_target_value(&ev));
         no_bio_input_yet.set(false);
@@ -234,6 +368,16 @@ where
         no_email_input_yet.set(false);
     };
 
+    // The email being typed, or the saved one
+    let user_inputs = Signal::derive(move || {
+        let email = if no_email_input_yet.get() {
+            prev_data.with(|x| x.email())
+        } else {
+            email_value.get()
+        };
+        vec![prev_data.with(|x| x.username()), email]
+    });
+
     let passwords_dont_match = move || {
         !(new_password_value.get().is_empty() && confirm_password_value.get().is_empty())
             && new_password_value.get() != confirm_password_value.get()
@@ -410,13 +554,19 @@ where
     let (passwd_visible, set_passwd_visible) = signal(false);
 
     view! {
-        <Title text="Settings" />
+        <PageMeta
+            title="Settings".to_string()
+            description="The settings of your account".to_string()
+            path="/settings".to_string()
+            robots="noindex, nofollow"
+        />
         <div class="fixed inset-0 z-50 flex items-center justify-center bg-gray-900 bg-opacity-60">
             <div class="block rounded-lg bg-white w-2/5 p-4 shadow-[0_2px_15px_-3px_rgba(0,0,0,0.07),0_10px_20px_-2px_rgba(0,0,0,0.04)] z-70">
                 <h5 class="mb-5 text-xl font-medium leading-tight text-neutral-800">
                     Update Your Settings.
                 </h5>
 
+                <UsernameSettings current=user.username() username />
                 <form>
                     <div class="mb-5">
                         <input
@@ -428,15 +578,7 @@ where
                             placeholder="URL of profile picture"
                             on:input=on_profile_pic_url_input
                         />
-                    </div>
-                    <div class="mb-5">
-                        <input
-                            disabled
-                            node_ref=user_name
-                            class="input-field-common cursor-not-allowed"
-                            type="text"
-                            placeholder=user.username()
-                        />
+                        <ImageUpload label=" Upload a picture" on_upload=on_profile_pic_upload />
                     </div>
                     <div class="mb-5">
                         <textarea
@@ -490,6 +632,7 @@ where
                             }></i>
                         </span>
                     </div>
+                    <PasswordStrength password=new_password_value user_inputs />
                     <div class="mb-5 relative">
                         <input
                             node_ref=user_confirm_password
@@ -565,7 +708,239 @@ where
                         </button>
                     </div>
                 </form>
+                <DigestSettings />
+                <AccountSettings logout />
             </div>
         </div>
     }
 }
+
+#[component]
+fn DigestSettings() -> impl IntoView {
+    use crate::models::DigestFrequency;
+
+    let digest_update = ServerAction::<DigestUpdateAction>::new();
+    let frequency = Resource::new(move || digest_update.version().get(), move |_| digest_get());
+
+    let digest_status = move || match digest_update.value().get() {
+        Some(Ok(())) => "Successful. Digest preference saved.".to_string(),
+        Some(Err(x)) => format!("Unexpected error: {x}"),
+        None => String::new(),
+    };
+
+    view! {
+        <ActionForm action=digest_update>
+            <div class="mb-5 flex items-center justify-between gap-2">
+                <label for="frequency" class="text-gray-700">
+                    "Email digest of the authors and tags you follow"
+                </label>
+                <Suspense fallback=move || view! { <p>"Loading digest settings"</p> }>
+                    {move || {
+                        frequency
+                            .get()
+                            .map(|f| {
+                                let f = f.unwrap_or_default();
+                                view! {
+                                    <select
+                                        id="frequency"
+                                        name="frequency"
+                                        class="focus:shadow-outline rounded border px-1 py-1 leading-tight text-gray-700 shadow focus:outline-none"
+                                    >
+                                        <option value="never" selected=f == DigestFrequency::Never>
+                                            "Never"
+                                        </option>
+                                        <option value="daily" selected=f == DigestFrequency::Daily>
+                                            "Daily"
+                                        </option>
+                                        <option
+                                            value="weekly"
+                                            selected=f == DigestFrequency::Weekly
+                                        >
+                                            "Weekly"
+                                        </option>
+                                    </select>
+                                }
+                            })
+                    }}
+                </Suspense>
+                <button
+                    type="submit"
+                    class="bg-blue-700 hover:bg-blue-800 px-3 py-1 text-white rounded-lg"
+                >
+                    "Save"
+                </button>
+            </div>
+            <p class=move || {
+                format!(
+                    "font-medium {}",
+                    if digest_status().starts_with("Successful.") {
+                        "text-green-500"
+                    } else {
+                        "text-red-500"
+                    },
+                )
+            }>{digest_status}</p>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn UsernameSettings(current: String, username: crate::auth::UsernameSignal) -> impl IntoView {
+    let change_username = ServerAction::<UsernameChangeAction>::new();
+
+    Effect::new(move || {
+        if let Some(Ok(UsernameChangeResponse::Changed(x))) = change_username.value().get() {
+            username.set(Some(x));
+        }
+    });
+    let username_status = move || match change_username.value().get() {
+        Some(Ok(UsernameChangeResponse::Changed(x))) => {
+            format!("Successful. You are {x} now, your former profile URL leads here.")
+        }
+        Some(Ok(UsernameChangeResponse::ValidationError(x))) => x,
+        Some(Ok(UsernameChangeResponse::Taken)) => "This username is taken.".to_string(),
+        Some(Ok(UsernameChangeResponse::TooSoon(x))) => {
+            format!("You changed your username recently, the next change is possible from {x}.")
+        }
+        Some(Err(x)) => format!("Unexpected error: {x}"),
+        None => String::new(),
+    };
+
+    view! {
+        <ActionForm action=change_username>
+            <div class="mb-5 flex items-center justify-between gap-2">
+                <input
+                    name="username"
+                    class="input-field-common"
+                    type="text"
+                    value=current
+                    placeholder="Username"
+                    minlength=crate::models::USERNAME_MIN_LENGTH
+                    maxlength=crate::models::USERNAME_MAX_LENGTH
+                    pattern="[A-Za-z0-9_\\-]+"
+                    required
+                />
+                <button
+                    type="submit"
+                    class="bg-blue-700 hover:bg-blue-800 px-3 py-1 text-white rounded-lg whitespace-nowrap"
+                    prop:disabled=move || change_username.pending().get()
+                >
+                    "Change username"
+                </button>
+            </div>
+            <p class=move || {
+                format!(
+                    "font-medium {}",
+                    if username_status().starts_with("Successful.") {
+                        "text-green-500"
+                    } else {
+                        "text-red-500"
+                    },
+                )
+            }>{username_status}</p>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn AccountSettings(logout: ServerAction<LogoutAction>) -> impl IntoView {
+    let delete_account = ServerAction::<DeleteAccountAction>::new();
+    let confirming = RwSignal::new(false);
+
+    Effect::new(move || {
+        if let Some(Ok(DeleteAccountResponse::Deleted)) = delete_account.value().get() {
+            logout.dispatch(LogoutAction {});
+        }
+    });
+    let delete_status = move || match delete_account.value().get() {
+        Some(Ok(DeleteAccountResponse::WrongPassword)) => "Wrong password.".to_string(),
+        Some(Ok(DeleteAccountResponse::Deleted)) => "Your account is deleted.".to_string(),
+        Some(Err(x)) => format!("Unexpected error: {x}"),
+        None => String::new(),
+    };
+
+    view! {
+        <div class="mb-5 flex items-center justify-between gap-2">
+            <span class="text-gray-700">
+                "Your profile, articles, comments, favourites and follows"
+            </span>
+            <a
+                href=crate::account::DOWNLOAD_PATH
+                rel="external"
+                download
+                class="bg-blue-700 hover:bg-blue-800 px-3 py-1 text-white rounded-lg"
+            >
+                "Download my data"
+            </a>
+        </div>
+        <Show
+            when=move || confirming.get()
+            fallback=move || {
+                view! {
+                    <button
+                        type="button"
+                        class="bg-red-600 hover:bg-red-700 px-3 py-1 text-white rounded-lg"
+                        on:click=move |_| confirming.set(true)
+                    >
+                        "Delete my account"
+                    </button>
+                }
+            }
+        >
+            <ActionForm action=delete_account>
+                <p class="mb-3 text-gray-700">
+                    "This can't be undone. Your follows, favourites and bookmarks are removed."
+                </p>
+                <div class="mb-3">
+                    <select
+                        name="content"
+                        class="focus:shadow-outline rounded border px-1 py-1 leading-tight text-gray-700 shadow focus:outline-none"
+                    >
+                        <option value="anonymise" selected>
+                            "Keep my articles and comments as written by a deleted user"
+                        </option>
+                        <option value="delete">"Delete my articles and comments too"</option>
+                    </select>
+                </div>
+                <div class="mb-3">
+                    <input
+                        name="password"
+                        class="input-field-common"
+                        type="password"
+                        placeholder="Your password, to confirm"
+                        required
+                    />
+                </div>
+                <div class="flex justify-between">
+                    <button
+                        type="submit"
+                        class="bg-red-600 hover:bg-red-700 px-3 py-1 text-white rounded-lg"
+                        prop:disabled=move || delete_account.pending().get()
+                    >
+                        "Delete my account"
+                    </button>
+                    <button
+                        type="button"
+                        class="bg-gray-300 hover:bg-gray-400 px-3 py-1 text-white rounded-lg"
+                        on:click=move |_| {
+                            delete_account.clear();
+                            confirming.set(false);
+                        }
+                    >
+                        "Cancel"
+                    </button>
+                </div>
+                <p class=move || {
+                    format!(
+                        "font-medium {}",
+                        if delete_status().starts_with("Your account") {
+                            "text-green-500"
+                        } else {
+                            "text-red-500"
+                        },
+                    )
+                }>{delete_status}</p>
+            </ActionForm>
+        </Show>
+    }
+}
@@ -1,5 +1,6 @@
+use crate::components::password_strength::PasswordStrength;
+use crate::components::seo::PageMeta;
 use leptos::{html::Input, prelude::*};
-use leptos_meta::*;
 use leptos_router::components::*;
 
 use crate::auth::{SignupAction, SignupResponse};
@@ -64,6 +65,10 @@ where
     let acc_user: NodeRef<Input> = NodeRef::new();
     let acc_email: NodeRef<Input> = NodeRef::new();
     let acc_password: NodeRef<Input> = NodeRef::new();
+    let (username_value, set_username_value) = signal(String::new());
+    let (email_value, set_email_value) = signal(String::new());
+    let (password_value, set_password_value) = signal(String::new());
+    let user_inputs = Signal::derive(move || vec![username_value.get(), email_value.get()]);
 
     let on_in_event = move |_| {
         if signup_status.get().starts_with("Signup Successful") {
@@ -97,7 +102,12 @@ where
     };
 
     view! {
-        <Title text="Signup" />
+        <PageMeta
+            title="Signup".to_string()
+            description="Create an account".to_string()
+            path="/signup".to_string()
+            robots="noindex, nofollow"
+        />
         <div class="fixed inset-0 z-50 flex items-center justify-center bg-gray-900 bg-opacity-60">
             <div class="block rounded-lg bg-white w-2/5 p-4 shadow-[0_2px_15px_-3px_rgba(0,0,0,0.07),0_10px_20px_-2px_rgba(0,0,0,0.04)] z-70">
 
@@ -123,6 +133,7 @@ where
                             value=move || { String::new() }
                             placeholder="username"
                             required=true
+                            on:input=move |ev| set_username_value(event_target_value(&ev))
                         />
                     </div>
                     <label class="block text-gray-700 text-sm font-bold mb-2" for="email">
@@ -138,6 +149,7 @@ where
                             value=move || { String::new() }
                             placeholder="Email"
                             required=true
+                            on:input=move |ev| set_email_value(event_target_value(&ev))
                         />
                     </div>
                     <label class="block text-gray-700 text-sm font-bold mb-2" for="password">
@@ -158,6 +170,7 @@ where
                             value=move || { String::new() }
                             placeholder="Password"
                             required=true
+                            on:input=move |ev| set_password_value(event_target_value(&ev))
                         />
                         <span
                             class="absolute inset-y-0 right-0 flex items-center pr-3 cursor-pointer"
@@ -177,6 +190,7 @@ where
                             }></i>
                         </span>
                     </div>
+                    <PasswordStrength password=password_value user_inputs />
                     <div class="flex flex-row-reverse space-x-4 space-x-reverse">
                         <button
                             type="button"
@@ -0,0 +1,385 @@
+use crate::components::buttons::{followed_tags, ButtonFollowTag, FollowTagAction};
+use crate::components::seo::PageMeta;
+use crate::models::{Pagination, TagAlias, TagCount};
+use leptos::prelude::*;
+use leptos_router::{
+    components::{Form, A},
+    hooks::use_query,
+    params::Params,
+};
+
+const TAGS_PER_PAGE: u32 = 50;
+
+#[derive(Debug, Params, PartialEq, Clone, Default)]
+struct TagsQuery {
+    q: Option<String>,
+    page: Option<u32>,
+}
+
+impl TagsQuery {
+    fn search(&self) -> String {
+        self.q.as_deref().unwrap_or_default().to_string()
+    }
+    fn page(&self) -> u32 {
+        self.page.unwrap_or_default()
+    }
+    fn url(&self, page: u32) -> String {
+        format!(
+            "/tags?q={}&page={page}",
+            crate::urls::encode_query_value(&self.search())
+        )
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
+pub struct TagDirectory {
+    pub tags: Vec<TagCount>,
+    pub total: i64,
+}
+
+#[server(TagDirectoryAction, "/api", "GetJson")]
+#[tracing::instrument]
+pub async fn tag_directory(search: String, page: u32) -> Result<TagDirectory, ServerFnError> {
+    // Searching for `#Rust` is searching for `rust`
+    let search = crate::models::normalize_tag(&search).unwrap_or_default();
+    let (tags, total) = tokio::try_join!(
+        TagCount::directory(search.clone(), i64::from(page), i64::from(TAGS_PER_PAGE)),
+        TagCount::count(search),
+    )
+    .map_err(|x| {
+        tracing::error!("problem while fetching the tag directory: {x:?}");
+        ServerFnError::new("Problem while fetching the tags")
+    })?;
+    Ok(TagDirectory { tags, total })
+}
+
+#[cfg(feature = "ssr")]
+async fn require_admin() -> Result<(), ServerFnError> {
+    let Some(username) = crate::auth::get_username() else {
+        return Err(ServerFnError::new("You need to be authenticated"));
+    };
+    match crate::models::User::is_admin(username).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(ServerFnError::new(
+            "Only the administrators can manage the tags",
+        )),
+        Err(x) => {
+            tracing::error!("problem while fetching the user role: {x:?}");
+            Err(ServerFnError::new("Problem while checking the permissions"))
+        }
+    }
+}
+
+/// The tag aliases, `None` when the current user is not an administrator.
+#[server(TagAliasesAction, "/api", "GetJson")]
+#[tracing::instrument]
+pub async fn tag_aliases() -> Result<Option<Vec<TagAlias>>, ServerFnError> {
+    if require_admin().await.is_err() {
+        return Ok(None);
+    }
+    TagAlias::all().await.map(Some).map_err(|x| {
+        tracing::error!("problem while fetching the tag aliases: {x:?}");
+        ServerFnError::new("Problem while fetching the tag aliases")
+    })
+}
+
+#[server(MergeTagsAction, "/api")]
+#[tracing::instrument]
+pub async fn merge_tags(from: String, into: String) -> Result<(), ServerFnError> {
+    require_admin().await?;
+    let (Some(from), Some(into)) = (
+        crate::models::normalize_tag(&from),
+        crate::models::normalize_tag(&into),
+    ) else {
+        return Err(ServerFnError::new("This is not a valid tag"));
+    };
+    let into = TagAlias::resolve(into).await.map_err(|x| {
+        tracing::error!("problem while resolving the tag: {x:?}");
+        ServerFnError::new("Problem while merging the tags")
+    })?;
+    if from == into {
+        return Err(ServerFnError::new(format!("{from} is already {into}")));
+    }
+    TagAlias::merge(from, into).await.map_err(|x| {
+        tracing::error!("problem while merging the tags: {x:?}");
+        ServerFnError::new("Problem while merging the tags")
+    })
+}
+
+#[server(RemoveTagAliasAction, "/api")]
+#[tracing::instrument]
+pub async fn remove_tag_alias(alias: String) -> Result<(), ServerFnError> {
+    require_admin().await?;
+    TagAlias::remove(alias).await.map(|_| ()).map_err(|x| {
+        tracing::error!("problem while removing the tag alias: {x:?}");
+        ServerFnError::new("Problem while removing the tag alias")
+    })
+}
+
+/// Every tag with its number of articles, searchable, next to the tags followed by the user.
+#[component]
+pub fn TagsPage(username: crate::auth::UsernameSignal) -> impl IntoView {
+    let show_modal: RwSignal<bool> = use_context().expect("show_modal context should be available");
+    show_modal.set(false);
+
+    let query = use_query::<TagsQuery>();
+    let follow_tag = ServerAction::<FollowTagAction>::new();
+    let merge = ServerAction::<MergeTagsAction>::new();
+    let followed = Resource::new(
+        move || {
+            (
+                username.get(),
+                follow_tag.version().get(),
+                merge.version().get(),
+            )
+        },
+        |_| followed_tags(),
+    );
+    let directory = Resource::new(
+        move || (query.get().unwrap_or_default(), merge.version().get()),
+        |(query, _)| tag_directory(query.search(), query.page()),
+    );
+
+    let tag_row = move |tag: String, articles: Option<i64>, is_followed: bool| {
+        let href = Pagination::default().set_tag(&tag).to_string();
+        let follow_tag_name = tag.clone();
+        view! {
+            <div class="flex items-center gap-1">
+                <A href=href>
+                    <span class="rounded px-1 py-0.5 bg-gray-200 hover:bg-green-300">{tag}</span>
+                </A>
+                {articles.map(|n| view! { <span class="text-sm text-gray-500">"× "{n}</span> })}
+                <Show when=move || username.with(Option::is_some)>
+                    <ButtonFollowTag follow_tag tag=follow_tag_name.clone() followed=is_followed />
+                </Show>
+            </div>
+        }
+    };
+
+    view! {
+        <PageMeta
+            title="Tags".to_string()
+            description="All the tags of the articles, with the number of articles for each".to_string()
+            path="/tags".to_string()
+        />
+        <div class="mx-auto sm:px-6 lg:px-8 bg-gray-200 px-2 py-2">
+            <Show when=move || username.with(Option::is_some)>
+                <div class="mb-2 p-4 bg-white rounded-lg shadow-md">
+                    <h2 class="font-bold mb-2">"Tags you follow"</h2>
+                    <Transition fallback=move || view! { <p>"Loading..."</p> }>
+                        {move || {
+                            followed
+                                .get()
+                                .map(|tags| match tags {
+                                    Ok(tags) if tags.is_empty() => {
+                                        view! { <p>"You are not following any tag yet."</p> }
+                                            .into_any()
+                                    }
+                                    Ok(tags) => {
+                                        view! {
+                                            <div class="flex flex-wrap gap-2">
+                                                {tags
+                                                    .into_iter()
+                                                    .map(|tag| tag_row(tag, None, true))
+                                                    .collect_view()}
+                                            </div>
+                                        }
+                                            .into_any()
+                                    }
+                                    Err(x) => {
+                                        view! { <p class="text-red-500">{x.to_string()}</p> }
+                                            .into_any()
+                                    }
+                                })
+                        }}
+                    </Transition>
+                    <ActionForm action=follow_tag>
+                        <div class="flex gap-2 mt-3">
+                            <input type="hidden" name="follow" value="true" />
+                            <input
+                                name="tag"
+                                class="input-field-common"
+                                type="text"
+                                placeholder="Follow another tag"
+                                required=true
+                            />
+                            <button class="bg-blue-700 hover:bg-blue-800 text-white font-bold px-3 rounded-lg">
+                                "Follow"
+                            </button>
+                        </div>
+                    </ActionForm>
+                    {move || {
+                        follow_tag
+                            .value()
+                            .get()
+                            .and_then(Result::err)
+                            .map(|x| view! { <p class="text-red-500">{x.to_string()}</p> })
+                    }}
+                </div>
+            </Show>
+            <div class="mb-2 p-4 bg-white rounded-lg shadow-md">
+                <div class="flex justify-between items-center mb-2">
+                    <h2 class="font-bold">"All tags"</h2>
+                    <Form method="GET" action="/tags">
+                        <input
+                            type="search"
+                            name="q"
+                            class="input-field-common"
+                            placeholder="Search tags"
+                            prop:value=move || query.get().unwrap_or_default().search()
+                        />
+                    </Form>
+                </div>
+                <Transition fallback=move || view! { <p>"Loading..."</p> }>
+                    {move || {
+                        let followed = followed.get().and_then(Result::ok).unwrap_or_default();
+                        directory
+                            .get()
+                            .map(|directory| match directory {
+                                Ok(directory) if directory.tags.is_empty() => {
+                                    view! { <p>"No tags to list"</p> }.into_any()
+                                }
+                                Ok(directory) => {
+                                    view! {
+                                        <div class="flex flex-wrap gap-2">
+                                            {directory
+                                                .tags
+                                                .into_iter()
+                                                .map(|t| {
+                                                    let is_followed = followed.contains(&t.tag);
+                                                    tag_row(t.tag, Some(t.articles), is_followed)
+                                                })
+                                                .collect_view()}
+                                        </div>
+                                        <TagsPageNavigator query total=directory.total />
+                                    }
+                                        .into_any()
+                                }
+                                Err(x) => {
+                                    view! { <p class="text-red-500">{x.to_string()}</p> }.into_any()
+                                }
+                            })
+                    }}
+                </Transition>
+            </div>
+            <TagAdmin merge />
+        </div>
+    }
+}
+
+#[component]
+fn TagsPageNavigator(
+    query: Memo<Result<TagsQuery, leptos_router::params::ParamsError>>,
+    total: i64,
+) -> impl IntoView {
+    let pages = (total as u32).div_ceil(TAGS_PER_PAGE).max(1);
+    let current = move || query.get().unwrap_or_default();
+
+    view! {
+        <div class="flex gap-4 items-center mt-3">
+            <Show when=move || { current().page() > 0 }>
+                <A href=move || current().url(current().page() - 1)>
+                    <span class="px-4 hover:text-blue-500 border rounded-full bg-gray-100">
+                        "<< Previous page"
+                    </span>
+                </A>
+            </Show>
+            <span class="text-sm text-gray-500">
+                {move || format!("Page {} of {pages} ({total} tags)", current().page() + 1)}
+            </span>
+            <Show when=move || { current().page() + 1 < pages }>
+                <A href=move || current().url(current().page() + 1)>
+                    <span class="px-4 hover:text-blue-500 border rounded-full bg-gray-100">
+                        "Next page >>"
+                    </span>
+                </A>
+            </Show>
+        </div>
+    }
+}
+
+/// Merges and aliases of tags, only shown to the administrators.
+#[component]
+fn TagAdmin(merge: ServerAction<MergeTagsAction>) -> impl IntoView {
+    let remove = ServerAction::<RemoveTagAliasAction>::new();
+    let aliases = Resource::new(
+        move || (merge.version().get(), remove.version().get()),
+        |_| tag_aliases(),
+    );
+
+    view! {
+        <Transition>
+            {move || {
+                aliases
+                    .get()
+                    .and_then(Result::ok)
+                    .flatten()
+                    .map(|aliases| {
+                        view! {
+                            <div class="mb-2 p-4 bg-white rounded-lg shadow-md">
+                                <h2 class="font-bold mb-2">"Merge tags"</h2>
+                                <p class="text-sm text-gray-500 mb-2">
+                                    "The articles and followers of the first tag are moved to the second one, and the first tag becomes an alias of it."
+                                </p>
+                                <ActionForm action=merge>
+                                    <div class="flex gap-2">
+                                        <input
+                                            name="from"
+                                            class="input-field-common"
+                                            type="text"
+                                            placeholder="Merge this tag"
+                                            required=true
+                                        />
+                                        <input
+                                            name="into"
+                                            class="input-field-common"
+                                            type="text"
+                                            placeholder="into this tag"
+                                            required=true
+                                        />
+                                        <button class="bg-blue-700 hover:bg-blue-800 text-white font-bold px-3 rounded-lg">
+                                            "Merge"
+                                        </button>
+                                    </div>
+                                </ActionForm>
+                                {move || {
+                                    merge
+                                        .value()
+                                        .get()
+                                        .and_then(Result::err)
+                                        .map(|x| view! { <p class="text-red-500">{x.to_string()}</p> })
+                                }}
+                                <h3 class="font-bold mt-3 mb-1">"Aliases"</h3>
+                                <ul>
+                                    {aliases
+                                        .into_iter()
+                                        .map(|a| {
+                                            let alias = a.alias.clone();
+                                            view! {
+                                                <li class="flex gap-2 items-center">
+                                                    <span>{a.alias}" → "{a.tag}</span>
+                                                    <button
+                                                        type="button"
+                                                        class="text-red-600 hover:underline"
+                                                        disabled=move || remove.pending().get()
+                                                        on:click=move |_| {
+                                                            remove
+                                                                .dispatch(RemoveTagAliasAction {
+                                                                    alias: alias.clone(),
+                                                                });
+                                                        }
+                                                    >
+                                                        "Remove"
+                                                    </button>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                            </div>
+                        }
+                    })
+            }}
+        </Transition>
+    }
+}
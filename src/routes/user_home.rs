@@ -1,8 +1,8 @@
 use crate::app::{GlobalState, GlobalStateStoreFields};
 use crate::components::article_view::ArticleView;
+use crate::components::seo::{PageMeta, SITE_DESCRIPTION};
 use crate::models::Pagination;
 use leptos::{html::Input, prelude::*};
-use leptos_meta::*;
 use leptos_router::{
     components::A,
     hooks::{use_location, use_query},
@@ -11,8 +11,10 @@ use leptos_router::{
 use reactive_stores::Store;
 
 use crate::components::{
-    article_preview::ArticlePreviewList, items_per_page::ItemsPerPage,
-    prev_next_buttons::PreviousNextButton,
+    article_preview::ArticlePreviewList,
+    buttons::{followed_tags, ButtonFollowTag, FollowTagAction},
+    items_per_page::ItemsPerPage,
+    prev_next_buttons::{PageNavigator, PreviousNextButton},
 };
 
 #[tracing::instrument]
@@ -22,18 +24,35 @@ async fn home_articles(
     amount: u32,
     tag: String,
     my_feed: bool,
-) -> Result<Vec<crate::models::Article>, ServerFnError> {
-    let page = i64::from(page);
+    feed_tags: bool,
+    after: Option<String>,
+    before: Option<String>,
+) -> Result<crate::models::ArticleFeed, ServerFnError> {
+    let start = crate::models::PageStart::new(page, amount, after, before).map_err(|x| {
+        tracing::debug!("{x}");
+        ServerFnError::new("The page is not valid anymore, go back to the first one")
+    })?;
     let amount = i64::from(amount);
+    let tag = match crate::models::normalize_tag(&tag) {
+        Some(tag) => crate::models::TagAlias::resolve(tag).await.map_err(|x| {
+            tracing::error!("problem while resolving the tag: {x:?}");
+            ServerFnError::new("Problem while fetching home articles")
+        })?,
+        None => String::new(),
+    };
 
-    Ok(
-        crate::models::Article::for_home_page(page, amount, tag, my_feed)
-            .await
-            .map_err(|x| {
-                tracing::error!("problem while fetching home articles: {x:?}");
-                ServerFnError::new("Problem while fetching home articles")
-            })?,
-    )
+    let mut query = crate::models::ArticleQuery::new().tag(tag);
+    if my_feed {
+        let username = crate::auth::get_username().unwrap_or_default();
+        query = query.followed_by(username, feed_tags);
+    }
+    let (mut feed, total) = tokio::try_join!(query.fetch_page(start, amount), query.count())
+        .map_err(|x| {
+            tracing::error!("problem while fetching home articles: {x:?}");
+            ServerFnError::new("Problem while fetching home articles")
+        })?;
+    feed.total = total;
+    Ok(feed)
 }
 
 #[server(GetTagsAction, "/api", "GetJson")]
@@ -167,6 +186,9 @@ pub fn HomePage(username: crate::auth::UsernameSignal) -> impl IntoView {
                 pagination.get_amount(),
                 pagination.get_tag().to_string(),
                 pagination.get_my_feed(),
+                pagination.get_feed_tags(),
+                pagination.get_after(),
+                pagination.get_before(),
             )
             .await
         },
@@ -202,8 +224,27 @@ pub fn HomePage(username: crate::auth::UsernameSignal) -> impl IntoView {
     });
 
     let run_search = expect_context::<ServerAction<SearchAction>>();
+    let selected_tag = move || {
+        pagination.with(|x| {
+            x.as_ref()
+                .map(|x| x.get_tag().to_string())
+                .unwrap_or_default()
+        })
+    };
+    let page_title = Signal::derive(move || match selected_tag() {
+        tag if tag.is_empty() => "Home".to_string(),
+        tag => format!("Articles tagged {tag}"),
+    });
+    let page_description = Signal::derive(move || match selected_tag() {
+        tag if tag.is_empty() => SITE_DESCRIPTION.to_string(),
+        tag => format!("The latest articles about {tag}"),
+    });
+    let page_path = Signal::derive(move || match selected_tag() {
+        tag if tag.is_empty() => "/".to_string(),
+        tag => format!("/?tag={}", crate::models::tag_query_value(&tag)),
+    });
     view! {
-        <Title text="Home" />
+        <PageMeta title=page_title description=page_description path=page_path />
         <div class="mx-auto lg:px-8 bg-gray-200 px-2 py-2 sm:px-0">
             <div class="">
                 <div class="flex justify-between">
@@ -222,14 +263,21 @@ pub fn HomePage(username: crate::auth::UsernameSignal) -> impl IntoView {
                         <div class="flex gap-1 rounded bg-white mb-2">
                             <span class="font-bold m-1">Popular Tags:</span>
                             <TagList />
+                            <A href="/tags">
+                                <span class="m-1 text-blue-600 hover:underline">"All tags"</span>
+                            </A>
+                            <FollowSelectedTag username pagination />
                         </div>
                     </Show>
+                    <Show when=move || pagination.get().unwrap_or_default().get_my_feed()>
+                        <FeedTagsToggle pagination />
+                    </Show>
                     <Show
                         when=move || {
                             articles
                                 .with(|x| {
                                     x.as_ref()
-                                        .map_or(0, |y| y.as_ref().map(Vec::len).unwrap_or_default())
+                                        .map_or(0, |y| y.as_ref().map(|y| y.articles.len()).unwrap_or_default())
                                 }) != 0
                         }
                         fallback=move || {
@@ -237,7 +285,7 @@ pub fn HomePage(username: crate::auth::UsernameSignal) -> impl IntoView {
                                 <div>
                                     <p>
                                         {if pagination.get().unwrap_or_default().get_my_feed() {
-                                            "You are not following any other user!"
+                                            "You are not following any other user or tag!"
                                         } else {
                                             "No articles to list"
                                         }}
@@ -414,6 +462,19 @@ fn ResultsViewPrevNextButton(
                     </ActionForm>
                 </div>
             </Show>
+            <PageNavigator
+                total=total_count
+                page
+                amount
+                on_page=move |page| {
+                    run_search
+                        .dispatch(SearchAction {
+                            search: global_state.search_param().get_untracked(),
+                            page,
+                            amount,
+                        });
+                }
+            />
         </div>
     }
 }
@@ -646,6 +707,82 @@ fn GlobalFeedTab(pagination: Memo<Result<Pagination, ParamsError>>) -> impl Into
     }
 }
 
+/// Lets a logged in user follow the tag the home page is filtered by.
+#[component]
+fn FollowSelectedTag(
+    username: crate::auth::UsernameSignal,
+    pagination: Memo<Result<Pagination, ParamsError>>,
+) -> impl IntoView {
+    let follow_tag = ServerAction::<FollowTagAction>::new();
+    let followed = Resource::new(
+        move || (username.get(), follow_tag.version().get()),
+        |_| followed_tags(),
+    );
+    let selected = move || {
+        pagination.with(|x| {
+            x.as_ref()
+                .map(crate::models::Pagination::get_tag)
+                .unwrap_or_default()
+                .to_string()
+        })
+    };
+
+    view! {
+        <Show when=move || username.with(Option::is_some) && !selected().is_empty()>
+            <Transition>
+                {move || {
+                    followed
+                        .get()
+                        .and_then(Result::ok)
+                        .map(|tags| {
+                            let tag = selected();
+                            let is_followed = tags.contains(&tag);
+                            view! {
+                                <div class="ml-auto m-1">
+                                    <ButtonFollowTag follow_tag tag followed=is_followed />
+                                </div>
+                            }
+                        })
+                }}
+            </Transition>
+        </Show>
+    }
+}
+
+/// Switches "Your Feed" between the followed authors only and the followed authors and tags.
+#[component]
+fn FeedTagsToggle(pagination: Memo<Result<Pagination, ParamsError>>) -> impl IntoView {
+    let feed_tags = move || pagination.get().unwrap_or_default().get_feed_tags();
+
+    view! {
+        <div class="flex gap-2 rounded bg-white mb-2 px-1">
+            <label class="m-1 cursor-pointer">
+                <input
+                    type="checkbox"
+                    class="mr-1"
+                    prop:checked=feed_tags
+                    on:change=move |_| {
+                        let navigate = leptos_router::hooks::use_navigate();
+                        navigate(
+                            &pagination
+                                .get()
+                                .unwrap_or_default()
+                                .reset_page()
+                                .set_feed_tags(!feed_tags())
+                                .to_string(),
+                            Default::default(),
+                        );
+                    }
+                />
+                "Include the tags I follow"
+            </label>
+            <A href="/tags">
+                <span class="m-1 text-blue-600 hover:underline">"Manage followed tags"</span>
+            </A>
+        </div>
+    }
+}
+
 #[component]
 fn TagList() -> impl IntoView {
     let pagination = use_query::<crate::models::Pagination>();
@@ -0,0 +1,714 @@
+//! Content for development and load testing: the demo content, or a generated data set which
+//! is the same for the same options, so a slow page or a broken layout can be reproduced.
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use std::collections::BTreeSet;
+
+/// The demo content which used to come with the migrations: user1 to user5 and their articles.
+const DEMO: &str = include_str!("../demo/basedata.sql");
+
+/// The password of every generated user.
+pub const PASSWORD: &str = "password";
+
+/// How skewed the popularity of the tags, authors and articles is, 1 is the classic Zipf law.
+const ZIPF_EXPONENT: f64 = 1.1;
+
+const FIRST_NAMES: [&str; 24] = [
+    "ada", "alan", "barbara", "brian", "claude", "dennis", "donald", "edsger", "frances", "grace",
+    "guido", "hedy", "ivan", "john", "ken", "linus", "margaret", "niklaus", "radia", "rich",
+    "robin", "shafi", "tim", "yukihiro",
+];
+
+const LAST_NAMES: [&str; 16] = [
+    "baker", "carter", "diaz", "evans", "fischer", "garcia", "hughes", "ito", "jensen", "kowalski",
+    "larsen", "moreau", "nakamura", "okafor", "patel", "silva",
+];
+
+const TOPICS: [&str; 48] = [
+    "rust",
+    "leptos",
+    "sqlite",
+    "axum",
+    "webassembly",
+    "tokio",
+    "async",
+    "testing",
+    "css",
+    "accessibility",
+    "performance",
+    "security",
+    "databases",
+    "caching",
+    "compilers",
+    "types",
+    "macros",
+    "networking",
+    "observability",
+    "deployment",
+    "docker",
+    "linux",
+    "embedded",
+    "games",
+    "graphics",
+    "audio",
+    "machine learning",
+    "data",
+    "search",
+    "design",
+    "ux",
+    "mobile",
+    "open source",
+    "career",
+    "teaching",
+    "books",
+    "hardware",
+    "cryptography",
+    "privacy",
+    "distributed systems",
+    "concurrency",
+    "memory",
+    "parsing",
+    "tooling",
+    "editors",
+    "git",
+    "architecture",
+    "refactoring",
+];
+
+const WORDS: [&str; 64] = [
+    "the",
+    "a",
+    "system",
+    "request",
+    "server",
+    "client",
+    "cache",
+    "query",
+    "index",
+    "page",
+    "signal",
+    "component",
+    "state",
+    "error",
+    "value",
+    "thread",
+    "task",
+    "future",
+    "buffer",
+    "stream",
+    "table",
+    "row",
+    "column",
+    "schema",
+    "migration",
+    "build",
+    "test",
+    "release",
+    "bug",
+    "fix",
+    "feature",
+    "trait",
+    "struct",
+    "module",
+    "crate",
+    "function",
+    "closure",
+    "lifetime",
+    "borrow",
+    "owner",
+    "reference",
+    "pointer",
+    "heap",
+    "stack",
+    "latency",
+    "throughput",
+    "load",
+    "pool",
+    "connection",
+    "lock",
+    "transaction",
+    "commit",
+    "route",
+    "handler",
+    "layout",
+    "style",
+    "theme",
+    "form",
+    "input",
+    "event",
+    "render",
+    "hydration",
+    "island",
+    "macro",
+];
+
+const VERBS: [&str; 24] = [
+    "makes",
+    "breaks",
+    "hides",
+    "shows",
+    "needs",
+    "returns",
+    "holds",
+    "drops",
+    "moves",
+    "clones",
+    "parses",
+    "renders",
+    "caches",
+    "locks",
+    "waits for",
+    "spawns",
+    "sends",
+    "reads",
+    "writes",
+    "checks",
+    "measures",
+    "changes",
+    "replaces",
+    "improves",
+];
+
+const TITLE_PATTERNS: [&str; 8] = [
+    "What I learned about {topic}",
+    "A practical guide to {topic}",
+    "Why {topic} is harder than it looks",
+    "{topic} in production",
+    "Notes on {topic}",
+    "Ten mistakes with {topic}",
+    "The case for {topic}",
+    "How we rebuilt our {topic} stack",
+];
+
+/// What to generate. The same options give the same data set.
+#[derive(Debug, Clone)]
+pub struct SeedOptions {
+    pub seed: u64,
+    pub users: usize,
+    pub articles: usize,
+    pub tags: usize,
+    /// The mean number of comments per article.
+    pub comments: usize,
+}
+
+impl Default for SeedOptions {
+    fn default() -> Self {
+        Self {
+            seed: 1,
+            users: 50,
+            articles: 500,
+            tags: 40,
+            comments: 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeedUser {
+    pub username: String,
+    pub email: String,
+    pub bio: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeedArticle {
+    pub slug: String,
+    pub author: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub tags: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeedComment {
+    pub article: String,
+    pub username: String,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+}
+
+/// A generated data set, ready to be inserted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dataset {
+    pub users: Vec<SeedUser>,
+    pub articles: Vec<SeedArticle>,
+    /// The follower, then the followed user.
+    pub follows: Vec<(String, String)>,
+    /// The article, then the user.
+    pub favourites: Vec<(String, String)>,
+    pub comments: Vec<SeedComment>,
+}
+
+/// SplitMix64, small and stable across versions, unlike the generators of `rand`.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` is not 0.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `[0, 1)`.
+    fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        self.unit() < probability
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Ranks drawn with a probability proportional to `1 / (rank + 1)^s`, rank 0 is the most
+/// popular one.
+struct Zipf {
+    cumulative: Vec<f64>,
+}
+
+impl Zipf {
+    fn new(n: usize) -> Self {
+        let mut total = 0.;
+        let cumulative = (1..=n)
+            .map(|rank| {
+                total += 1. / (rank as f64).powf(ZIPF_EXPONENT);
+                total
+            })
+            .collect();
+        Self { cumulative }
+    }
+
+    fn sample(&self, rng: &mut Rng) -> usize {
+        let total = self.cumulative.last().copied().unwrap_or_default();
+        let target = rng.unit() * total;
+        self.cumulative
+            .partition_point(|x| *x <= target)
+            .min(self.cumulative.len() - 1)
+    }
+
+    /// Up to `count` distinct ranks, fewer when the draws keep landing on the same ones.
+    fn sample_distinct(&self, rng: &mut Rng, count: usize) -> BTreeSet<usize> {
+        let mut ranks = BTreeSet::new();
+        for _ in 0..count * 3 {
+            if ranks.len() == count.min(self.cumulative.len()) {
+                break;
+            }
+            ranks.insert(self.sample(rng));
+        }
+        ranks
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map(|x| x.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn sentence(rng: &mut Rng) -> String {
+    let mut words = (0..4 + rng.below(8))
+        .map(|_| rng.pick(&WORDS).to_string())
+        .collect::<Vec<_>>();
+    let split = 1 + rng.below(words.len() - 1);
+    words.insert(split, rng.pick(&VERBS).to_string());
+    let decorated = 1 + rng.below(words.len() - 1);
+    if rng.chance(0.15) {
+        words[decorated] = format!("**{}**", words[decorated]);
+    } else if rng.chance(0.15) {
+        words[decorated] = format!("`{}`", words[decorated]);
+    }
+    format!("{}.", capitalize(&words.join(" ")))
+}
+
+fn paragraph(rng: &mut Rng) -> String {
+    (0..2 + rng.below(5))
+        .map(|_| sentence(rng))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A Markdown body with sections, lists, code blocks, links and emphasis.
+fn markdown(rng: &mut Rng, topic: &str) -> String {
+    let mut blocks = vec![paragraph(rng)];
+    for _ in 0..1 + rng.below(4) {
+        blocks.push(format!(
+            "## {}",
+            capitalize(&sentence(rng).replace('.', ""))
+        ));
+        blocks.push(paragraph(rng));
+        match rng.below(4) {
+            0 => blocks.push(
+                (0..2 + rng.below(4))
+                    .map(|_| format!("- {}", sentence(rng)))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            1 => blocks.push(format!(
+                "```rust\nfn {}() -> Result<(), Error> {{\n    let {} = {}::new();\n    Ok(())\n}}\n```",
+                rng.pick(&WORDS),
+                rng.pick(&WORDS),
+                capitalize(rng.pick(&WORDS))
+            )),
+            2 => blocks.push(format!(
+                "> {}\n\nMore about it in [the {topic} notes](https://example.com/{}).",
+                sentence(rng),
+                slugify(topic)
+            )),
+            _ => blocks.push(paragraph(rng)),
+        }
+    }
+    blocks.join("\n\n")
+}
+
+/// Generates the data set of `options`, without touching the database.
+pub fn generate(options: &SeedOptions) -> Dataset {
+    let mut rng = Rng(options.seed);
+    let base = NaiveDate::from_ymd_opt(2025, 1, 1)
+        .and_then(|x| x.and_hms_opt(0, 0, 0))
+        .expect("valid date");
+
+    let mut dataset = Dataset::default();
+    if options.users == 0 {
+        return dataset;
+    }
+    let users = (0..options.users)
+        .map(|i| {
+            let username = format!(
+                "{}_{}{}",
+                rng.pick(&FIRST_NAMES),
+                rng.pick(&LAST_NAMES),
+                i + 1
+            );
+            SeedUser {
+                email: format!("{username}@example.com"),
+                bio: rng
+                    .chance(0.6)
+                    .then(|| format!("Writes about {}.", rng.pick(&TOPICS))),
+                username,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let tags = (0..options.tags)
+        .map(|i| match TOPICS.get(i) {
+            Some(topic) => topic.to_string(),
+            None => format!("{}-{}", TOPICS[i % TOPICS.len()], i / TOPICS.len() + 1),
+        })
+        .collect::<Vec<_>>();
+
+    // The ranks of the popularity laws point into shuffled lists, so the most popular ones
+    // aren't always the first ones
+    let mut authors = (0..users.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut authors);
+    let user_popularity = Zipf::new(users.len());
+    let tag_popularity = Zipf::new(tags.len());
+
+    let span = 365 * 24 * 60;
+    for i in 0..options.articles {
+        let count = 1 + rng.below(4);
+        let article_tags = tag_popularity
+            .sample_distinct(&mut rng, count)
+            .into_iter()
+            .map(|x| tags[x].clone())
+            .collect::<Vec<_>>();
+        let topic = article_tags
+            .first()
+            .cloned()
+            .unwrap_or_else(|| rng.pick(&TOPICS).to_string());
+        let title = capitalize(&rng.pick(&TITLE_PATTERNS).replace("{topic}", &topic));
+        // Spread over a year, in the order of the articles
+        let created_at = base
+            + TimeDelta::minutes((span * i / options.articles.max(1)) as i64)
+            + TimeDelta::minutes(rng.below(60) as i64);
+        let updated_at = if rng.chance(0.3) {
+            created_at + TimeDelta::hours(1 + rng.below(24 * 30) as i64)
+        } else {
+            created_at
+        };
+        dataset.articles.push(SeedArticle {
+            slug: format!("{}-{}-{}", slugify(&title), options.seed, i + 1),
+            author: users[authors[user_popularity.sample(&mut rng)]]
+                .username
+                .clone(),
+            description: sentence(&mut rng),
+            body: markdown(&mut rng, &topic),
+            title,
+            tags: article_tags,
+            created_at,
+            updated_at,
+        });
+    }
+
+    for (i, user) in users.iter().enumerate() {
+        let count = rng.below(users.len().min(20));
+        for rank in user_popularity.sample_distinct(&mut rng, count) {
+            if authors[rank] != i {
+                dataset
+                    .follows
+                    .push((user.username.clone(), users[authors[rank]].username.clone()));
+            }
+        }
+    }
+
+    let mut popular = (0..dataset.articles.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut popular);
+    let article_popularity = Zipf::new(dataset.articles.len());
+    if !popular.is_empty() {
+        for user in &users {
+            let count = rng.below(16);
+            for rank in article_popularity.sample_distinct(&mut rng, count) {
+                dataset.favourites.push((
+                    dataset.articles[popular[rank]].slug.clone(),
+                    user.username.clone(),
+                ));
+            }
+        }
+    }
+
+    for article in &dataset.articles {
+        for _ in 0..rng.below(2 * options.comments + 1) {
+            dataset.comments.push(SeedComment {
+                article: article.slug.clone(),
+                username: users[rng.below(users.len())].username.clone(),
+                body: paragraph(&mut rng),
+                created_at: article.created_at
+                    + TimeDelta::minutes(1 + rng.below(60 * 24 * 14) as i64),
+            });
+        }
+    }
+    dataset.users = users;
+    dataset
+}
+
+fn hash_password() -> Result<String, String> {
+    use argon2::{
+        password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
+        Argon2,
+    };
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(PASSWORD.as_bytes(), &salt)
+        .map(|x| x.to_string())
+        .map_err(|x| x.to_string())
+}
+
+/// Inserts `dataset` in one transaction. The users already there are kept, the articles of
+/// the same data set can't be inserted twice.
+pub async fn insert(dataset: &Dataset) -> Result<(), String> {
+    // One hash for everybody, hashing is what would take the time
+    let password = hash_password()?;
+    let mut transaction = crate::database::begin_write()
+        .await
+        .map_err(|x| x.to_string())?;
+    if let Some(first) = dataset.articles.first() {
+        let exists =
+            sqlx::query_scalar!("SELECT COUNT(*) FROM Articles WHERE slug = $1", first.slug)
+                .fetch_one(transaction.as_mut())
+                .await
+                .map_err(|x| x.to_string())?;
+        if exists > 0 {
+            return Err("the database already has the articles of this seed".into());
+        }
+    }
+
+    async {
+        for user in &dataset.users {
+            sqlx::query!(
+                "INSERT OR IGNORE INTO Users(username, email, password, bio) VALUES ($1, $2, $3, $4)",
+                user.username,
+                user.email,
+                password,
+                user.bio,
+            )
+            .execute(transaction.as_mut())
+            .await?;
+        }
+        for article in &dataset.articles {
+            sqlx::query!(
+                "INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, $6, $7)",
+                article.slug,
+                article.author,
+                article.title,
+                article.description,
+                article.body,
+                article.created_at,
+                article.updated_at,
+            )
+            .execute(transaction.as_mut())
+            .await?;
+            for tag in &article.tags {
+                sqlx::query!(
+                    "INSERT OR IGNORE INTO ArticleTags(article, tag) VALUES ($1, $2)",
+                    article.slug,
+                    tag
+                )
+                .execute(transaction.as_mut())
+                .await?;
+            }
+            crate::models::TagAlias::resolve_article_tags(transaction.as_mut(), &article.slug)
+                .await?;
+        }
+        for (follower, influencer) in &dataset.follows {
+            sqlx::query!(
+                "INSERT OR IGNORE INTO Follows(follower, influencer) VALUES ($1, $2)",
+                follower,
+                influencer
+            )
+            .execute(transaction.as_mut())
+            .await?;
+        }
+        for (article, username) in &dataset.favourites {
+            sqlx::query!(
+                "INSERT OR IGNORE INTO FavArticles(article, username) VALUES ($1, $2)",
+                article,
+                username
+            )
+            .execute(transaction.as_mut())
+            .await?;
+        }
+        for comment in &dataset.comments {
+            sqlx::query!(
+                "INSERT INTO Comments(article, username, body, created_at) VALUES ($1, $2, $3, $4)",
+                comment.article,
+                comment.username,
+                comment.body,
+                comment.created_at,
+            )
+            .execute(transaction.as_mut())
+            .await?;
+        }
+        Ok::<_, sqlx::Error>(())
+    }
+    .await
+    .map_err(|x| x.to_string())?;
+    transaction.commit().await.map_err(|x| x.to_string())
+}
+
+/// Generates and inserts the data set of `options`.
+pub async fn seed(options: &SeedOptions) -> Result<Dataset, String> {
+    let dataset = generate(options);
+    insert(&dataset).await?;
+    Ok(dataset)
+}
+
+/// Loads the demo content, user1 to user5 with their username as password, once.
+pub async fn load_demo() -> Result<(), String> {
+    let mut transaction = crate::database::begin_write()
+        .await
+        .map_err(|x| x.to_string())?;
+    let exists = sqlx::query_scalar!("SELECT COUNT(*) FROM Users WHERE username = 'user1'")
+        .fetch_one(transaction.as_mut())
+        .await
+        .map_err(|x| x.to_string())?;
+    if exists > 0 {
+        return Err("the demo content is already there".into());
+    }
+    sqlx::raw_sql(DEMO)
+        .execute(transaction.as_mut())
+        .await
+        .map_err(|x| x.to_string())?;
+    transaction.commit().await.map_err(|x| x.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn options(seed: u64) -> SeedOptions {
+        SeedOptions {
+            seed,
+            users: 20,
+            articles: 60,
+            tags: 50,
+            comments: 2,
+        }
+    }
+
+    #[test]
+    fn same_options_same_dataset() {
+        assert_eq!(generate(&options(7)), generate(&options(7)));
+        assert_ne!(generate(&options(7)), generate(&options(8)));
+    }
+
+    #[test]
+    fn dataset_is_consistent() {
+        let dataset = generate(&options(3));
+        assert_eq!(dataset.users.len(), 20);
+        assert_eq!(dataset.articles.len(), 60);
+
+        let usernames = dataset
+            .users
+            .iter()
+            .map(|x| x.username.as_str())
+            .collect::<HashSet<_>>();
+        assert_eq!(usernames.len(), dataset.users.len());
+        let slugs = dataset
+            .articles
+            .iter()
+            .map(|x| x.slug.as_str())
+            .collect::<HashSet<_>>();
+        assert_eq!(slugs.len(), dataset.articles.len());
+
+        for article in &dataset.articles {
+            assert!(usernames.contains(article.author.as_str()));
+            assert!(!article.tags.is_empty());
+            for tag in &article.tags {
+                assert_eq!(crate::models::normalize_tag(tag).as_ref(), Some(tag));
+            }
+            assert!(article.updated_at >= article.created_at);
+        }
+        for (follower, followed) in &dataset.follows {
+            assert_ne!(follower, followed);
+            assert!(usernames.contains(follower.as_str()));
+            assert!(usernames.contains(followed.as_str()));
+        }
+        for (article, username) in &dataset.favourites {
+            assert!(slugs.contains(article.as_str()));
+            assert!(usernames.contains(username.as_str()));
+        }
+        for comment in &dataset.comments {
+            assert!(slugs.contains(comment.article.as_str()));
+            assert!(usernames.contains(comment.username.as_str()));
+        }
+    }
+
+    #[test]
+    fn no_users_no_dataset() {
+        let dataset = generate(&SeedOptions {
+            users: 0,
+            ..options(1)
+        });
+        assert_eq!(dataset, Dataset::default());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::config::AppConfig;
+use crate::feeds::escape;
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+/// The pages kept out of the search engines, the private ones and the forms.
+const DISALLOWED_PATHS: [&str; 8] = [
+    "/api/",
+    "/admin/",
+    "/editor",
+    "/settings",
+    "/login",
+    "/signup",
+    "/reset_password",
+    "/upload",
+];
+
+/// Lists the home page, the tags page, the articles and the profiles.
+#[tracing::instrument]
+pub async fn sitemap(State(config): State<&'static AppConfig>) -> Response {
+    let entries = match crate::models::SitemapEntry::all().await {
+        Ok(x) => x,
+        Err(x) => {
+            tracing::error!("problem while building the sitemap: {x:?}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let base_url = &config.base_url;
+
+    let mut sitemap = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+"#,
+    );
+    for path in ["/", "/tags"] {
+        sitemap.push_str(&format!(
+            "<url><loc>{}</loc></url>\n",
+            escape(&format!("{base_url}{path}"))
+        ));
+    }
+    for entry in entries {
+        sitemap.push_str(&format!(
+            "<url><loc>{}</loc>{}</url>\n",
+            escape(&format!("{base_url}{}", entry.path)),
+            entry
+                .updated_at
+                .map(|x| format!("<lastmod>{}</lastmod>", x.format("%Y-%m-%dT%H:%M:%SZ")))
+                .unwrap_or_default()
+        ));
+    }
+    sitemap.push_str("</urlset>\n");
+
+    (
+        [
+            (header::CONTENT_TYPE, "application/xml; charset=utf-8"),
+            (header::CACHE_CONTROL, "public, max-age=3600"),
+        ],
+        sitemap,
+    )
+        .into_response()
+}
+
+#[tracing::instrument]
+pub async fn robots(State(config): State<&'static AppConfig>) -> Response {
+    let mut robots = String::from("User-agent: *\nAllow: /\n");
+    for path in DISALLOWED_PATHS {
+        robots.push_str(&format!("Disallow: {path}\n"));
+    }
+    robots.push_str(&format!("\nSitemap: {}/sitemap.xml\n", config.base_url));
+    (
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        robots,
+    )
+        .into_response()
+}
@@ -0,0 +1,733 @@
+//! Export and import of the content as JSON Lines, one record per line in the shape of the
+//! RealWorld API, so the content can move between this app and the other implementations.
+
+use crate::models::{Article, ArticleQuery, TagAlias, User};
+use chrono::{NaiveDateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+use sqlx::SqliteConnection;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, Write};
+
+/// How often the import reports its progress, in lines.
+const PROGRESS_EVERY: usize = 500;
+
+/// The records are written in the order they can be read back: the users, the tags, the
+/// articles, then what refers to them.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Record {
+    User(UserRecord),
+    Tag(TagRecord),
+    Article(ArticleRecord),
+    Comment(CommentRecord),
+    Follow(FollowRecord),
+    Favorite(FavoriteRecord),
+}
+
+/// A user, the password hash is only there when it was asked for. Without it, the imported
+/// user has to reset the password before logging in.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserRecord {
+    pub username: String,
+    pub email: String,
+    pub bio: Option<String>,
+    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+/// A tag with the aliases folded into it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TagRecord {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleRecord {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub tag_list: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub author: AuthorRecord,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthorRecord {
+    pub username: String,
+}
+
+/// A comment, with the slug of its article as the RealWorld comments live under the article.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentRecord {
+    pub article: String,
+    pub body: String,
+    pub created_at: String,
+    pub author: AuthorRecord,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FollowRecord {
+    pub follower: String,
+    pub following: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FavoriteRecord {
+    pub article: String,
+    pub username: String,
+}
+
+/// What the import does with a user or an article whose key is already taken.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OnConflict {
+    /// Keeps the existing one, the records referring to the key refer to it
+    #[default]
+    Skip,
+    /// Replaces the existing one with the record
+    Overwrite,
+    /// Imports the record under a free key (`name-2`, `name-3`...), the records referring to
+    /// it follow
+    Rename,
+}
+
+#[derive(Debug)]
+pub enum TransferError {
+    Io(std::io::Error),
+    Json(usize, serde_json::Error),
+    Database(sqlx::Error),
+    /// The database refused the record of a line.
+    Record(usize, sqlx::Error),
+}
+
+impl std::fmt::Display for TransferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(x) => write!(f, "{x}"),
+            Self::Json(line, x) => write!(f, "line {line}: {x}"),
+            Self::Database(x) => write!(f, "{x}"),
+            Self::Record(line, x) => write!(f, "line {line}: {x}"),
+        }
+    }
+}
+
+impl std::error::Error for TransferError {}
+
+impl From<std::io::Error> for TransferError {
+    fn from(x: std::io::Error) -> Self {
+        Self::Io(x)
+    }
+}
+
+impl From<sqlx::Error> for TransferError {
+    fn from(x: sqlx::Error) -> Self {
+        Self::Database(x)
+    }
+}
+
+/// How many records of each kind were written or read.
+#[derive(Debug, Default, Clone)]
+pub struct Counts {
+    pub lines: usize,
+    pub users: usize,
+    pub tags: usize,
+    pub articles: usize,
+    pub comments: usize,
+    pub follows: usize,
+    pub favorites: usize,
+    /// Already there, or referring to something which is not.
+    pub skipped: usize,
+    pub overwritten: usize,
+    pub renamed: usize,
+}
+
+impl std::fmt::Display for Counts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} users, {} tags, {} articles, {} comments, {} follows, {} favorites",
+            self.users, self.tags, self.articles, self.comments, self.follows, self.favorites
+        )
+    }
+}
+
+/// What the import did with a record.
+enum Outcome {
+    Inserted,
+    Overwritten,
+    Renamed,
+    Skipped,
+}
+
+pub(crate) fn format_date(date: NaiveDateTime) -> String {
+    date.and_utc().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn parse_date(date: &str) -> Option<NaiveDateTime> {
+    sqlx::types::chrono::DateTime::parse_from_rfc3339(date)
+        .ok()
+        .map(|x| x.naive_utc())
+}
+
+fn write_record(out: &mut impl Write, record: &Record) -> Result<(), TransferError> {
+    serde_json::to_writer(&mut *out, record).map_err(std::io::Error::from)?;
+    out.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Writes everything, in the order of [`Record`]. The password hashes are left out unless
+/// `with_passwords`.
+pub async fn export(out: &mut impl Write, with_passwords: bool) -> Result<Counts, TransferError> {
+    let mut counts = Counts::default();
+    let db = crate::database::get_db();
+    for user in User::all().await? {
+        write_record(
+            out,
+            &Record::User(UserRecord {
+                username: user.username(),
+                email: user.email(),
+                bio: user.bio(),
+                image: user.image(),
+                password: user.password_hash().filter(|_| with_passwords),
+            }),
+        )?;
+        counts.users += 1;
+    }
+
+    let mut tags = sqlx::query_scalar!("SELECT DISTINCT tag FROM ArticleTags")
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|x| (x, vec![]))
+        .collect::<BTreeMap<_, _>>();
+    for alias in TagAlias::all().await? {
+        tags.entry(alias.tag).or_default().push(alias.alias);
+    }
+    for (name, aliases) in tags {
+        write_record(out, &Record::Tag(TagRecord { name, aliases }))?;
+        counts.tags += 1;
+    }
+
+    let mut articles = ArticleQuery::new().fetch_all().await?;
+    articles.reverse();
+    let slugs = articles.iter().map(|x| x.slug.clone()).collect::<Vec<_>>();
+    let mut contents = Article::contents(&slugs)
+        .await?
+        .into_iter()
+        .map(|x| (x.slug.clone(), x))
+        .collect::<HashMap<_, _>>();
+    for article in articles {
+        let Some(content) = contents.remove(&article.slug) else {
+            continue;
+        };
+        write_record(
+            out,
+            &Record::Article(ArticleRecord {
+                slug: article.slug,
+                title: article.title,
+                description: article.description,
+                body: content.body,
+                tag_list: article.tag_list.into_iter().map(String::from).collect(),
+                created_at: format_date(content.created_at),
+                updated_at: format_date(content.updated_at),
+                author: AuthorRecord {
+                    username: article.author.username,
+                },
+            }),
+        )?;
+        counts.articles += 1;
+    }
+
+    for comment in sqlx::query!(
+        "SELECT article, username, body, created_at FROM Comments ORDER BY created_at, id"
+    )
+    .fetch_all(db)
+    .await?
+    {
+        write_record(
+            out,
+            &Record::Comment(CommentRecord {
+                article: comment.article,
+                body: comment.body,
+                created_at: format_date(comment.created_at),
+                author: AuthorRecord {
+                    username: comment.username,
+                },
+            }),
+        )?;
+        counts.comments += 1;
+    }
+
+    for follow in
+        sqlx::query!("SELECT follower, influencer FROM Follows ORDER BY follower, influencer")
+            .fetch_all(db)
+            .await?
+    {
+        write_record(
+            out,
+            &Record::Follow(FollowRecord {
+                follower: follow.follower,
+                following: follow.influencer,
+            }),
+        )?;
+        counts.follows += 1;
+    }
+
+    for favorite in
+        sqlx::query!("SELECT article, username FROM FavArticles ORDER BY username, article")
+            .fetch_all(db)
+            .await?
+    {
+        write_record(
+            out,
+            &Record::Favorite(FavoriteRecord {
+                article: favorite.article,
+                username: favorite.username,
+            }),
+        )?;
+        counts.favorites += 1;
+    }
+    out.flush()?;
+    Ok(counts)
+}
+
+/// Where the keys of the imported records ended up.
+#[derive(Default)]
+struct Keys {
+    /// The renamed users, or `None` for the ones left out whose records must not land on
+    /// somebody else.
+    users: HashMap<String, Option<String>>,
+    articles: HashMap<String, String>,
+}
+
+impl Keys {
+    fn user(&self, username: &str) -> Option<String> {
+        match self.users.get(username) {
+            Some(x) => x.clone(),
+            None => Some(username.to_string()),
+        }
+    }
+
+    fn article(&self, slug: &str) -> String {
+        self.articles
+            .get(slug)
+            .cloned()
+            .unwrap_or_else(|| slug.to_string())
+    }
+}
+
+async fn user_exists(conn: &mut SqliteConnection, username: &str) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!("SELECT COUNT(*) FROM Users WHERE username=$1", username)
+        .fetch_one(&mut *conn)
+        .await
+        .map(|x| x > 0)
+}
+
+async fn article_exists(conn: &mut SqliteConnection, slug: &str) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar!("SELECT COUNT(*) FROM Articles WHERE slug=$1", slug)
+        .fetch_one(&mut *conn)
+        .await
+        .map(|x| x > 0)
+}
+
+/// The first of `base-2`, `base-3`... which is no username yet.
+async fn free_username(conn: &mut SqliteConnection, base: &str) -> Result<String, sqlx::Error> {
+    for n in 2.. {
+        let username = format!("{base}-{n}");
+        if !user_exists(conn, &username).await? {
+            return Ok(username);
+        }
+    }
+    unreachable!("the numbers ran out")
+}
+
+/// The first of `base-2`, `base-3`... which is no slug yet.
+async fn free_slug(conn: &mut SqliteConnection, base: &str) -> Result<String, sqlx::Error> {
+    for n in 2.. {
+        let slug = format!("{base}-{n}");
+        if !article_exists(conn, &slug).await? {
+            return Ok(slug);
+        }
+    }
+    unreachable!("the numbers ran out")
+}
+
+/// A user is matched by username. One whose email belongs to another user is left out, the
+/// email identifies the account.
+async fn import_user(
+    conn: &mut SqliteConnection,
+    policy: OnConflict,
+    keys: &mut Keys,
+    user: UserRecord,
+) -> Result<Outcome, sqlx::Error> {
+    let exists = user_exists(conn, &user.username).await?;
+    let email_owner = sqlx::query_scalar!("SELECT username FROM Users WHERE email=$1", user.email)
+        .fetch_optional(&mut *conn)
+        .await?;
+    let email_taken = email_owner.as_ref().is_some_and(|x| *x != user.username);
+    let password = user.password.clone().filter(|x| !x.is_empty());
+
+    let username = match (exists, policy) {
+        (false, _) if email_taken => {
+            keys.users.insert(user.username, None);
+            return Ok(Outcome::Skipped);
+        }
+        (false, _) => user.username.clone(),
+        (true, OnConflict::Skip) => return Ok(Outcome::Skipped),
+        (true, OnConflict::Overwrite) => {
+            // The local account with this username is somebody else
+            if email_taken {
+                keys.users.insert(user.username, None);
+                return Ok(Outcome::Skipped);
+            }
+            sqlx::query!(
+                "UPDATE Users SET email=$2, bio=$3, image=$4, password=COALESCE($5, password) WHERE username=$1",
+                user.username,
+                user.email,
+                user.bio,
+                user.image,
+                password,
+            )
+            .execute(&mut *conn)
+            .await?;
+            return Ok(Outcome::Overwritten);
+        }
+        // The email is another local user's, a renamed copy can't have it
+        (true, OnConflict::Rename) if email_taken => {
+            keys.users.insert(user.username, None);
+            return Ok(Outcome::Skipped);
+        }
+        // The same username and email, the same account
+        (true, OnConflict::Rename) if email_owner.is_some() => return Ok(Outcome::Skipped),
+        (true, OnConflict::Rename) => free_username(conn, &user.username).await?,
+    };
+    // The new accounts follow the rules of the signup, reserved names included
+    if crate::models::validate_username(&username).is_err() {
+        keys.users.insert(user.username, None);
+        return Ok(Outcome::Skipped);
+    }
+    if exists {
+        keys.users
+            .insert(user.username.clone(), Some(username.clone()));
+    }
+    // An empty password can't be verified, the user has to reset it
+    let password = password.unwrap_or_default();
+    sqlx::query!(
+        "INSERT INTO Users(username, email, password, bio, image) VALUES ($1, $2, $3, $4, $5)",
+        username,
+        user.email,
+        password,
+        user.bio,
+        user.image,
+    )
+    .execute(&mut *conn)
+    .await?;
+    Ok(if exists {
+        Outcome::Renamed
+    } else {
+        Outcome::Inserted
+    })
+}
+
+/// Folds the aliases into the tag, like the tag administration does. An alias already
+/// pointing to another tag is only moved with [`OnConflict::Overwrite`].
+async fn import_tag(
+    conn: &mut SqliteConnection,
+    policy: OnConflict,
+    tag: TagRecord,
+) -> Result<Outcome, sqlx::Error> {
+    let Some(name) = crate::models::normalize_tag(&tag.name) else {
+        return Ok(Outcome::Skipped);
+    };
+    let is_alias = sqlx::query_scalar!("SELECT COUNT(*) FROM TagAliases WHERE alias=$1", name)
+        .fetch_one(&mut *conn)
+        .await?
+        > 0;
+    if is_alias {
+        return Ok(Outcome::Skipped);
+    }
+    let mut outcome = Outcome::Inserted;
+    for alias in tag
+        .aliases
+        .iter()
+        .filter_map(|x| crate::models::normalize_tag(x))
+    {
+        if alias == name {
+            continue;
+        }
+        let current = sqlx::query_scalar!("SELECT tag FROM TagAliases WHERE alias=$1", alias)
+            .fetch_optional(&mut *conn)
+            .await?;
+        match current {
+            Some(x) if x == name => {}
+            Some(_) if policy != OnConflict::Overwrite => outcome = Outcome::Skipped,
+            Some(_) => {
+                TagAlias::merge_with(conn, &alias, &name).await?;
+                outcome = Outcome::Overwritten;
+            }
+            None => TagAlias::merge_with(conn, &alias, &name).await?,
+        }
+    }
+    Ok(outcome)
+}
+
+async fn insert_article_tags(
+    conn: &mut SqliteConnection,
+    slug: &str,
+    tags: &[String],
+) -> Result<(), sqlx::Error> {
+    for tag in tags.iter().filter_map(|x| crate::models::normalize_tag(x)) {
+        sqlx::query!(
+            "INSERT OR IGNORE INTO ArticleTags(article, tag) VALUES ($1, $2)",
+            slug,
+            tag
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    TagAlias::resolve_article_tags(conn, slug).await
+}
+
+async fn import_article(
+    conn: &mut SqliteConnection,
+    policy: OnConflict,
+    keys: &mut Keys,
+    article: ArticleRecord,
+) -> Result<Outcome, sqlx::Error> {
+    let Some(author) = keys.user(&article.author.username) else {
+        return Ok(Outcome::Skipped);
+    };
+    if !user_exists(conn, &author).await? {
+        return Ok(Outcome::Skipped);
+    }
+    let created_at = parse_date(&article.created_at);
+    let updated_at = parse_date(&article.updated_at);
+    let exists = article_exists(conn, &article.slug).await?;
+
+    let slug = match (exists, policy) {
+        (false, _) => article.slug.clone(),
+        (true, OnConflict::Skip) => return Ok(Outcome::Skipped),
+        (true, OnConflict::Overwrite) => {
+            sqlx::query!(
+                "UPDATE Articles SET author=$2, title=$3, description=$4, body=$5,
+created_at=COALESCE($6, created_at), updated_at=COALESCE($7, CURRENT_TIMESTAMP)
+WHERE slug=$1",
+                article.slug,
+                author,
+                article.title,
+                article.description,
+                article.body,
+                created_at,
+                updated_at,
+            )
+            .execute(&mut *conn)
+            .await?;
+            // The `articles_owner` trigger only runs on INSERT, the owner row follows by hand.
+            // A co-author becoming the owner leaves their editor row.
+            sqlx::query!(
+                "DELETE FROM ArticleAuthors WHERE article=$1 and username=$2 and role!='owner'",
+                article.slug,
+                author
+            )
+            .execute(&mut *conn)
+            .await?;
+            sqlx::query!(
+                "UPDATE ArticleAuthors SET username=$2 WHERE article=$1 and role='owner'",
+                article.slug,
+                author
+            )
+            .execute(&mut *conn)
+            .await?;
+            sqlx::query!("DELETE FROM ArticleTags WHERE article=$1", article.slug)
+                .execute(&mut *conn)
+                .await?;
+            insert_article_tags(conn, &article.slug, &article.tag_list).await?;
+            return Ok(Outcome::Overwritten);
+        }
+        (true, OnConflict::Rename) => {
+            let renamed = free_slug(conn, &article.slug).await?;
+            keys.articles.insert(article.slug.clone(), renamed.clone());
+            renamed
+        }
+    };
+    sqlx::query!(
+        "INSERT INTO Articles(slug, author, title, description, body, created_at, updated_at)
+VALUES ($1, $2, $3, $4, $5, COALESCE($6, CURRENT_TIMESTAMP), COALESCE($7, CURRENT_TIMESTAMP))",
+        slug,
+        author,
+        article.title,
+        article.description,
+        article.body,
+        created_at,
+        updated_at,
+    )
+    .execute(&mut *conn)
+    .await?;
+    insert_article_tags(conn, &slug, &article.tag_list).await?;
+    Ok(if exists {
+        Outcome::Renamed
+    } else {
+        Outcome::Inserted
+    })
+}
+
+/// The comments have no key, the same comment on the same article at the same time is
+/// already there.
+async fn import_comment(
+    conn: &mut SqliteConnection,
+    keys: &Keys,
+    comment: CommentRecord,
+) -> Result<Outcome, sqlx::Error> {
+    let Some(username) = keys.user(&comment.author.username) else {
+        return Ok(Outcome::Skipped);
+    };
+    let article = keys.article(&comment.article);
+    if !user_exists(conn, &username).await? || !article_exists(conn, &article).await? {
+        return Ok(Outcome::Skipped);
+    }
+    let created_at = parse_date(&comment.created_at);
+    let duplicates = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM Comments WHERE article=$1 and username=$2 and body=$3 and created_at=$4",
+        article,
+        username,
+        comment.body,
+        created_at,
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+    if duplicates > 0 {
+        return Ok(Outcome::Skipped);
+    }
+    sqlx::query!(
+        "INSERT INTO Comments(article, username, body, created_at) VALUES ($1, $2, $3, COALESCE($4, CURRENT_TIMESTAMP))",
+        article,
+        username,
+        comment.body,
+        created_at,
+    )
+    .execute(&mut *conn)
+    .await?;
+    Ok(Outcome::Inserted)
+}
+
+async fn import_follow(
+    conn: &mut SqliteConnection,
+    keys: &Keys,
+    follow: FollowRecord,
+) -> Result<Outcome, sqlx::Error> {
+    let (Some(follower), Some(following)) =
+        (keys.user(&follow.follower), keys.user(&follow.following))
+    else {
+        return Ok(Outcome::Skipped);
+    };
+    if follower == following
+        || !user_exists(conn, &follower).await?
+        || !user_exists(conn, &following).await?
+    {
+        return Ok(Outcome::Skipped);
+    }
+    let inserted = sqlx::query!(
+        "INSERT OR IGNORE INTO Follows(follower, influencer) VALUES ($1, $2)",
+        follower,
+        following
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected()
+        == 1;
+    Ok(if inserted {
+        Outcome::Inserted
+    } else {
+        Outcome::Skipped
+    })
+}
+
+async fn import_favorite(
+    conn: &mut SqliteConnection,
+    keys: &Keys,
+    favorite: FavoriteRecord,
+) -> Result<Outcome, sqlx::Error> {
+    let Some(username) = keys.user(&favorite.username) else {
+        return Ok(Outcome::Skipped);
+    };
+    let article = keys.article(&favorite.article);
+    if !user_exists(conn, &username).await? || !article_exists(conn, &article).await? {
+        return Ok(Outcome::Skipped);
+    }
+    let inserted = sqlx::query!(
+        "INSERT OR IGNORE INTO FavArticles(article, username) VALUES ($1, $2)",
+        article,
+        username
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected()
+        == 1;
+    Ok(if inserted {
+        Outcome::Inserted
+    } else {
+        Outcome::Skipped
+    })
+}
+
+/// Reads the records in one transaction, nothing is imported when a line is invalid. The
+/// conflicts on the users and articles are resolved by `policy`, the records referring to
+/// something which isn't there (yet) are skipped. `progress` is called every few hundred
+/// lines and once at the end.
+pub async fn import(
+    input: impl BufRead,
+    policy: OnConflict,
+    mut progress: impl FnMut(&Counts),
+) -> Result<Counts, TransferError> {
+    let mut counts = Counts::default();
+    let mut keys = Keys::default();
+    let mut transaction = crate::database::begin_write().await?;
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        counts.lines = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record =
+            serde_json::from_str::<Record>(&line).map_err(|x| TransferError::Json(index + 1, x))?;
+        let conn = transaction.as_mut();
+        let (outcome, kind) = match record {
+            Record::User(x) => (
+                import_user(conn, policy, &mut keys, x).await,
+                &mut counts.users,
+            ),
+            Record::Tag(x) => (import_tag(conn, policy, x).await, &mut counts.tags),
+            Record::Article(x) => (
+                import_article(conn, policy, &mut keys, x).await,
+                &mut counts.articles,
+            ),
+            Record::Comment(x) => (import_comment(conn, &keys, x).await, &mut counts.comments),
+            Record::Follow(x) => (import_follow(conn, &keys, x).await, &mut counts.follows),
+            Record::Favorite(x) => (import_favorite(conn, &keys, x).await, &mut counts.favorites),
+        };
+        match outcome.map_err(|x| TransferError::Record(index + 1, x))? {
+            Outcome::Inserted => *kind += 1,
+            Outcome::Overwritten => {
+                *kind += 1;
+                counts.overwritten += 1;
+            }
+            Outcome::Renamed => {
+                *kind += 1;
+                counts.renamed += 1;
+            }
+            Outcome::Skipped => counts.skipped += 1,
+        }
+        if counts.lines % PROGRESS_EVERY == 0 {
+            progress(&counts);
+        }
+    }
+    progress(&counts);
+    transaction.commit().await?;
+    Ok(counts)
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// Where the stored files are served from.
+pub const UPLOADS_PATH: &str = "/uploads";
+/// The multipart endpoint, it expects the file in a field named `file`.
+pub const UPLOAD_URL: &str = "/upload";
+pub const MAX_UPLOAD_SIZE: usize = 5 * 1024 * 1024;
+pub const ALLOWED_MIME_TYPES: [&str; 4] = ["image/jpeg", "image/png", "image/gif", "image/webp"];
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UploadedFile {
+    pub url: String,
+    pub thumbnail: String,
+}
+
+/// An image can be an external url or a file previously uploaded here.
+pub fn is_image_url(url: &str) -> bool {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        return true;
+    }
+    let Some(name) = url
+        .strip_prefix(UPLOADS_PATH)
+        .and_then(|x| x.strip_prefix('/'))
+        .map(|x| x.strip_prefix("thumbs/").unwrap_or(x))
+    else {
+        return false;
+    };
+    match name.split_once('.') {
+        Some((hash, extension)) => {
+            hash.len() == 64
+                && hash.chars().all(|x| x.is_ascii_hexdigit())
+                && !extension.is_empty()
+                && extension.chars().all(|x| x.is_ascii_alphanumeric())
+        }
+        None => false,
+    }
+}
+
+/// Sends the file selected in `input` to the upload endpoint.
+/// Only available once the page is hydrated, it fails on the server.
+pub async fn upload_input(
+    input: leptos::web_sys::HtmlInputElement,
+) -> Result<UploadedFile, String> {
+    #[cfg(feature = "hydrate")]
+    {
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen_futures::JsFuture;
+
+        let file = input
+            .files()
+            .and_then(|x| x.get(0))
+            .ok_or("Choose a file to upload")?;
+        if file.size() > MAX_UPLOAD_SIZE as f64 {
+            return Err(format!(
+                "The file is too big, the limit is {} MB",
+                MAX_UPLOAD_SIZE / 1024 / 1024
+            ));
+        }
+        let form = web_sys::FormData::new().map_err(|x| format!("{x:?}"))?;
+        form.append_with_blob("file", &file)
+            .map_err(|x| format!("{x:?}"))?;
+        let init = web_sys::RequestInit::new();
+        init.set_method("POST");
+        init.set_body(&form);
+        let window = web_sys::window().ok_or("No window")?;
+        let response: web_sys::Response =
+            JsFuture::from(window.fetch_with_str_and_init(UPLOAD_URL, &init))
+                .await
+                .map_err(|x| format!("Problem while uploading the file: {x:?}"))?
+                .unchecked_into();
+        let text = JsFuture::from(response.text().map_err(|x| format!("{x:?}"))?)
+            .await
+            .map_err(|x| format!("{x:?}"))?
+            .as_string()
+            .unwrap_or_default();
+        if !response.ok() {
+            return Err(text);
+        }
+        serde_json::from_str(&text).map_err(|x| format!("Unexpected response: {x}"))
+    }
+    #[cfg(not(feature = "hydrate"))]
+    {
+        let _ = input;
+        Err("Uploads need the page to be loaded".into())
+    }
+}
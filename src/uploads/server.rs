@@ -0,0 +1,163 @@
+use super::{UploadedFile, ALLOWED_MIME_TYPES, MAX_UPLOAD_SIZE, UPLOADS_PATH};
+use axum::{
+    extract::Multipart,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    Json,
+};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tower_http::{
+    services::ServeDir,
+    set_header::{SetResponseHeader, SetResponseHeaderLayer},
+};
+
+const THUMBNAIL_SIZE: u32 = 256;
+const THUMBNAILS_DIR: &str = "thumbs";
+const MAX_IMAGE_DIMENSION: u32 = 8192;
+
+/// The directory where the files are stored, `uploads.dir` of the configuration.
+pub fn upload_dir() -> PathBuf {
+    crate::config::get_config().uploads.dir.clone()
+}
+
+/// Serves the stored files, they never change as they are named after their content.
+pub fn serve_uploads() -> SetResponseHeader<ServeDir, HeaderValue> {
+    tower::ServiceBuilder::new()
+        .layer(SetResponseHeaderLayer::if_not_present(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("public, max-age=31536000, immutable"),
+        ))
+        .service(ServeDir::new(upload_dir()))
+}
+
+/// Stores an image sent as multipart in the `file` field and generates its thumbnail.
+/// Answers with the urls of both.
+#[tracing::instrument(skip(multipart))]
+pub async fn upload(
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<Json<UploadedFile>, (StatusCode, String)> {
+    let username = crate::auth::get_username_from_headers(&headers).ok_or((
+        StatusCode::UNAUTHORIZED,
+        "You need to be authenticated".to_string(),
+    ))?;
+
+    let mut file = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|x| (x.status(), x.body_text()))?
+    {
+        if field.name() == Some("file") {
+            let mime = field.content_type().unwrap_or_default().to_string();
+            let bytes = field
+                .bytes()
+                .await
+                .map_err(|x| (x.status(), x.body_text()))?;
+            file = Some((mime, bytes));
+            break;
+        }
+    }
+    let Some((mime, bytes)) = file else {
+        return Err((StatusCode::BAD_REQUEST, "The file is missing".into()));
+    };
+    if bytes.len() > MAX_UPLOAD_SIZE {
+        return Err((
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "The file is too big, the limit is {} MB",
+                MAX_UPLOAD_SIZE / 1024 / 1024
+            ),
+        ));
+    }
+    // The declared type has to match the content
+    let format = image::guess_format(&bytes)
+        .ok()
+        .filter(|x| ALLOWED_MIME_TYPES.contains(&x.to_mime_type()) && x.to_mime_type() == mime)
+        .ok_or((
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            format!("Only {} images are accepted", ALLOWED_MIME_TYPES.join(", ")),
+        ))?;
+
+    let hash = format!("{:x}", Sha256::digest(&bytes));
+    let extension = format.extensions_str()[0].to_string();
+    let size = bytes.len() as i64;
+    let name = format!("{hash}.{extension}");
+    let dir = upload_dir();
+    tokio::task::spawn_blocking({
+        let name = name.clone();
+        move || store(&dir, &name, &bytes, format)
+    })
+    .await
+    .map_err(|x| x.to_string())
+    .and_then(|x| x)
+    .map_err(|x| {
+        tracing::error!("problem while storing the upload {name}: {x}");
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "The file is not a valid image".to_string(),
+        )
+    })?;
+
+    crate::models::Upload {
+        hash,
+        username,
+        mime,
+        extension,
+        size,
+    }
+    .insert()
+    .await
+    .map_err(|x| {
+        tracing::error!("problem while recording the upload {name}: {x:?}");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Problem while storing the file".to_string(),
+        )
+    })?;
+
+    Ok(Json(UploadedFile {
+        url: format!("{UPLOADS_PATH}/{name}"),
+        thumbnail: format!("{UPLOADS_PATH}/{THUMBNAILS_DIR}/{name}"),
+    }))
+}
+
+/// Removes stored files and their thumbnails, once no upload refers to them anymore. A file
+/// which can't be removed is only logged, the database is the reference.
+pub async fn remove_files(names: &[String]) {
+    let dir = upload_dir();
+    for name in names {
+        for path in [dir.join(name), dir.join(THUMBNAILS_DIR).join(name)] {
+            match tokio::fs::remove_file(&path).await {
+                Err(x) if x.kind() != std::io::ErrorKind::NotFound => {
+                    tracing::error!("problem while removing the upload {path:?}: {x}");
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Decodes the image, which also validates it, then writes it and its thumbnail unless they
+/// are already there.
+fn store(dir: &Path, name: &str, bytes: &[u8], format: image::ImageFormat) -> Result<(), String> {
+    let path = dir.join(name);
+    let thumbnail_path = dir.join(THUMBNAILS_DIR).join(name);
+    if path.exists() && thumbnail_path.exists() {
+        return Ok(());
+    }
+
+    let mut limits = image::Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+    limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+    let mut reader = image::ImageReader::with_format(std::io::Cursor::new(bytes), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|x| x.to_string())?;
+
+    std::fs::create_dir_all(dir.join(THUMBNAILS_DIR)).map_err(|x| x.to_string())?;
+    image
+        .thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        .save_with_format(&thumbnail_path, format)
+        .map_err(|x| x.to_string())?;
+    std::fs::write(&path, bytes).map_err(|x| x.to_string())
+}
@@ -0,0 +1,25 @@
+//! The parts of the urls of the links, built the same way on the server and in the browser.
+
+/// Percent-encodes `segment` so it can be used as a part of the path of an url.
+pub fn encode_path_segment(segment: &str) -> String {
+    percent_encode(segment)
+}
+
+/// Percent-encodes `value` so it can be used as a value of the query string of an url: `#`
+/// would start the fragment, `+` would be read as a space and `&` would end the value.
+pub fn encode_query_value(value: &str) -> String {
+    percent_encode(value)
+}
+
+/// Everything but the unreserved characters of RFC 3986, each byte of the UTF-8 on its own.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
@@ -0,0 +1,190 @@
+//! Hammers the database with concurrent writers and readers, through the same pools and models
+//! as the server, and checks that no write failed with `SQLITE_BUSY`, that the counters kept by
+//! the triggers match the rows, and that the deletes cascade.
+//!
+//! `cargo test --test write_stress --features ssr`
+
+use realworld_app_leptos_axum_sqlite::{config, database, models};
+
+const USERS: i64 = 20;
+const ARTICLES: i64 = 10;
+const WRITERS: usize = 16;
+const READERS: usize = 4;
+const OPERATIONS: usize = 50;
+
+async fn seed(db: &sqlx::SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO Users(username, email, password)
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < $1)
+SELECT 'stress' || i, 'stress' || i || '@example.com', '' FROM n",
+    )
+    .bind(USERS)
+    .execute(db)
+    .await?;
+    sqlx::query(
+        "INSERT INTO Articles(slug, author, title, description, body)
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < $1)
+SELECT 'stress-' || i, 'stress1', 'Article ' || i, 'A description', 'A body' FROM n",
+    )
+    .bind(ARTICLES)
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Comments and favourites from one user, every other comment is deleted again.
+async fn write(worker: usize) -> Result<(), sqlx::Error> {
+    let db = database::get_writer();
+    let username = format!("stress{}", worker as i64 % USERS + 1);
+    for i in 0..OPERATIONS {
+        let slug = format!("stress-{}", (worker + i) as i64 % ARTICLES + 1);
+        models::Comment::insert(slug.clone(), username.clone(), format!("Comment {i}")).await?;
+        if i % 2 == 0 {
+            let id = sqlx::query_scalar::<_, i64>(
+                "SELECT MAX(id) FROM Comments WHERE article=$1 and username=$2",
+            )
+            .bind(&slug)
+            .bind(&username)
+            .fetch_one(db)
+            .await?;
+            let deleted = models::Comment::delete(id as i32, username.clone()).await?;
+            assert_eq!(deleted.as_ref(), Some(&slug), "the comment was deleted");
+        }
+        let mut transaction = database::begin_write().await?;
+        let faved = sqlx::query("SELECT 1 FROM FavArticles WHERE article=$1 and username=$2")
+            .bind(&slug)
+            .bind(&username)
+            .fetch_optional(transaction.as_mut())
+            .await?
+            .is_some();
+        let statement = if faved {
+            "DELETE FROM FavArticles WHERE article=$1 and username=$2"
+        } else {
+            "INSERT INTO FavArticles(article, username) VALUES ($1, $2)"
+        };
+        sqlx::query(statement)
+            .bind(&slug)
+            .bind(&username)
+            .execute(transaction.as_mut())
+            .await?;
+        transaction.commit().await?;
+    }
+    Ok(())
+}
+
+async fn read() -> Result<usize, sqlx::Error> {
+    let mut rows = 0;
+    for _ in 0..OPERATIONS {
+        rows += models::ArticleQuery::new()
+            .fetch_page(models::PageStart::Offset(0), 10)
+            .await?
+            .articles
+            .len();
+    }
+    Ok(rows)
+}
+
+async fn count(query: &str) -> i64 {
+    sqlx::query_scalar::<_, i64>(query)
+        .fetch_one(database::get_db())
+        .await
+        .expect("count")
+}
+
+async fn check() {
+    for pool in [database::get_db(), database::get_writer()] {
+        let foreign_keys = sqlx::query_scalar::<_, i64>("PRAGMA foreign_keys")
+            .fetch_one(pool)
+            .await
+            .expect("foreign_keys");
+        let journal_mode = sqlx::query_scalar::<_, String>("PRAGMA journal_mode")
+            .fetch_one(pool)
+            .await
+            .expect("journal_mode");
+        assert_eq!(foreign_keys, 1, "foreign keys on every connection");
+        assert_eq!(journal_mode, "wal", "WAL on every connection");
+    }
+
+    // Half of the comments of each writer are deleted again
+    let comments = (WRITERS * OPERATIONS / 2) as i64;
+    assert_eq!(count("SELECT COUNT(*) FROM Comments").await, comments);
+    assert_eq!(
+        count("SELECT SUM(comments_count) FROM Articles").await,
+        comments
+    );
+    assert_eq!(
+        count("SELECT SUM(favorites_count) FROM Articles").await,
+        count("SELECT COUNT(*) FROM FavArticles").await
+    );
+    let drifted = count(
+        "SELECT COUNT(*) FROM Articles as a WHERE
+    a.favorites_count != (SELECT COUNT(*) FROM FavArticles WHERE article=a.slug)
+    OR a.comments_count != (SELECT COUNT(*) FROM Comments WHERE article=a.slug)",
+    )
+    .await;
+    assert_eq!(drifted, 0, "articles with counters out of sync");
+
+    let before = count("SELECT COUNT(*) FROM Comments WHERE article='stress-1'").await;
+    assert!(before > 0, "the article to delete has comments");
+    models::Article::delete("stress-1".into(), "stress1".into())
+        .await
+        .expect("article deleted");
+    let orphans = count(
+        "SELECT (SELECT COUNT(*) FROM Comments WHERE article='stress-1')
+    + (SELECT COUNT(*) FROM FavArticles WHERE article='stress-1')
+    + (SELECT COUNT(*) FROM ArticleAuthors WHERE article='stress-1')",
+    )
+    .await;
+    assert_eq!(orphans, 0, "rows left after deleting their article");
+
+    models::User::delete("stress2".into())
+        .await
+        .expect("user deleted");
+    let orphans = count(
+        "SELECT (SELECT COUNT(*) FROM Comments WHERE username='stress2')
+    + (SELECT COUNT(*) FROM FavArticles WHERE username='stress2')",
+    )
+    .await;
+    assert_eq!(orphans, 0, "rows left after deleting their user");
+    assert_eq!(
+        count("SELECT SUM(comments_count) FROM Articles").await,
+        count("SELECT COUNT(*) FROM Comments").await,
+        "the counters follow the cascades"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_writes() {
+    let file = std::env::temp_dir().join(format!("write_stress_{}.db", std::process::id()));
+    std::env::set_var("DATABASE_URL", format!("sqlite://{}", file.display()));
+    std::env::set_var("JWT_SECRET", "stress");
+    config::init_config(([127, 0, 0, 1], 3000).into()).expect("configuration loaded");
+    database::init_db().await.expect("database initialized");
+    seed(database::get_writer()).await.expect("seeded");
+
+    let writers = (0..WRITERS)
+        .map(|x| tokio::spawn(write(x)))
+        .collect::<Vec<_>>();
+    let readers = (0..READERS)
+        .map(|_| tokio::spawn(read()))
+        .collect::<Vec<_>>();
+    let mut failures = vec![];
+    for writer in writers {
+        if let Err(x) = writer.await.expect("writer task") {
+            failures.push(format!("write failed: {x}"));
+        }
+    }
+    for reader in readers {
+        if let Err(x) = reader.await.expect("reader task") {
+            failures.push(format!("read failed: {x}"));
+        }
+    }
+    assert!(failures.is_empty(), "{failures:?}");
+
+    check().await;
+    database::get_db().close().await;
+    database::get_writer().close().await;
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{suffix}", file.display()));
+    }
+}